@@ -27,20 +27,6 @@ impl TestOption {
         }
     }
 
-    fn from_index(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(TestOption::Option1),
-            1 => Some(TestOption::Option2),
-            2 => Some(TestOption::Option3),
-            3 => Some(TestOption::Option4),
-            4 => Some(TestOption::Option5),
-            5 => Some(TestOption::Option6),
-            6 => Some(TestOption::Option7),
-            7 => Some(TestOption::Option8),
-            _ => None,
-        }
-    }
-
     fn all_options() -> Vec<TestOption> {
         vec![
             TestOption::Option1,
@@ -64,12 +50,9 @@ fn main() {
     // Test variable that starts at -1
     let mut test_variable: i32 = -1;
     
-    // Convert enum options to strings for the dropdown
-    let enum_options = TestOption::all_options();
-    let dropdown_items: Vec<String> = enum_options.iter().map(|opt| opt.to_string()).collect();
-    
-    // Create dropdown with enum-based items
-    let mut dropdown = Dropdown::new(50.0, 100.0, 300.0, 30.0, dropdown_items)
+    // Create dropdown holding the enum values, labelled by their display names
+    let mut dropdown = Dropdown::new_with_display(50.0, 100.0, 300.0, 30.0, TestOption::all_options(), |opt| opt.to_string())
+        .with_placeholder("Choose an option")
         .with_style(presets::dropdown_default());
     
     // Set max visible items to show scrolling
@@ -125,10 +108,8 @@ fn main() {
         }
 
         // Update selected option display
-        if let Some(selected_index) = dropdown.selected_index {
-            if let Some(selected_enum) = TestOption::from_index(selected_index) {
-                selected_option_text = format!("Selected: {:?} ({})", selected_enum, selected_enum.to_string());
-            }
+        if let Some(selected_enum) = dropdown.selected_value() {
+            selected_option_text = format!("Selected: {:?} ({})", selected_enum, selected_enum.to_string());
         } else {
             selected_option_text = "No option selected".to_string();
         }
@@ -1,5 +1,5 @@
 use raylib::prelude::*;
-use raylib_interactive::{presets, Button, Checkbox, Dropdown, Style, TextField};
+use raylib_interactive::{presets, Button, Checkbox, CursorResolver, Dropdown, Style, TextField};
 
 fn main() {
     let (mut rl, thread) = raylib::init()
@@ -46,6 +46,8 @@ fn main() {
     let mut custom_button =
         Button::new(350.0, 10.0, 100.0, 40.0, "Custom").with_style(custom_style);
 
+    let mut cursors = CursorResolver::new();
+
     while !rl.window_should_close() {
         // Get mouse position for UI interaction
         let mouse = rl.get_mouse_position();
@@ -60,6 +62,8 @@ fn main() {
             dropdown,
             custom_button,
         );
+        // Show the cursor the hovered widget asked for
+        cursors.apply(&rl);
 
         // Handle interactions
         if button.is_clicked(&rl) {
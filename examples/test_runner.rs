@@ -1,11 +1,14 @@
 use raylib::prelude::*;
-use raylib_interactive::{Button, TextField, Checkbox, Dropdown, Style, presets, TestResults, run_all_tests, update_all, draw_all};
+use raylib_interactive::{Button, TextField, Checkbox, Dropdown, Style, presets, TestResults, run_all_tests, update_all, draw_all, draw_tooltips};
+use raylib_interactive::{ConfirmResult, DialogResult, ModalLayer};
 
 fn main() {
     let (mut rl, thread) = raylib::init()
         .size(1000, 800)
         .title("Raylib Interactive Test Runner")
         .build();
+    // Esc answers dialogs instead of closing the window
+    rl.set_exit_key(None);
 
     // Test results
     let mut test_results = run_all_tests();
@@ -15,19 +18,12 @@ fn main() {
     let mut run_tests_button = Button::new(50.0, 50.0, 150.0, 40.0, "Run Tests")
         .with_style(presets::button_primary());
 
-    let mut yes_button = Button::new(50.0, 700.0, 100.0, 40.0, "YES")
-        .with_style(presets::button_success());
-    yes_button.enabled = false;
-
-    let mut no_button = Button::new(170.0, 700.0, 100.0, 40.0, "NO")
-        .with_style(presets::button_danger());
-    no_button.enabled = false;
-
-    let mut reset_button = Button::new(290.0, 700.0, 100.0, 40.0, "Reset")
+    let mut reset_button = Button::new(50.0, 700.0, 100.0, 40.0, "Reset")
         .with_style(presets::button_secondary());
 
     // Demo components to show they work
     let mut demo_button = Button::new(250.0, 50.0, 120.0, 40.0, "Demo Button")
+        .with_tooltip("Prints a message to the console")
         .with_style(presets::button_secondary());
 
     let mut demo_textfield = TextField::new(400.0, 50.0, 150.0, 30.0, 20)
@@ -41,43 +37,45 @@ fn main() {
         vec!["Option A".to_string(), "Option B".to_string(), "Option C".to_string()])
         .with_style(presets::dropdown_default());
 
+    let mut modal = ModalLayer::new();
+    let mut verify_dialog = None;
+
     // Status text
     let mut status_text = "Click 'Run Tests' to start testing".to_string();
 
     while !rl.window_should_close() {
-        // Update all components
-        update_all!(&mut rl, Vector2::new(0.0, 0.0), run_tests_button, yes_button, no_button, reset_button, demo_button, demo_textfield, demo_checkbox, demo_dropdown);
+        let mouse = rl.get_mouse_position();
+
+        // The confirmation dialog blocks everything underneath while it's open
+        if modal.is_open() {
+            if let Some((id, DialogResult::Confirm(answer))) = modal.update(mouse, &mut rl) {
+                if Some(id) == verify_dialog {
+                    status_text = match (answer, test_results.all_passed) {
+                        (ConfirmResult::Yes, true) => "Correct! All tests passed!",
+                        (ConfirmResult::Yes, false) => "Incorrect! Some tests failed!",
+                        (ConfirmResult::No, false) => "Correct! Some tests failed!",
+                        (ConfirmResult::No, true) => "Incorrect! All tests actually passed!",
+                        (ConfirmResult::Cancel, _) => "Verification skipped. Click Reset to run tests again.",
+                    }
+                    .to_string();
+                    verify_dialog = None;
+                }
+            }
+        } else {
+            update_all!(&mut rl, mouse, run_tests_button, reset_button, demo_button, demo_textfield, demo_checkbox, demo_dropdown);
+        }
 
         // Handle button clicks
         if run_tests_button.is_clicked(&rl) && !tests_run {
             test_results = run_all_tests();
             tests_run = true;
             status_text = "Tests completed! Check results below.".to_string();
-            yes_button.enabled = true;
-            no_button.enabled = true;
-        }
-
-        if yes_button.is_clicked(&rl) {
-            if test_results.all_passed {
-                status_text = "Correct! All tests passed!".to_string();
-            } else {
-                status_text = "Incorrect! Some tests failed!".to_string();
-            }
-        }
-
-        if no_button.is_clicked(&rl) {
-            if !test_results.all_passed {
-                status_text = "Correct! Some tests failed!".to_string();
-            } else {
-                status_text = "Incorrect! All tests actually passed!".to_string();
-            }
+            verify_dialog = Some(modal.confirm("Review the results.\nDid all tests pass?"));
         }
 
         if reset_button.is_clicked(&rl) {
             tests_run = false;
             status_text = "Click 'Run Tests' to start testing".to_string();
-            yes_button.enabled = false;
-            no_button.enabled = false;
         }
 
         // Demo interactions
@@ -112,8 +110,6 @@ fn main() {
         // Draw control buttons
         d.draw_text("Controls:", 50, 670, 16, Color::BLACK);
         run_tests_button.draw(&mut d);
-        yes_button.draw(&mut d);
-        no_button.draw(&mut d);
         reset_button.draw(&mut d);
 
         // Draw instructions
@@ -121,8 +117,11 @@ fn main() {
         d.draw_text("Instructions:", 50, instructions_y, 14, Color::GRAY);
         d.draw_text("1. Click 'Run Tests' to execute all tests", 50, instructions_y + 20, 12, Color::GRAY);
         d.draw_text("2. Review the test results below", 50, instructions_y + 35, 12, Color::GRAY);
-        d.draw_text("3. Click YES if all tests passed, NO if any failed", 50, instructions_y + 50, 12, Color::GRAY);
+        d.draw_text("3. Answer Yes if all tests passed, No if any failed", 50, instructions_y + 50, 12, Color::GRAY);
         d.draw_text("4. Click Reset to run tests again", 50, instructions_y + 65, 12, Color::GRAY);
+
+        draw_tooltips!(&mut d, demo_button);
+        modal.draw(&mut d);
     }
 }
 
@@ -159,6 +158,126 @@ fn draw_test_results(d: &mut RaylibDrawHandle, results: &TestResults) {
     let test_color = if results.style_tests { Color::GREEN } else { Color::RED };
     let test_status = if results.style_tests { "PASS" } else { "FAIL" };
     d.draw_text(&format!("Style Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.radio_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.radio_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Radio Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.toggle_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.toggle_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Toggle Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.combobox_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.combobox_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("ComboBox Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.tabs_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.tabs_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Tabs Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.window_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.window_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Window Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.scroll_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.scroll_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("ScrollArea Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.modal_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.modal_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Modal Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.tooltip_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.tooltip_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Tooltip Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.context_menu_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.context_menu_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Context Menu Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.menu_bar_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.menu_bar_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Menu Bar Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.shortcuts_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.shortcuts_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Shortcuts Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.listbox_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.listbox_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("ListBox Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.table_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.table_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Table Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.tree_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.tree_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("TreeView Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.label_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.label_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Label/RichText Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.link_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.link_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Link Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.cursor_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.cursor_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Cursor Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.button_icon_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.button_icon_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Button Icon Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.skin_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.skin_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Skin Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.dropdown_multi_select_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.dropdown_multi_select_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Dropdown Multi-Select Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.dropdown_typed_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.dropdown_typed_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Dropdown Typed Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.dropdown_keyboard_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.dropdown_keyboard_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Dropdown Keyboard Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.dropdown_groups_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.dropdown_groups_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Dropdown Groups Tests: {}", test_status), 50, y, 16, test_color);
+    y += line_height;
+
+    let test_color = if results.dropdown_placement_tests { Color::GREEN } else { Color::RED };
+    let test_status = if results.dropdown_placement_tests { "PASS" } else { "FAIL" };
+    d.draw_text(&format!("Dropdown Placement Tests: {}", test_status), 50, y, 16, test_color);
     y += line_height + 10;
 
     // Overall result
@@ -174,9 +293,33 @@ fn draw_test_results(d: &mut RaylibDrawHandle, results: &TestResults) {
     let details = vec![
         "• Button: Creation, styling, state changes, bounds",
         "• TextField: Creation, styling, text content, placeholder, cursor",
-        "• Checkbox: Creation, styling, state, label, animation, toggle",
+        "• Checkbox: Creation, styling, state, label, animation, toggle, tri-state, enabled",
         "• Dropdown: Creation, styling, state, selection, items, clear",
-        "• Style: Default, custom, presets, builder methods, themes"
+        "• Style: Default, custom, presets, builder methods, themes",
+        "• Radio: Creation, layout, exclusive selection, clear, from enum",
+        "• Toggle: Creation, state, state text, shared binding with Checkbox",
+        "• ComboBox: Prefix/fuzzy matching, filtering, selection, clear",
+        "• Tabs: Selection, close, reorder, overflow, Ctrl+Tab cycling, TabView pages",
+        "• Window: Drag, resize, minimize/close, z-order, child placement",
+        "• ScrollArea: Clipping, child input, scroll bars, smooth and kinetic scrolling",
+        "• Modal: Alert/confirm/prompt results, queueing, Enter/Esc, layout",
+        "• Tooltip: Delay, placement, screen clamping, wrapping, disabled buttons",
+        "• Context Menu: Submenus, keyboard focus, checkable and disabled items",
+        "• Menu Bar: Accelerators, mnemonics, menu switching, shortcut parsing",
+        "• Shortcuts: Sequences, scopes, priority, keymap files, TextField remapping",
+        "• ListBox: Virtualized rows, Ctrl/Shift selection, double-click, keyboard",
+        "• Table: Virtualized rows, sorting, column resize/reorder, in-place editing",
+        "• TreeView: keyboard navigation, lazy loading, tri-state check boxes and drag-and-drop",
+        "• Label/RichText: markup parsing, wrapping, alignment and links",
+        "• Link: activation, visited state and rich text links",
+        "• Cursor: request priority, once-per-frame resolving and resize shapes",
+        "• Button Icon: icon placement, scaling and truncation",
+        "• Skin: nine-slice state fallback",
+        "• Dropdown multi-select: sorted toggling, select/clear all, clear row, removal",
+        "• Dropdown typed items: selected values, display closures, placeholder, clear row",
+        "• Dropdown keyboard: hover movement, scrolling, type-ahead, activation",
+        "• Dropdown groups: headers, separators, disabled items, keyboard skipping",
+        "• Dropdown placement: flipping above the box, item height, clamping to the parent"
     ];
 
     for detail in details {
@@ -188,9 +331,9 @@ fn draw_test_results(d: &mut RaylibDrawHandle, results: &TestResults) {
     y += 20;
     d.draw_text("Verification:", 50, y, 16, Color::BLACK);
     y += line_height;
-    d.draw_text("Based on the results above, click YES or NO to verify:", 50, y, 14, Color::GRAY);
+    d.draw_text("Based on the results above, answer the dialog to verify:", 50, y, 14, Color::GRAY);
     y += 20;
-    d.draw_text("YES = All tests passed correctly", 50, y, 12, Color::GREEN);
+    d.draw_text("Yes = All tests passed correctly", 50, y, 12, Color::GREEN);
     y += 15;
-    d.draw_text("NO = Some tests failed", 50, y, 12, Color::RED);
+    d.draw_text("No = Some tests failed", 50, y, 12, Color::RED);
 } 
\ No newline at end of file
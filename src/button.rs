@@ -1,5 +1,181 @@
+use crate::cursor::{request_cursor, CursorShape};
+use crate::skin::SkinState;
 use crate::style::Style;
+use crate::text::{measure_text, truncate_with_ellipsis};
+use crate::tooltip::Tooltip;
 use raylib::prelude::*;
+use std::rc::Rc;
+
+/// Where a button's icon sits relative to its label
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum IconPlacement {
+    #[default]
+    Left,
+    Right,
+    Top,
+}
+
+/// A texture, or a region of a sprite atlas, drawn on a button
+#[derive(Clone, Debug)]
+pub struct ButtonIcon {
+    pub texture: Rc<Texture2D>,
+    /// Atlas region to draw; the whole texture when `None`
+    pub source: Option<Rectangle>,
+    /// Drawn instead of `texture` while the button is disabled
+    pub disabled_texture: Option<Rc<Texture2D>>,
+    /// Size to draw at; the source size when `None`. Icons shrink to fit the button but never grow
+    pub size: Option<Vector2>,
+}
+
+impl ButtonIcon {
+    pub fn new(texture: Rc<Texture2D>) -> Self {
+        Self {
+            texture,
+            source: None,
+            disabled_texture: None,
+            size: None,
+        }
+    }
+
+    pub fn from_atlas(texture: Rc<Texture2D>, source: Rectangle) -> Self {
+        Self {
+            source: Some(source),
+            ..Self::new(texture)
+        }
+    }
+
+    pub fn with_size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some(Vector2::new(width, height));
+        self
+    }
+
+    pub fn with_disabled_texture(mut self, texture: Rc<Texture2D>) -> Self {
+        self.disabled_texture = Some(texture);
+        self
+    }
+
+    /// Use a grayscale copy of the texture while disabled; keeps the icon unchanged if the copy can't be made
+    pub fn with_desaturated_disabled(mut self, rl: &mut RaylibHandle, thread: &RaylibThread) -> Self {
+        if let Some(texture) = desaturate(rl, thread, &self.texture) {
+            self.disabled_texture = Some(Rc::new(texture));
+        }
+        self
+    }
+
+    pub fn source_rect(&self) -> Rectangle {
+        self.source
+            .unwrap_or_else(|| Rectangle::new(0.0, 0.0, self.texture.width as f32, self.texture.height as f32))
+    }
+
+    pub fn natural_size(&self) -> Vector2 {
+        self.size.unwrap_or_else(|| {
+            let source = self.source_rect();
+            Vector2::new(source.width.abs(), source.height.abs())
+        })
+    }
+}
+
+/// A grayscale copy of `texture`, for disabled icons
+pub fn desaturate(rl: &mut RaylibHandle, thread: &RaylibThread, texture: &Texture2D) -> Option<Texture2D> {
+    let mut image = texture.load_image().ok()?;
+    image.color_grayscale();
+    rl.load_texture_from_image(thread, &image).ok()
+}
+
+/// Where a button draws its icon and label
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ButtonLayout {
+    pub icon: Option<Rectangle>,
+    /// The label, truncated to the room the icon leaves
+    pub text: String,
+    pub text_position: Vector2,
+}
+
+/// Lay out a label and an icon of `icon_size` inside `bounds`, centering them as a group
+pub(crate) fn layout_button(
+    bounds: Rectangle,
+    style: &Style,
+    label: &str,
+    icon_size: Option<Vector2>,
+    placement: IconPlacement,
+) -> ButtonLayout {
+    let padding = style.padding;
+    let font_size = style.font_size as f32;
+    let content_width = (bounds.width - padding * 2.0).max(0.0);
+    let content_height = (bounds.height - padding * 2.0).max(0.0);
+    let text_y = bounds.y + (bounds.height - font_size) / 2.0;
+
+    let Some(natural) = icon_size else {
+        let text = truncate_with_ellipsis(label, content_width, style.font_size);
+        let text_width = measure_text(&text, style.font_size);
+        return ButtonLayout {
+            text_position: Vector2::new(bounds.x + (bounds.width - text_width) / 2.0, text_y),
+            icon: None,
+            text,
+        };
+    };
+
+    // Top icons share the height with the label; side icons share the width
+    let max_height = if placement == IconPlacement::Top && !label.is_empty() {
+        content_height - font_size - padding
+    } else {
+        content_height
+    };
+    let scale = if natural.x > 0.0 && natural.y > 0.0 {
+        (content_width / natural.x).min(max_height / natural.y).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let icon = Vector2::new(natural.x * scale, natural.y * scale);
+
+    if label.is_empty() {
+        let icon_x = bounds.x + (bounds.width - icon.x) / 2.0;
+        let icon_y = bounds.y + (bounds.height - icon.y) / 2.0;
+        return ButtonLayout {
+            icon: Some(Rectangle::new(icon_x, icon_y, icon.x, icon.y)),
+            text: String::new(),
+            text_position: Vector2::new(icon_x, text_y),
+        };
+    }
+
+    match placement {
+        IconPlacement::Left | IconPlacement::Right => {
+            let text = truncate_with_ellipsis(label, content_width - icon.x - padding, style.font_size);
+            let text_width = measure_text(&text, style.font_size);
+            let start = bounds.x + (bounds.width - (icon.x + padding + text_width)) / 2.0;
+            let (icon_x, text_x) = if placement == IconPlacement::Left {
+                (start, start + icon.x + padding)
+            } else {
+                (start + text_width + padding, start)
+            };
+            ButtonLayout {
+                icon: Some(Rectangle::new(icon_x, bounds.y + (bounds.height - icon.y) / 2.0, icon.x, icon.y)),
+                text,
+                text_position: Vector2::new(text_x, text_y),
+            }
+        }
+        IconPlacement::Top => {
+            let text = truncate_with_ellipsis(label, content_width, style.font_size);
+            let text_width = measure_text(&text, style.font_size);
+            let top = bounds.y + (bounds.height - (icon.y + padding + font_size)) / 2.0;
+            ButtonLayout {
+                icon: Some(Rectangle::new(bounds.x + (bounds.width - icon.x) / 2.0, top, icon.x, icon.y)),
+                text,
+                text_position: Vector2::new(bounds.x + (bounds.width - text_width) / 2.0, top + icon.y + padding),
+            }
+        }
+    }
+}
+
+/// Tint for a button's icon in `state`
+pub(crate) fn icon_tint(style: &Style, state: SkinState) -> Color {
+    match state {
+        SkinState::Disabled => style.icon_tint_disabled,
+        SkinState::Pressed => style.icon_tint_pressed,
+        SkinState::Hover => style.icon_tint_hover,
+        SkinState::Normal | SkinState::Focused => style.icon_tint,
+    }
+}
 
 pub struct Button {
     pub bounds: Rectangle,
@@ -9,6 +185,9 @@ pub struct Button {
     pub is_pressed: bool,
     pub animation_progress: f32,
     pub enabled: bool,
+    pub tooltip: Option<Tooltip>,
+    pub icon: Option<ButtonIcon>,
+    pub icon_placement: IconPlacement,
 }
 
 impl Button {
@@ -21,14 +200,38 @@ impl Button {
             is_pressed: false,
             animation_progress: 0.0,
             enabled: true,
+            tooltip: None,
+            icon: None,
+            icon_placement: IconPlacement::Left,
         }
     }
 
+    /// A button showing only `icon`; set `label` too for an icon and text button
+    pub fn icon_only(x: f32, y: f32, width: f32, height: f32, icon: ButtonIcon) -> Self {
+        Self::new(x, y, width, height, "").with_icon(icon)
+    }
+
+    pub fn with_icon(mut self, icon: ButtonIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn with_icon_placement(mut self, placement: IconPlacement) -> Self {
+        self.icon_placement = placement;
+        self
+    }
+
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = style;
         self
     }
 
+    /// Show `text` in a tooltip after hovering, even while disabled
+    pub fn with_tooltip(mut self, text: &str) -> Self {
+        self.tooltip = Some(Tooltip::new(text));
+        self
+    }
+
     pub fn set_colors(
         &mut self,
         background: Color,
@@ -45,13 +248,24 @@ impl Button {
     }
 
     pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        // Tooltips also explain disabled buttons, so they track the mouse before the enabled check
+        if let Some(tooltip) = &mut self.tooltip {
+            tooltip.update(self.bounds.check_collision_point_rec(mouse), mouse, self.bounds, rl);
+        }
+
         if !self.enabled {
             self.is_hovered = false;
             self.is_pressed = false;
+            if self.bounds.check_collision_point_rec(mouse) {
+                request_cursor(CursorShape::NotAllowed);
+            }
             return;
         }
 
         self.is_hovered = self.bounds.check_collision_point_rec(mouse);
+        if self.is_hovered {
+            request_cursor(CursorShape::PointingHand);
+        }
 
         // Update pressed state
         if self.is_hovered {
@@ -88,6 +302,66 @@ impl Button {
     }
 
     pub fn draw(&self, d: &mut impl RaylibDraw) {
+        let state = SkinState::from_flags(self.enabled, self.is_pressed, false, self.is_hovered);
+        if !self.style.draw_skin(d, self.bounds, state) {
+            self.draw_background(d);
+        }
+
+        // Truncate with ellipsis if the label doesn't fit beside the icon, then center both
+        let layout = layout_button(
+            self.bounds,
+            &self.style,
+            &self.label,
+            self.icon.as_ref().map(ButtonIcon::natural_size),
+            self.icon_placement,
+        );
+
+        // Draw icon and text with slight offset when pressed
+        let (text_offset_x, text_offset_y) = if self.is_pressed {
+            (1.0, 1.0)
+        } else {
+            (0.0, 0.0)
+        };
+
+        let text_color = if self.enabled {
+            if self.is_pressed {
+                self.style.text_color_pressed
+            } else if self.is_hovered {
+                self.style.text_color_hover
+            } else {
+                self.style.text_color
+            }
+        } else {
+            self.style.text_color_disabled
+        };
+
+        if let (Some(icon), Some(icon_bounds)) = (&self.icon, layout.icon) {
+            let texture = if self.enabled {
+                &icon.texture
+            } else {
+                icon.disabled_texture.as_ref().unwrap_or(&icon.texture)
+            };
+            let tint = icon_tint(&self.style, state);
+            let dest = Rectangle::new(
+                icon_bounds.x + text_offset_x,
+                icon_bounds.y + text_offset_y,
+                icon_bounds.width,
+                icon_bounds.height,
+            );
+            d.draw_texture_pro(texture.as_ref(), icon.source_rect(), dest, Vector2::zero(), 0.0, tint);
+        }
+
+        d.draw_text(
+            &layout.text,
+            (layout.text_position.x + text_offset_x) as i32,
+            (layout.text_position.y + text_offset_y) as i32,
+            self.style.font_size,
+            text_color,
+        );
+    }
+
+    /// The procedural background and border, drawn when the style has no skin
+    fn draw_background(&self, d: &mut impl RaylibDraw) {
         let current_color = if !self.enabled {
             self.style.disabled_color
         } else {
@@ -132,67 +406,13 @@ impl Button {
         };
 
         d.draw_rectangle_rounded_lines(self.bounds, self.style.corner_radius, 8, border_color);
+    }
 
-        // Calculate text position for centering
-        let text_width = unsafe {
-            raylib::ffi::MeasureText(self.label.as_ptr() as *const i8, self.style.font_size)
-        };
-        let text_x = self.bounds.x + (self.bounds.width - text_width as f32) / 2.0;
-        let text_y = self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0;
-
-        // Check if text fits, if not truncate with ellipsis
-        let display_text = if text_width as f32 > self.bounds.width - self.style.padding * 2.0 {
-            let mut truncated = self.label.clone();
-            while unsafe {
-                raylib::ffi::MeasureText(
-                    (truncated.clone() + "...").as_ptr() as *const i8,
-                    self.style.font_size,
-                )
-            } as f32
-                > self.bounds.width - self.style.padding * 2.0
-            {
-                truncated.pop();
-                if truncated.is_empty() {
-                    break;
-                }
-            }
-            truncated + "..."
-        } else {
-            self.label.clone()
-        };
-
-        // Recalculate position for truncated text
-        let final_text_width = unsafe {
-            raylib::ffi::MeasureText(display_text.as_ptr() as *const i8, self.style.font_size)
-        };
-        let final_text_x = self.bounds.x + (self.bounds.width - final_text_width as f32) / 2.0;
-
-        // Draw text with slight offset when pressed
-        let (text_offset_x, text_offset_y) = if self.is_pressed {
-            (1.0, 1.0)
-        } else {
-            (0.0, 0.0)
-        };
-
-        let text_color = if self.enabled {
-            if self.is_pressed {
-                self.style.text_color_pressed
-            } else if self.is_hovered {
-                self.style.text_color_hover
-            } else {
-                self.style.text_color
-            }
-        } else {
-            self.style.text_color_disabled
-        };
-
-        d.draw_text(
-            &display_text,
-            (final_text_x + text_offset_x) as i32,
-            (text_y + text_offset_y) as i32,
-            self.style.font_size,
-            text_color,
-        );
+    /// Draw the tooltip if it is showing; call after drawing everything else so it stays on top
+    pub fn draw_tooltip(&self, d: &mut impl RaylibDraw) {
+        if let Some(tooltip) = &self.tooltip {
+            tooltip.draw(d, &self.style);
+        }
     }
 
     pub fn is_clicked(&self, rl: &RaylibHandle) -> bool {
@@ -1,26 +1,48 @@
 use raylib::prelude::*;
+use crate::cursor::{request_cursor, CursorShape};
+use crate::skin::SkinState;
 use crate::style::Style;
+use crate::tooltip::Tooltip;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckState {
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
 
 pub struct Checkbox {
     pub bounds: Rectangle,
+    pub state: CheckState,
+    /// Deprecated: mirrors `state == CheckState::Checked` for code written before tri-state.
+    /// Writing it takes effect on the next `update` or `toggle`; prefer `state` and `set_checked`
     pub is_checked: bool,
     pub style: Style,
     pub label: String,
     pub is_hovered: bool,
     pub animation_progress: f32,
     pub is_clicked: bool,
+    pub enabled: bool,
+    pub binding: Option<Rc<Cell<bool>>>,
+    pub tooltip: Option<Tooltip>,
 }
 
 impl Checkbox {
     pub fn new(x: f32, y: f32, size: f32, label: &str) -> Self {
         Self {
             bounds: Rectangle::new(x, y, size, size),
+            state: CheckState::Unchecked,
             is_checked: false,
             style: Style::default(),
             label: label.to_string(),
             is_hovered: false,
             animation_progress: 0.0,
             is_clicked: false,
+            enabled: true,
+            tooltip: None,
+            binding: None,
         }
     }
 
@@ -29,6 +51,20 @@ impl Checkbox {
         self
     }
 
+    /// Share the checked state with other widgets through a common cell
+    pub fn with_binding(mut self, binding: Rc<Cell<bool>>) -> Self {
+        self.set_checked(binding.get());
+        self.animation_progress = if self.is_checked() { 1.0 } else { 0.0 };
+        self.binding = Some(binding);
+        self
+    }
+
+    /// Show `text` in a tooltip after hovering, even while disabled
+    pub fn with_tooltip(mut self, text: &str) -> Self {
+        self.tooltip = Some(Tooltip::new(text));
+        self
+    }
+
     pub fn set_colors(
         &mut self,
         background: Color,
@@ -45,23 +81,42 @@ impl Checkbox {
     }
 
     pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
-        self.is_hovered = self.bounds.check_collision_point_rec(mouse);
+        self.sync_binding();
+
+        let hit_bounds = self.hit_bounds();
+        if let Some(tooltip) = &mut self.tooltip {
+            tooltip.update(hit_bounds.check_collision_point_rec(mouse), mouse, hit_bounds, rl);
+        }
+
+        if !self.enabled {
+            self.is_hovered = false;
+            self.is_clicked = false;
+            if hit_bounds.check_collision_point_rec(mouse) {
+                request_cursor(CursorShape::NotAllowed);
+            }
+        } else {
+            // The label is clickable as well as the box
+            self.is_hovered = self.hit_bounds().check_collision_point_rec(mouse);
+            if self.is_hovered {
+                request_cursor(CursorShape::PointingHand);
+            }
 
-        // Handle click animation
-        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) && self.is_hovered {
-            self.is_clicked = true;
-            self.is_checked = !self.is_checked;
-            self.animation_progress = 0.0;
+            // Handle click animation
+            if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) && self.is_hovered {
+                self.is_clicked = true;
+                self.toggle();
+            }
         }
 
         // Update animation
         let animation_speed = 4.0;
-        if self.is_checked && self.animation_progress < 1.0 {
+        let is_marked = self.state != CheckState::Unchecked;
+        if is_marked && self.animation_progress < 1.0 {
             self.animation_progress += rl.get_frame_time() * animation_speed;
             if self.animation_progress > 1.0 {
                 self.animation_progress = 1.0;
             }
-        } else if !self.is_checked && self.animation_progress > 0.0 {
+        } else if !is_marked && self.animation_progress > 0.0 {
             self.animation_progress -= rl.get_frame_time() * animation_speed;
             if self.animation_progress < 0.0 {
                 self.animation_progress = 0.0;
@@ -75,19 +130,29 @@ impl Checkbox {
     }
 
     pub fn draw(&self, d: &mut impl RaylibDraw) {
-        // Draw background
-        let background_color = if self.is_hovered {
-            self.style.hover_color
-        } else {
-            self.style.background_color
-        };
-        d.draw_rectangle_rec(self.bounds, background_color);
+        let state = SkinState::from_flags(self.enabled, self.is_clicked, false, self.is_hovered);
+        if !self.style.draw_skin(d, self.bounds, state) {
+            // Draw background
+            let background_color = if !self.enabled {
+                self.style.disabled_color
+            } else if self.is_hovered {
+                self.style.hover_color
+            } else {
+                self.style.background_color
+            };
+            d.draw_rectangle_rec(self.bounds, background_color);
 
-        // Draw border
-        let border_thickness = if self.is_clicked { 3.0 } else { self.style.border_thickness };
-        d.draw_rectangle_lines_ex(self.bounds, border_thickness, self.style.border_color);
+            // Draw border
+            let border_thickness = if self.is_clicked { 3.0 } else { self.style.border_thickness };
+            let border_color = if self.enabled {
+                self.style.border_color
+            } else {
+                self.style.text_color_disabled
+            };
+            d.draw_rectangle_lines_ex(self.bounds, border_thickness, border_color);
+        }
 
-        // Draw check mark with animation
+        // Draw check mark or dash with animation
         if self.animation_progress > 0.0 {
             let padding = self.bounds.width * 0.2;
             let check_bounds = Rectangle::new(
@@ -102,39 +167,117 @@ impl Checkbox {
             let center_y = check_bounds.y + check_bounds.height / 2.0;
             let size = check_bounds.width / 2.0 * self.animation_progress;
 
-            // Draw custom check mark
-            let points = [
-                Vector2::new(center_x - size, center_y),
-                Vector2::new(center_x, center_y + size),
-                Vector2::new(center_x + size, center_y - size),
-            ];
-
+            let base_color = if self.enabled {
+                self.style.check_color
+            } else {
+                self.style.text_color_disabled
+            };
             let check_color = Color::new(
-                self.style.check_color.r,
-                self.style.check_color.g,
-                self.style.check_color.b,
-                (self.style.check_color.a as f32 * self.animation_progress) as u8,
+                base_color.r,
+                base_color.g,
+                base_color.b,
+                (base_color.a as f32 * self.animation_progress) as u8,
             );
 
-            d.draw_line_ex(points[0], points[1], 2.0, check_color);
-            d.draw_line_ex(points[1], points[2], 2.0, check_color);
+            if self.state == CheckState::Indeterminate {
+                // Draw dash for the mixed state
+                d.draw_line_ex(
+                    Vector2::new(center_x - size, center_y),
+                    Vector2::new(center_x + size, center_y),
+                    2.0,
+                    check_color,
+                );
+            } else {
+                // Draw custom check mark
+                let points = [
+                    Vector2::new(center_x - size, center_y),
+                    Vector2::new(center_x, center_y + size),
+                    Vector2::new(center_x + size, center_y - size),
+                ];
+
+                d.draw_line_ex(points[0], points[1], 2.0, check_color);
+                d.draw_line_ex(points[1], points[2], 2.0, check_color);
+            }
         }
 
         // Draw label with proper alignment
         let label_x = self.bounds.x + self.bounds.width + 10.0;
         let label_y = self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0;
+        let label_color = if self.enabled {
+            self.style.text_color
+        } else {
+            self.style.text_color_disabled
+        };
 
         d.draw_text(
             &self.label,
             label_x as i32,
             label_y as i32,
             self.style.font_size,
-            self.style.text_color,
+            label_color,
         );
     }
 
+    /// Draw the tooltip if it is showing; call after drawing everything else so it stays on top
+    pub fn draw_tooltip(&self, d: &mut impl RaylibDraw) {
+        if let Some(tooltip) = &self.tooltip {
+            tooltip.draw(d, &self.style);
+        }
+    }
+
+    /// Area that reacts to the mouse: the box plus the label drawn to its right
+    pub fn hit_bounds(&self) -> Rectangle {
+        if self.label.is_empty() {
+            return self.bounds;
+        }
+        let cstr = std::ffi::CString::new(self.label.as_str()).unwrap_or_default();
+        let label_width = unsafe { raylib::ffi::MeasureText(cstr.as_ptr(), self.style.font_size) } as f32;
+        Rectangle::new(
+            self.bounds.x,
+            self.bounds.y.min(self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0),
+            self.bounds.width + 10.0 + label_width,
+            self.bounds.height.max(self.style.font_size as f32),
+        )
+    }
+
+    /// Pick up writes to the `is_checked` field, then changes made through the binding by other widgets
+    fn sync_binding(&mut self) {
+        if self.is_checked != (self.state == CheckState::Checked) {
+            self.set_checked(self.is_checked);
+        }
+        if let Some(binding) = &self.binding {
+            if binding.get() != self.is_checked() {
+                self.state = if binding.get() { CheckState::Checked } else { CheckState::Unchecked };
+                self.is_checked = binding.get();
+            }
+        }
+    }
+
+    /// Unchecked and indeterminate boxes become checked, checked boxes become unchecked
     pub fn toggle(&mut self) {
-        self.is_checked = !self.is_checked;
-        self.animation_progress = if self.is_checked { 0.0 } else { 1.0 };
+        self.sync_binding();
+        let checked = self.state != CheckState::Checked;
+        self.set_checked(checked);
+        self.animation_progress = if checked { 0.0 } else { 1.0 };
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.state == CheckState::Checked
+    }
+
+    pub fn is_indeterminate(&self) -> bool {
+        self.state == CheckState::Indeterminate
+    }
+
+    pub fn set_checked(&mut self, checked: bool) {
+        self.set_state(if checked { CheckState::Checked } else { CheckState::Unchecked });
+    }
+
+    pub fn set_state(&mut self, state: CheckState) {
+        self.state = state;
+        self.is_checked = state == CheckState::Checked;
+        if let Some(binding) = &self.binding {
+            binding.set(state == CheckState::Checked);
+        }
     }
 }
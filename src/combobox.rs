@@ -0,0 +1,411 @@
+use raylib::prelude::*;
+use crate::cursor::{request_cursor, CursorShape};
+use crate::dropdown::{draw_arrow_glyph, ArrowDirection};
+use crate::style::Style;
+use crate::textfield::TextField;
+use std::ffi::CString;
+
+/// How typed text is matched against the items
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MatchMode {
+    /// Items starting with the typed text (case-insensitive)
+    Prefix,
+    /// Items containing the typed characters in order (case-insensitive)
+    Fuzzy,
+}
+
+/// An item that matches the current text, with the matched character positions
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComboMatch {
+    pub index: usize,
+    pub positions: Vec<usize>,
+}
+
+pub struct ComboBox {
+    pub text_field: TextField,
+    pub items: Vec<String>,
+    pub matches: Vec<ComboMatch>,
+    pub selected_index: Option<usize>,
+    pub hover_index: Option<usize>,
+    pub is_open: bool,
+    pub style: Style,
+    pub max_visible_items: usize,
+    pub scroll_offset: usize,
+    pub match_mode: MatchMode,
+    pub allow_free_text: bool,
+    last_text: String,
+    last_mouse: Vector2,
+}
+
+impl ComboBox {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, items: Vec<String>) -> Self {
+        let mut combo = Self {
+            text_field: TextField::new(x, y, width, height, 256),
+            items,
+            matches: Vec::new(),
+            selected_index: None,
+            hover_index: None,
+            is_open: false,
+            style: Style::default(),
+            max_visible_items: 5,
+            scroll_offset: 0,
+            match_mode: MatchMode::Prefix,
+            allow_free_text: false,
+            last_text: String::new(),
+            last_mouse: Vector2::zero(),
+        };
+        combo.refilter();
+        combo
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.text_field.style = style.clone();
+        self.style = style;
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.text_field.placeholder = placeholder.to_string();
+        self
+    }
+
+    pub fn with_match_mode(mut self, match_mode: MatchMode) -> Self {
+        self.match_mode = match_mode;
+        self.refilter();
+        self
+    }
+
+    /// Accept text that is not one of the items when the box loses focus or Enter is pressed
+    pub fn with_free_text(mut self, allow: bool) -> Self {
+        self.allow_free_text = allow;
+        self
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &mut RaylibHandle) {
+        let bounds = self.text_field.bounds;
+
+        // Handle clicks on the open list before the text field loses focus
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            if bounds.check_collision_point_rec(mouse) {
+                self.is_open = true;
+            } else if self.is_open {
+                let visible_items = self.matches.len().min(self.max_visible_items);
+                let clicked = (0..visible_items)
+                    .find(|&i| self.get_item_bounds(i).check_collision_point_rec(mouse))
+                    .map(|i| i + self.scroll_offset);
+                if let Some(match_index) = clicked {
+                    self.select_match(match_index);
+                }
+                self.is_open = false;
+            }
+        }
+
+        let was_active = self.text_field.is_active;
+        self.text_field.update(mouse, rl);
+
+        // Losing focus commits whatever was typed
+        if was_active && !self.text_field.is_active {
+            self.commit_text();
+            self.is_open = false;
+        }
+
+        // Typing filters the list and opens it
+        if self.text_field.text != self.last_text {
+            self.last_text = self.text_field.text.clone();
+            self.refilter();
+            self.is_open = true;
+            self.hover_index = if self.matches.is_empty() { None } else { Some(0) };
+        }
+
+        if self.text_field.is_active {
+            let count = self.matches.len();
+            if rl.is_key_pressed(KeyboardKey::KEY_DOWN) && count > 0 {
+                self.is_open = true;
+                self.hover_index = Some(self.hover_index.map(|i| (i + 1).min(count - 1)).unwrap_or(0));
+                self.scroll_to_hover();
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_UP) && count > 0 {
+                self.is_open = true;
+                self.hover_index = Some(self.hover_index.map(|i| i.saturating_sub(1)).unwrap_or(0));
+                self.scroll_to_hover();
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_ENTER) {
+                match self.hover_index {
+                    Some(match_index) if self.is_open => self.select_match(match_index),
+                    _ => self.commit_text(),
+                }
+                self.is_open = false;
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_ESCAPE) {
+                self.is_open = false;
+            }
+        }
+
+        // Handle scrolling when the list is open
+        if self.is_open && self.matches.len() > self.max_visible_items {
+            let wheel_move = rl.get_mouse_wheel_move() as i32;
+            if wheel_move != 0 {
+                self.scroll_offset = (self.scroll_offset as i32 - wheel_move)
+                    .max(0)
+                    .min((self.matches.len() - self.max_visible_items) as i32) as usize;
+            }
+        }
+
+        self.hover_at(mouse);
+    }
+
+    /// Mouse hover overrides keyboard hover, but only while the mouse moves over the list.
+    /// Asks for the hand cursor whenever the mouse is over a suggestion.
+    pub fn hover_at(&mut self, mouse: Vector2) {
+        let mouse_moved = mouse.x != self.last_mouse.x || mouse.y != self.last_mouse.y;
+        self.last_mouse = mouse;
+        if !self.is_open {
+            return;
+        }
+        let visible_items = self.matches.len().min(self.max_visible_items);
+        let Some(i) = (0..visible_items).find(|&i| self.get_item_bounds(i).check_collision_point_rec(mouse)) else {
+            return;
+        };
+        request_cursor(CursorShape::PointingHand);
+        if mouse_moved {
+            self.hover_index = Some(i + self.scroll_offset);
+        }
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        self.text_field.draw(d);
+
+        // Draw dropdown arrow
+        let bounds = self.text_field.bounds;
+        let arrow_size = self.style.font_size as f32 * 0.5;
+        let arrow_x = bounds.x + bounds.width - arrow_size - self.style.padding;
+        let arrow_y = bounds.y + (bounds.height - arrow_size) / 2.0;
+        let arrow = Rectangle::new(arrow_x, arrow_y, arrow_size, arrow_size);
+        draw_arrow_glyph(d, arrow, ArrowDirection::Down, self.style.text_color);
+
+        if !self.is_open {
+            return;
+        }
+
+        let visible_items = self.matches.len().min(self.max_visible_items);
+        for i in 0..visible_items {
+            let match_index = i + self.scroll_offset;
+            let Some(item_match) = self.matches.get(match_index) else {
+                break;
+            };
+
+            let item_bounds = self.get_item_bounds(i);
+            let background_color = if Some(match_index) == self.hover_index {
+                self.style.hover_color
+            } else {
+                self.style.background_color
+            };
+            d.draw_rectangle_rec(item_bounds, background_color);
+            d.draw_rectangle_lines_ex(item_bounds, self.style.border_thickness, self.style.border_color);
+            self.draw_highlighted(d, item_bounds, item_match);
+        }
+
+        if self.matches.is_empty() {
+            let item_bounds = self.get_item_bounds(0);
+            d.draw_rectangle_rec(item_bounds, self.style.background_color);
+            d.draw_rectangle_lines_ex(item_bounds, self.style.border_thickness, self.style.border_color);
+            d.draw_text(
+                "No matches",
+                item_bounds.x as i32 + self.style.padding as i32,
+                (item_bounds.y + (item_bounds.height - self.style.font_size as f32) / 2.0) as i32,
+                self.style.font_size,
+                self.style.placeholder_color,
+            );
+        }
+    }
+
+    /// Draw an item's text, coloring the matched characters with `active_color`
+    fn draw_highlighted(&self, d: &mut impl RaylibDraw, item_bounds: Rectangle, item_match: &ComboMatch) {
+        let text = &self.items[item_match.index];
+        let text_x = item_bounds.x + self.style.padding;
+        let text_y = (item_bounds.y + (item_bounds.height - self.style.font_size as f32) / 2.0) as i32;
+
+        // Split the text into runs of matched and unmatched characters
+        let mut run_start = 0;
+        let mut run_matched = item_match.positions.contains(&0);
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        for (char_pos, &(byte_pos, _)) in chars.iter().enumerate().skip(1) {
+            let matched = item_match.positions.contains(&char_pos);
+            if matched != run_matched {
+                self.draw_run(d, text, run_start, byte_pos, run_matched, text_x, text_y);
+                run_start = byte_pos;
+                run_matched = matched;
+            }
+        }
+        self.draw_run(d, text, run_start, text.len(), run_matched, text_x, text_y);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_run(
+        &self,
+        d: &mut impl RaylibDraw,
+        text: &str,
+        start: usize,
+        end: usize,
+        matched: bool,
+        text_x: f32,
+        text_y: i32,
+    ) {
+        if start >= end {
+            return;
+        }
+        let cstr = CString::new(&text[..start]).unwrap_or_default();
+        let offset = unsafe { raylib::ffi::MeasureText(cstr.as_ptr(), self.style.font_size) } as f32;
+        let color = if matched { self.style.active_color } else { self.style.text_color };
+        d.draw_text(
+            &text[start..end],
+            (text_x + offset) as i32,
+            text_y,
+            self.style.font_size,
+            color,
+        );
+    }
+
+    fn get_item_bounds(&self, index: usize) -> Rectangle {
+        let bounds = self.text_field.bounds;
+        Rectangle::new(
+            bounds.x,
+            bounds.y + bounds.height * (index as f32 + 1.0),
+            bounds.width,
+            bounds.height,
+        )
+    }
+
+    fn scroll_to_hover(&mut self) {
+        if let Some(hover) = self.hover_index {
+            if hover < self.scroll_offset {
+                self.scroll_offset = hover;
+            } else if hover >= self.scroll_offset + self.max_visible_items {
+                self.scroll_offset = hover + 1 - self.max_visible_items;
+            }
+        }
+    }
+
+    /// Recompute `matches` from the current text
+    pub fn refilter(&mut self) {
+        let query = self.text_field.text.to_lowercase();
+        self.matches.clear();
+        for (index, item) in self.items.iter().enumerate() {
+            let positions = match self.match_mode {
+                MatchMode::Prefix => prefix_match(&query, item),
+                MatchMode::Fuzzy => fuzzy_match(&query, item),
+            };
+            if let Some(positions) = positions {
+                self.matches.push(ComboMatch { index, positions });
+            }
+        }
+
+        // Tighter fuzzy matches first
+        if self.match_mode == MatchMode::Fuzzy {
+            self.matches.sort_by_key(|m| {
+                let first = m.positions.first().copied().unwrap_or(0);
+                let last = m.positions.last().copied().unwrap_or(0);
+                (last - first, first)
+            });
+        }
+
+        self.scroll_offset = 0;
+        if let Some(hover) = self.hover_index {
+            if hover >= self.matches.len() {
+                self.hover_index = None;
+            }
+        }
+    }
+
+    fn select_match(&mut self, match_index: usize) {
+        if let Some(item_match) = self.matches.get(match_index) {
+            self.select(item_match.index);
+        }
+    }
+
+    /// Resolve the typed text into a selection, reverting it when free text is not allowed
+    fn commit_text(&mut self) {
+        let text = self.text_field.text.to_lowercase();
+        if let Some(index) = self.items.iter().position(|item| item.to_lowercase() == text) {
+            self.select(index);
+        } else if self.allow_free_text || text.is_empty() {
+            self.selected_index = None;
+        } else {
+            let restored = self.get_selected_item().cloned().unwrap_or_default();
+            self.text_field.set_text(&restored);
+            self.last_text = restored;
+            self.refilter();
+        }
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if let Some(item) = self.items.get(index) {
+            let item = item.clone();
+            self.selected_index = Some(index);
+            self.text_field.set_text(&item);
+            self.last_text = item;
+            self.refilter();
+        }
+    }
+
+    pub fn get_selected_item(&self) -> Option<&String> {
+        self.selected_index.and_then(|i| self.items.get(i))
+    }
+
+    /// The current text, which may be free text when `allow_free_text` is set
+    pub fn value(&self) -> &str {
+        &self.text_field.text
+    }
+
+    pub fn set_items(&mut self, items: Vec<String>) {
+        self.items = items;
+        self.selected_index = None;
+        self.refilter();
+    }
+
+    pub fn clear(&mut self) {
+        self.text_field.clear();
+        self.last_text.clear();
+        self.selected_index = None;
+        self.refilter();
+    }
+
+    pub fn open(&mut self) {
+        self.is_open = true;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+}
+
+/// Case-insensitive prefix match, returning the matched character positions
+pub fn prefix_match(query: &str, candidate: &str) -> Option<Vec<usize>> {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    if candidate.len() < query.len() || candidate[..query.len()] != query[..] {
+        return None;
+    }
+    Some((0..query.len()).collect())
+}
+
+/// Case-insensitive subsequence match, returning the matched character positions
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<Vec<usize>> {
+    let mut positions = Vec::new();
+    let mut query_chars = query.to_lowercase().chars().collect::<Vec<_>>().into_iter().peekable();
+    for (i, c) in candidate.to_lowercase().chars().enumerate() {
+        match query_chars.peek() {
+            Some(&q) if q == c => {
+                positions.push(i);
+                query_chars.next();
+            }
+            Some(_) => {}
+            None => break,
+        }
+    }
+    if query_chars.peek().is_none() {
+        Some(positions)
+    } else {
+        None
+    }
+}
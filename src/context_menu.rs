@@ -0,0 +1,556 @@
+use crate::cursor::{request_cursor, CursorShape};
+use crate::dropdown::{draw_arrow_glyph, draw_item_row, draw_separator_row, ArrowDirection, ItemIcon, RowContent};
+use crate::style::Style;
+use crate::shortcuts::key_from_name;
+use crate::text::measure_text;
+use raylib::prelude::*;
+
+/// Gap between a submenu and the row that opened it
+const SUBMENU_OVERLAP: f32 = 2.0;
+
+/// A clickable menu row
+#[derive(Clone, Debug)]
+pub struct MenuItem {
+    pub id: String,
+    pub label: String,
+    /// Char index of the letter that activates the item while its menu is open
+    pub mnemonic: Option<usize>,
+    pub shortcut: Option<String>,
+    pub enabled: bool,
+    /// `Some` makes the item checkable
+    pub checked: Option<bool>,
+    pub icon: Option<ItemIcon>,
+    pub submenu: Vec<MenuEntry>,
+}
+
+impl MenuItem {
+    /// An item whose id is its label
+    pub fn new(label: &str) -> Self {
+        Self {
+            id: label.to_string(),
+            label: label.to_string(),
+            mnemonic: None,
+            shortcut: None,
+            enabled: true,
+            checked: None,
+            icon: None,
+            submenu: Vec::new(),
+        }
+    }
+
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+
+    /// Shortcut text shown on the right, e.g. "Ctrl+C"
+    pub fn with_shortcut(mut self, shortcut: &str) -> Self {
+        self.shortcut = Some(shortcut.to_string());
+        self
+    }
+
+    /// Underline the first `letter` in the label, ignoring case; pressing it activates the item while its menu is open
+    pub fn with_mnemonic(mut self, letter: char) -> Self {
+        self.mnemonic = self.label.chars().position(|c| c.to_lowercase().eq(letter.to_lowercase()));
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Make the item checkable, starting in the given state
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn with_icon(mut self, icon: ItemIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn with_submenu(mut self, entries: Vec<MenuEntry>) -> Self {
+        self.submenu = entries;
+        self
+    }
+
+    pub fn has_submenu(&self) -> bool {
+        !self.submenu.is_empty()
+    }
+
+    pub fn mnemonic_char(&self) -> Option<char> {
+        self.mnemonic.and_then(|index| self.label.chars().nth(index))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum MenuEntry {
+    Item(MenuItem),
+    Separator,
+}
+
+impl MenuEntry {
+    fn item(&self) -> Option<&MenuItem> {
+        match self {
+            MenuEntry::Item(item) => Some(item),
+            MenuEntry::Separator => None,
+        }
+    }
+
+    fn is_selectable(&self) -> bool {
+        self.item().is_some_and(|item| item.enabled)
+    }
+}
+
+/// Something the user did in the menu during the last update
+#[derive(Clone, Debug, PartialEq)]
+pub enum MenuEvent {
+    Activated(String),
+    Toggled(String, bool),
+}
+
+/// A popup menu opened by right-clicking inside `target`
+pub struct ContextMenu {
+    pub target: Rectangle,
+    pub entries: Vec<MenuEntry>,
+    pub style: Style,
+    pub item_height: f32,
+    pub min_width: f32,
+    pub is_open: bool,
+    pub position: Vector2,
+    /// Entry indices of the open submenus, one per level below the root
+    pub open_path: Vec<usize>,
+    /// Focused entry in the deepest open level
+    pub focus_index: Option<usize>,
+    pub events: Vec<MenuEvent>,
+    pub(crate) screen_bounds: Option<Rectangle>,
+    last_mouse: Vector2,
+}
+
+impl ContextMenu {
+    pub fn new(target: Rectangle, entries: Vec<MenuEntry>) -> Self {
+        Self {
+            target,
+            entries,
+            style: Style::default(),
+            item_height: 26.0,
+            min_width: 160.0,
+            is_open: false,
+            position: Vector2::zero(),
+            open_path: Vec::new(),
+            focus_index: None,
+            events: Vec::new(),
+            screen_bounds: None,
+            last_mouse: Vector2::zero(),
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_item_height(mut self, height: f32) -> Self {
+        self.item_height = height;
+        self
+    }
+
+    pub fn with_min_width(mut self, width: f32) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        self.events.clear();
+        self.screen_bounds = Some(Rectangle::new(
+            0.0,
+            0.0,
+            rl.get_screen_width() as f32,
+            rl.get_screen_height() as f32,
+        ));
+
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_RIGHT) {
+            if self.target.check_collision_point_rec(mouse) {
+                self.open_at(mouse);
+                self.last_mouse = mouse;
+                return;
+            }
+            self.close();
+        }
+
+        if !self.is_open {
+            return;
+        }
+
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) && !self.click_at(mouse) {
+            self.close();
+            return;
+        }
+
+        // Hover only follows the mouse when it moves, so it doesn't fight the keyboard
+        if mouse != self.last_mouse {
+            self.hover_at(mouse);
+            self.last_mouse = mouse;
+        }
+        self.request_hover_cursor(mouse);
+
+        self.handle_keyboard(rl);
+    }
+
+    /// Arrow keys, Enter/Space, Esc and item mnemonics for the open menu
+    pub fn handle_keyboard(&mut self, rl: &RaylibHandle) {
+        if rl.is_key_pressed(KeyboardKey::KEY_DOWN) {
+            self.move_focus(1);
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_UP) {
+            self.move_focus(-1);
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_RIGHT) {
+            self.open_focused_submenu();
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_LEFT) {
+            self.close_submenu();
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_ENTER) || rl.is_key_pressed(KeyboardKey::KEY_SPACE) {
+            self.activate_focused();
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_ESCAPE) {
+            self.close();
+            return;
+        }
+
+        // Leave Ctrl+letter and Alt+letter to accelerators and the menu bar
+        let ctrl = rl.is_key_down(KeyboardKey::KEY_LEFT_CONTROL) || rl.is_key_down(KeyboardKey::KEY_RIGHT_CONTROL);
+        let alt = rl.is_key_down(KeyboardKey::KEY_LEFT_ALT) || rl.is_key_down(KeyboardKey::KEY_RIGHT_ALT);
+        if ctrl || alt {
+            return;
+        }
+
+        let level = self.open_path.len();
+        let pressed = self.entries_at(level).iter().position(|entry| {
+            entry
+                .item()
+                .and_then(MenuItem::mnemonic_char)
+                .and_then(|letter| key_from_name(&letter.to_string()))
+                .is_some_and(|key| rl.is_key_pressed(key))
+        });
+        if let Some(index) = pressed {
+            self.focus_index = Some(index);
+            self.activate_focused();
+        }
+    }
+
+    pub fn open_at(&mut self, position: Vector2) {
+        self.position = position;
+        self.is_open = true;
+        self.open_path.clear();
+        self.focus_index = None;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.open_path.clear();
+        self.focus_index = None;
+    }
+
+    /// Entries shown at `level`; 0 is the root menu
+    fn entries_at(&self, level: usize) -> &[MenuEntry] {
+        let mut entries = self.entries.as_slice();
+        for &index in self.open_path.iter().take(level) {
+            match entries.get(index).and_then(MenuEntry::item) {
+                Some(item) => entries = &item.submenu,
+                None => return &[],
+            }
+        }
+        entries
+    }
+
+    /// Number of menus showing: the root plus open submenus
+    pub fn level_count(&self) -> usize {
+        if self.is_open {
+            self.open_path.len() + 1
+        } else {
+            0
+        }
+    }
+
+    fn row_height(&self, entry: &MenuEntry) -> f32 {
+        match entry {
+            MenuEntry::Item(_) => self.item_height,
+            MenuEntry::Separator => self.item_height / 2.0,
+        }
+    }
+
+    fn level_size(&self, level: usize) -> Vector2 {
+        let entries = self.entries_at(level);
+        let font_size = self.style.font_size;
+        let content_width = entries
+            .iter()
+            .filter_map(MenuEntry::item)
+            .map(|item| {
+                let mut width = measure_text(&item.label, font_size) + self.style.padding * 2.0;
+                if let Some(shortcut) = &item.shortcut {
+                    width += measure_text(shortcut, font_size) + self.style.padding * 3.0;
+                }
+                if item.checked.is_some() || item.icon.is_some() {
+                    width += self.item_height;
+                }
+                if item.has_submenu() {
+                    width += self.item_height / 2.0;
+                }
+                width
+            })
+            .fold(0.0, f32::max);
+        let height = entries.iter().map(|entry| self.row_height(entry)).sum();
+        Vector2::new(content_width.max(self.min_width), height)
+    }
+
+    /// Screen rectangles of the root menu and every submenu on `open_path`, worked out top-down
+    /// in one pass and flipped and clamped to stay on screen
+    fn level_rects(&self) -> Vec<Rectangle> {
+        let mut rects: Vec<Rectangle> = Vec::with_capacity(self.open_path.len() + 1);
+        for level in 0..=self.open_path.len() {
+            let size = self.level_size(level);
+            let parent = rects.last().copied();
+            let (mut x, mut y) = match parent {
+                None => (self.position.x, self.position.y),
+                Some(parent) => {
+                    let row = self.row_in(parent, level - 1, self.open_path[level - 1]);
+                    (parent.x + parent.width - SUBMENU_OVERLAP, row.y)
+                }
+            };
+
+            if let Some(area) = self.screen_bounds {
+                if x + size.x > area.x + area.width {
+                    // Submenus open to the left of their parent instead
+                    x = match parent {
+                        None => area.x + area.width - size.x,
+                        Some(parent) => parent.x - size.x + SUBMENU_OVERLAP,
+                    };
+                }
+                x = x.max(area.x);
+                y = y.min(area.y + area.height - size.y).max(area.y);
+            }
+            rects.push(Rectangle::new(x, y, size.x, size.y));
+        }
+        rects
+    }
+
+    /// Screen rectangle of the menu at `level`
+    pub fn level_bounds(&self, level: usize) -> Rectangle {
+        self.level_rects()[level]
+    }
+
+    pub fn row_bounds(&self, level: usize, index: usize) -> Rectangle {
+        self.row_in(self.level_bounds(level), level, index)
+    }
+
+    /// Row `index` of the menu at `level`, given where that menu is
+    fn row_in(&self, menu: Rectangle, level: usize, index: usize) -> Rectangle {
+        let entries = self.entries_at(level);
+        let y = menu.y
+            + entries
+                .iter()
+                .take(index)
+                .map(|entry| self.row_height(entry))
+                .sum::<f32>();
+        let height = entries.get(index).map_or(self.item_height, |entry| self.row_height(entry));
+        Rectangle::new(menu.x, y, menu.width, height)
+    }
+
+    /// The level and entry under `point`, deepest menu first
+    pub fn entry_at(&self, point: Vector2) -> Option<(usize, usize)> {
+        let rects = self.level_rects();
+        (0..self.level_count()).rev().find_map(|level| {
+            if !rects[level].check_collision_point_rec(point) {
+                return None;
+            }
+            let index = (0..self.entries_at(level).len())
+                .find(|&index| self.row_in(rects[level], level, index).check_collision_point_rec(point))?;
+            Some((level, index))
+        })
+    }
+
+    /// Focus the entry under the mouse, opening its submenu or closing deeper ones
+    pub fn hover_at(&mut self, mouse: Vector2) {
+        let Some((level, index)) = self.entry_at(mouse) else {
+            return;
+        };
+        self.open_path.truncate(level);
+        self.focus_index = Some(index);
+        let entry = &self.entries_at(level)[index];
+        if entry.is_selectable() && entry.item().is_some_and(MenuItem::has_submenu) {
+            self.open_path.push(index);
+            self.focus_index = None;
+        } else if !entry.is_selectable() {
+            self.focus_index = None;
+        }
+    }
+
+    /// Ask for the hand cursor while the mouse is over an entry that can be chosen
+    pub fn request_hover_cursor(&self, mouse: Vector2) {
+        if self.entry_at(mouse).is_some_and(|(level, index)| self.entries_at(level)[index].is_selectable()) {
+            request_cursor(CursorShape::PointingHand);
+        }
+    }
+
+    /// Handle a left click; returns false if it landed outside every open menu
+    pub fn click_at(&mut self, mouse: Vector2) -> bool {
+        let Some((level, index)) = self.entry_at(mouse) else {
+            return false;
+        };
+        self.open_path.truncate(level);
+        self.focus_index = Some(index);
+        self.activate_focused();
+        true
+    }
+
+    /// Move focus within the deepest open menu, skipping separators and disabled items
+    pub fn move_focus(&mut self, direction: i32) {
+        let entries = self.entries_at(self.open_path.len());
+        let count = entries.len() as i32;
+        if count == 0 {
+            return;
+        }
+        let mut index = match self.focus_index {
+            Some(index) => index as i32,
+            None if direction > 0 => -1,
+            None => count,
+        };
+        for _ in 0..count {
+            index = (index + direction).rem_euclid(count);
+            if entries[index as usize].is_selectable() {
+                self.focus_index = Some(index as usize);
+                return;
+            }
+        }
+    }
+
+    /// Open the focused item's submenu and focus its first item
+    pub fn open_focused_submenu(&mut self) {
+        let Some(index) = self.focus_index else {
+            return;
+        };
+        let level = self.open_path.len();
+        if self.entries_at(level)[index].item().is_some_and(MenuItem::has_submenu) {
+            self.open_path.push(index);
+            self.focus_index = None;
+            self.move_focus(1);
+        }
+    }
+
+    /// Close the deepest submenu, focusing the item that opened it
+    pub fn close_submenu(&mut self) {
+        if let Some(index) = self.open_path.pop() {
+            self.focus_index = Some(index);
+        }
+    }
+
+    /// Run the focused item: open its submenu, or toggle/activate it and close the menu
+    pub fn activate_focused(&mut self) {
+        let Some(index) = self.focus_index else {
+            return;
+        };
+        let level = self.open_path.len();
+        let Some(item) = self.entries_at(level).get(index).and_then(MenuEntry::item) else {
+            return;
+        };
+        if !item.enabled {
+            return;
+        }
+        if item.has_submenu() {
+            self.open_focused_submenu();
+            return;
+        }
+
+        let id = item.id.clone();
+        let path = self.open_path.clone();
+        let event = match self.entry_mut(&path, index) {
+            Some(MenuEntry::Item(MenuItem { checked: Some(checked), .. })) => {
+                *checked = !*checked;
+                MenuEvent::Toggled(id, *checked)
+            }
+            _ => MenuEvent::Activated(id),
+        };
+        self.events.push(event);
+        self.close();
+    }
+
+    fn entry_mut(&mut self, path: &[usize], index: usize) -> Option<&mut MenuEntry> {
+        let mut entries = &mut self.entries;
+        for &step in path {
+            match entries.get_mut(step)? {
+                MenuEntry::Item(item) => entries = &mut item.submenu,
+                MenuEntry::Separator => return None,
+            }
+        }
+        entries.get_mut(index)
+    }
+
+    /// Find an item anywhere in the menu by id
+    pub fn item(&self, id: &str) -> Option<&MenuItem> {
+        fn find<'a>(entries: &'a [MenuEntry], id: &str) -> Option<&'a MenuItem> {
+            entries.iter().filter_map(MenuEntry::item).find_map(|item| {
+                if item.id == id {
+                    Some(item)
+                } else {
+                    find(&item.submenu, id)
+                }
+            })
+        }
+        find(&self.entries, id)
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        let rects = self.level_rects();
+        for (level, &menu) in rects.iter().enumerate().take(self.level_count()) {
+            d.draw_rectangle_rec(menu, self.style.background_color);
+
+            for (index, entry) in self.entries_at(level).iter().enumerate() {
+                let row = self.row_in(menu, level, index);
+                let item = match entry {
+                    MenuEntry::Separator => {
+                        draw_separator_row(d, &self.style, row);
+                        continue;
+                    }
+                    MenuEntry::Item(item) => item,
+                };
+
+                let highlighted = self.open_path.get(level) == Some(&index)
+                    || (level == self.open_path.len() && self.focus_index == Some(index));
+                if highlighted {
+                    d.draw_rectangle_rec(row, self.style.hover_color);
+                }
+
+                let arrow_width = if item.has_submenu() { self.item_height / 2.0 } else { 0.0 };
+                let content = RowContent {
+                    label: &item.label,
+                    enabled: item.enabled,
+                    checked: item.checked,
+                    icon: item.icon.as_ref(),
+                    secondary_text: item.shortcut.as_deref(),
+                    right_inset: arrow_width,
+                    mnemonic: item.mnemonic,
+                };
+                draw_item_row(d, &self.style, row, &content);
+
+                if item.has_submenu() {
+                    let color = if item.enabled {
+                        self.style.text_color
+                    } else {
+                        self.style.text_color_disabled
+                    };
+                    let size = self.item_height * 0.15;
+                    let x = row.x + row.width - self.style.padding - size;
+                    let y = row.y + row.height / 2.0;
+                    let arrow = Rectangle::new(x - size, y - size, size * 2.0, size * 2.0);
+                    draw_arrow_glyph(d, arrow, ArrowDirection::Right, color);
+                }
+            }
+
+            d.draw_rectangle_lines_ex(menu, self.style.border_thickness, self.style.border_color);
+        }
+    }
+}
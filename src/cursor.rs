@@ -0,0 +1,85 @@
+use raylib::prelude::*;
+use std::cell::Cell;
+
+/// Mouse cursor shapes widgets can ask for
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CursorShape {
+    #[default]
+    Default,
+    IBeam,
+    PointingHand,
+    ResizeEW,
+    ResizeNS,
+    ResizeNWSE,
+    ResizeNESW,
+    NotAllowed,
+}
+
+impl CursorShape {
+    pub fn to_raylib(self) -> MouseCursor {
+        match self {
+            CursorShape::Default => MouseCursor::MOUSE_CURSOR_DEFAULT,
+            CursorShape::IBeam => MouseCursor::MOUSE_CURSOR_IBEAM,
+            CursorShape::PointingHand => MouseCursor::MOUSE_CURSOR_POINTING_HAND,
+            CursorShape::ResizeEW => MouseCursor::MOUSE_CURSOR_RESIZE_EW,
+            CursorShape::ResizeNS => MouseCursor::MOUSE_CURSOR_RESIZE_NS,
+            CursorShape::ResizeNWSE => MouseCursor::MOUSE_CURSOR_RESIZE_NWSE,
+            CursorShape::ResizeNESW => MouseCursor::MOUSE_CURSOR_RESIZE_NESW,
+            CursorShape::NotAllowed => MouseCursor::MOUSE_CURSOR_NOT_ALLOWED,
+        }
+    }
+}
+
+thread_local! {
+    /// This frame's request so far, and whether it came from a drag
+    static REQUEST: Cell<Option<(CursorShape, bool)>> = const { Cell::new(None) };
+}
+
+/// Ask for `shape` while the mouse is over a widget; the last request of the frame wins
+pub fn request_cursor(shape: CursorShape) {
+    REQUEST.with(|request| {
+        if !request.get().is_some_and(|(_, captured)| captured) {
+            request.set(Some((shape, false)));
+        }
+    });
+}
+
+/// Ask for `shape` during a drag, such as a resize, so widgets the mouse passes over can't change it
+pub fn capture_cursor(shape: CursorShape) {
+    REQUEST.with(|request| request.set(Some((shape, true))));
+}
+
+/// The shape requested so far this frame
+pub fn requested_cursor() -> Option<CursorShape> {
+    REQUEST.with(|request| request.get().map(|(shape, _)| shape))
+}
+
+/// Sets the cursor widgets asked for, once per frame and only when it changes
+#[derive(Debug, Default)]
+pub struct CursorResolver {
+    /// Shape last passed to raylib
+    pub current: CursorShape,
+}
+
+impl CursorResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take this frame's request, falling back to the default arrow; returns the shape if it changed
+    pub fn resolve(&mut self) -> Option<CursorShape> {
+        let shape = REQUEST.with(|request| request.take()).map_or(CursorShape::Default, |(shape, _)| shape);
+        if shape == self.current {
+            return None;
+        }
+        self.current = shape;
+        Some(shape)
+    }
+
+    /// Call once per frame, after updating every widget
+    pub fn apply(&mut self, rl: &RaylibHandle) {
+        if let Some(shape) = self.resolve() {
+            rl.set_mouse_cursor(shape.to_raylib());
+        }
+    }
+}
@@ -1,35 +1,212 @@
 use raylib::prelude::*;
+use crate::checkbox::CheckState;
+use crate::cursor::{capture_cursor, request_cursor, CursorShape};
+use crate::scroll::ScrollAxis;
+use crate::skin::SkinState;
 use crate::style::Style;
+use crate::text::{draw_mnemonic_underline, measure_text};
+use crate::tooltip::Tooltip;
+use std::cell::{Cell, Ref, RefCell};
+use std::rc::Rc;
 
-pub struct Dropdown {
+/// Seconds of inactivity after which type-ahead starts a new prefix
+const TYPE_AHEAD_TIMEOUT: f64 = 1.0;
+
+/// Thickness of the scroll bars on scrolling lists and areas
+pub(crate) const SCROLLBAR_WIDTH: f32 = 10.0;
+const MIN_THUMB_LENGTH: f32 = 16.0;
+
+/// Next character typed this frame; raylib-rs only offers this on `&mut RaylibHandle`
+fn next_char_pressed() -> Option<char> {
+    let code = unsafe { raylib::ffi::GetCharPressed() };
+    if code > 0 {
+        char::from_u32(code as u32)
+    } else {
+        None
+    }
+}
+
+/// How a multi-select dropdown shows its selection while closed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SelectionSummary {
+    /// "3 selected"
+    Count,
+    /// One removable chip per selected item
+    Chips,
+}
+
+/// Icon drawn to the left of a dropdown item
+#[derive(Clone, Debug)]
+pub enum ItemIcon {
+    Texture(Rc<Texture2D>),
+    Glyph(String),
+}
+
+/// A selectable dropdown entry: its value plus how it is presented
+pub struct DropdownItem<T> {
+    pub value: T,
+    pub enabled: bool,
+    pub icon: Option<ItemIcon>,
+    pub secondary_text: Option<String>,
+}
+
+impl<T> DropdownItem<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            enabled: true,
+            icon: None,
+            secondary_text: None,
+        }
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn with_icon(mut self, icon: ItemIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Right-aligned text such as a shortcut or a count
+    pub fn with_secondary_text(mut self, text: &str) -> Self {
+        self.secondary_text = Some(text.to_string());
+        self
+    }
+}
+
+/// A non-selectable row drawn between items
+#[derive(Clone, Debug, PartialEq)]
+pub enum RowMarker {
+    Header(String),
+    Separator,
+}
+
+/// What a list row shows, in display order
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ListRow {
+    Clear,
+    Item(usize),
+    Header(usize),
+    Separator,
+}
+
+/// Where the open list goes: its bounds, how many rows fit and whether it opens upward
+type Placement = (Rectangle, usize, bool);
+
+/// Everything the list placement depends on
+#[derive(Clone, Copy, PartialEq)]
+struct PlacementKey {
+    bounds: Rectangle,
+    area: Option<Rectangle>,
+    rows: usize,
+    max_visible_items: usize,
+    item_height: f32,
+    multi_select: bool,
+}
+
+/// Rows built for a given number of items and markers and whether there is a clear row
+#[derive(Default)]
+struct RowCache {
+    key: Option<(usize, usize, bool)>,
+    rows: Vec<ListRow>,
+}
+
+/// A dropdown holding typed values, shown through a display closure
+pub struct Dropdown<T = String> {
     pub bounds: Rectangle,
-    pub items: Vec<String>,
+    pub items: Vec<DropdownItem<T>>,
+    /// Headers and separators, changed only through methods so the row cache sees every change
+    markers: Vec<(usize, RowMarker)>,
+    pub display: Box<dyn Fn(&T) -> String>,
     pub selected_index: Option<usize>,
     pub is_open: bool,
+    pub is_hovered: bool,
     pub style: Style,
     pub hover_index: Option<usize>,
     pub max_visible_items: usize,
     pub scroll_offset: usize,
+    pub item_height: f32,
+    pub parent_bounds: Option<Rectangle>,
+    pub is_scrolling: bool,
+    pub placeholder: String,
+    pub clear_row: Option<String>,
+    pub multi_select: bool,
+    pub selected_indices: Vec<usize>,
+    pub summary: SelectionSummary,
+    pub clear_on_escape: bool,
+    pub type_ahead: String,
+    pub tooltip: Option<Tooltip>,
+    type_ahead_time: f64,
+    last_mouse: Vector2,
+    screen_bounds: Option<Rectangle>,
+    scroll_grab: f32,
+    row_cache: RefCell<RowCache>,
+    placement_cache: Cell<Option<(PlacementKey, Placement)>>,
+}
+
+impl<T: ToString + 'static> Dropdown<T> {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, items: Vec<T>) -> Self {
+        Self::new_with_display(x, y, width, height, items, |item: &T| item.to_string())
+    }
+
+    /// Create a dropdown from an enum that implements ToString, keeping the values themselves
+    pub fn from_enum(x: f32, y: f32, width: f32, height: f32, options: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(x, y, width, height, options.to_vec())
+    }
 }
 
-impl Dropdown {
-    pub fn new(x: f32, y: f32, width: f32, height: f32, items: Vec<String>) -> Self {
+impl<T> Dropdown<T> {
+    /// Create a dropdown for values that are labelled by `display`
+    pub fn new_with_display(
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        items: Vec<T>,
+        display: impl Fn(&T) -> String + 'static,
+    ) -> Self {
         Self {
             bounds: Rectangle::new(x, y, width, height),
-            items,
+            items: items.into_iter().map(DropdownItem::new).collect(),
+            markers: Vec::new(),
+            display: Box::new(display),
             selected_index: None,
             is_open: false,
+            is_hovered: false,
             style: Style::default(),
             hover_index: None,
             max_visible_items: 5,
             scroll_offset: 0,
+            item_height: height,
+            parent_bounds: None,
+            is_scrolling: false,
+            placeholder: String::new(),
+            clear_row: None,
+            multi_select: false,
+            selected_indices: Vec::new(),
+            summary: SelectionSummary::Count,
+            clear_on_escape: false,
+            type_ahead: String::new(),
+            tooltip: None,
+            type_ahead_time: 0.0,
+            last_mouse: Vector2::zero(),
+            screen_bounds: None,
+            scroll_grab: 0.0,
+            row_cache: RefCell::default(),
+            placement_cache: Cell::new(None),
         }
     }
 
-    /// Create a dropdown from an enum that implements ToString
-    pub fn from_enum<T: ToString>(x: f32, y: f32, width: f32, height: f32, options: &[T]) -> Self {
-        let items: Vec<String> = options.iter().map(|opt| opt.to_string()).collect();
-        Self::new(x, y, width, height, items)
+    /// Replace how items are turned into text
+    pub fn with_display(mut self, display: impl Fn(&T) -> String + 'static) -> Self {
+        self.display = Box::new(display);
+        self
     }
 
     pub fn with_style(mut self, style: Style) -> Self {
@@ -37,13 +214,121 @@ impl Dropdown {
         self
     }
 
+    /// Show `text` in a tooltip after hovering
+    pub fn with_tooltip(mut self, text: &str) -> Self {
+        self.tooltip = Some(Tooltip::new(text));
+        self
+    }
+
+    /// Text shown in the closed box while nothing is selected
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    /// Add a row above the items that clears the selection; it is not an item and does not shift indices
     pub fn with_deselect_option(mut self, label: &str) -> Self {
-        self.items.insert(0, label.to_string());
+        self.clear_row = Some(label.to_string());
+        self
+    }
+
+    /// Height of each list row, independent of the closed box height
+    pub fn with_item_height(mut self, height: f32) -> Self {
+        self.item_height = height;
+        self
+    }
+
+    /// Keep the open list inside `parent` instead of the screen
+    pub fn with_parent_bounds(mut self, parent: Rectangle) -> Self {
+        self.parent_bounds = Some(parent);
         self
     }
 
-    fn has_deselect_option(&self) -> bool {
-        self.items.get(0).map(|s| s == "None" || s == "Deselect" || s == "Clear").unwrap_or(false)
+    /// Make Esc clear the selection as well as closing the list
+    pub fn with_clear_on_escape(mut self, clear: bool) -> Self {
+        self.clear_on_escape = clear;
+        self
+    }
+
+    /// Allow several items to be selected; the list stays open while toggling
+    pub fn with_multi_select(mut self, summary: SelectionSummary) -> Self {
+        self.multi_select = true;
+        self.summary = summary;
+        self
+    }
+
+    /// Append a header followed by its items
+    pub fn with_group(mut self, header: &str, values: Vec<T>) -> Self {
+        self.add_header(header);
+        self.items.extend(values.into_iter().map(DropdownItem::new));
+        self
+    }
+
+    /// Every list row in display order, rebuilt only when items, markers or the clear row are added or removed
+    fn rows(&self) -> Ref<'_, Vec<ListRow>> {
+        let key = (self.items.len(), self.markers.len(), self.clear_row.is_some());
+        if self.row_cache.borrow().key != Some(key) {
+            *self.row_cache.borrow_mut() = RowCache {
+                key: Some(key),
+                rows: self.build_rows(),
+            };
+        }
+        Ref::map(self.row_cache.borrow(), |cache| &cache.rows)
+    }
+
+    /// The clear row, then items with their headers and separators
+    fn build_rows(&self) -> Vec<ListRow> {
+        let mut rows = Vec::with_capacity(self.items.len() + self.markers.len() + 1);
+        if self.clear_row.is_some() {
+            rows.push(ListRow::Clear);
+        }
+        for index in 0..=self.items.len() {
+            for (marker_index, (before, marker)) in self.markers.iter().enumerate() {
+                if *before == index {
+                    rows.push(match marker {
+                        RowMarker::Header(_) => ListRow::Header(marker_index),
+                        RowMarker::Separator => ListRow::Separator,
+                    });
+                }
+            }
+            if index < self.items.len() {
+                rows.push(ListRow::Item(index));
+            }
+        }
+        rows
+    }
+
+    fn row_count(&self) -> usize {
+        self.rows().len()
+    }
+
+    /// Item shown on a list row, or None for the clear row, headers and separators
+    fn row_item(&self, row: usize) -> Option<usize> {
+        match self.rows().get(row) {
+            Some(ListRow::Item(index)) => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// Whether hovering, clicking or the keyboard can land on a row
+    fn row_selectable(&self, row: usize) -> bool {
+        match self.rows().get(row) {
+            Some(ListRow::Clear) => true,
+            Some(ListRow::Item(index)) => self.items[*index].enabled,
+            _ => false,
+        }
+    }
+
+    fn row_label(&self, row: usize) -> String {
+        match self.rows().get(row) {
+            Some(ListRow::Item(index)) => (self.display)(&self.items[*index].value),
+            Some(ListRow::Clear) => self.clear_row.clone().unwrap_or_default(),
+            Some(ListRow::Header(marker)) => match &self.markers[*marker].1 {
+                RowMarker::Header(label) => label.clone(),
+                RowMarker::Separator => String::new(),
+            },
+            _ => String::new(),
+        }
     }
 
     pub fn set_colors(&mut self, background: Color, border: Color, text: Color, hover: Color) {
@@ -54,32 +339,56 @@ impl Dropdown {
     }
 
     pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
-        self.hover_index = None;
+        let was_open = self.is_open;
+        self.screen_bounds = Some(Rectangle::new(
+            0.0,
+            0.0,
+            rl.get_screen_width() as f32,
+            rl.get_screen_height() as f32,
+        ));
+
+        // The tooltip describes the closed box; it would cover the list once open
+        self.is_hovered = self.bounds.check_collision_point_rec(mouse);
+        if let Some(tooltip) = &mut self.tooltip {
+            tooltip.update(self.is_hovered && !self.is_open, mouse, self.bounds, rl);
+        }
 
         // Handle main dropdown box click
         if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
             if self.bounds.check_collision_point_rec(mouse) {
-                self.is_open = !self.is_open;
+                // Clicking a chip's "x" removes it instead of opening the list
+                let removed_chip = if self.multi_select && self.summary == SelectionSummary::Chips {
+                    self.chip_layout()
+                        .0
+                        .into_iter()
+                        .find(|(_, _, close)| close.check_collision_point_rec(mouse))
+                        .map(|(idx, _, _)| idx)
+                } else {
+                    None
+                };
+                match removed_chip {
+                    Some(idx) => self.toggle_index(idx),
+                    None => self.is_open = !self.is_open,
+                }
+            } else if self.is_open && self.scrollbar_track().is_some_and(|track| track.check_collision_point_rec(mouse)) {
+                self.start_scroll_drag(mouse);
+            } else if self.is_open && self.multi_select {
+                self.handle_multi_select_click(mouse);
             } else if self.is_open {
                 // Check clicks on dropdown items
                 let mut clicked_item = false;
-                let visible_items = self.items.len().min(self.max_visible_items);
+                let visible_items = self.visible_row_count();
                 for i in 0..visible_items {
-                    let item_bounds = self.get_item_bounds(i);
+                    let item_bounds = self.list_item_bounds(i);
                     if item_bounds.check_collision_point_rec(mouse) {
                         clicked_item = true;
-                        if self.has_deselect_option() && i + self.scroll_offset == 0 {
-                            // Deselect option
-                            self.selected_index = None;
-                        } else {
-                            let idx = if self.has_deselect_option() {
-                                i + self.scroll_offset - 1
-                            } else {
-                                i + self.scroll_offset
-                            };
-                            self.selected_index = Some(idx);
+                        // Headers, separators and disabled items keep the list open
+                        let row = i + self.scroll_offset;
+                        if self.row_selectable(row) {
+                            // The clear row maps to None
+                            self.selected_index = self.row_item(row);
+                            self.is_open = false;
                         }
-                        self.is_open = false;
                         break;
                     }
                 }
@@ -90,64 +399,227 @@ impl Dropdown {
             }
         }
 
-        // Handle Esc key to clear selection if open
-        if self.is_open && rl.is_key_pressed(KeyboardKey::KEY_ESCAPE) {
-            self.selected_index = None;
-            self.is_open = false;
+        // Start keyboard navigation from the current selection
+        if self.is_open && !was_open {
+            self.hover_index = self.selected_row();
+            if let Some(row) = self.hover_index {
+                self.scroll_to_row(row);
+            }
+        }
+
+        if self.is_open {
+            self.handle_keyboard(rl);
         }
 
         // Handle scrolling when dropdown is open
-        if self.is_open && self.items.len() > self.max_visible_items {
+        if self.is_open && self.max_scroll() > 0 {
             let wheel_move = rl.get_mouse_wheel_move() as i32;
             if wheel_move != 0 {
                 self.scroll_offset = (self.scroll_offset as i32 - wheel_move)
                     .max(0)
-                    .min((self.items.len() - self.max_visible_items) as i32) as usize;
+                    .min(self.max_scroll() as i32) as usize;
             }
         }
 
-        // Update hover state
-        if self.is_open {
-            let visible_items = self.items.len().min(self.max_visible_items);
+        // Handle scroll bar dragging - continue even if mouse moves outside the scroll bar
+        if self.is_scrolling && self.is_open && rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.drag_scroll(mouse);
+        }
+        if !self.is_open || rl.is_mouse_button_released(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.is_scrolling = false;
+        }
+
+        // Update hover state; the mouse only takes over from the keyboard when it moves
+        let mouse_moved = mouse.x != self.last_mouse.x || mouse.y != self.last_mouse.y;
+        self.last_mouse = mouse;
+        if !self.is_open {
+            self.hover_index = None;
+        } else if mouse_moved && !self.is_scrolling {
+            let visible_items = self.visible_row_count();
             for i in 0..visible_items {
-                let item_bounds = self.get_item_bounds(i);
+                let item_bounds = self.list_item_bounds(i);
                 if item_bounds.check_collision_point_rec(mouse) {
-                    self.hover_index = Some(i + self.scroll_offset);
+                    let row = i + self.scroll_offset;
+                    self.hover_index = if self.row_selectable(row) { Some(row) } else { None };
                     break;
                 }
             }
         }
+
+        let over_row = self.is_open
+            && (0..self.visible_row_count())
+                .any(|i| self.list_item_bounds(i).check_collision_point_rec(mouse) && self.row_selectable(i + self.scroll_offset));
+        if self.is_hovered || over_row {
+            request_cursor(CursorShape::PointingHand);
+        }
+        if let Some(track) = self.scrollbar_track().filter(|_| self.is_open) {
+            scrollbar_cursor(ScrollAxis::Vertical, self.scrollbar_thumb(track), mouse, self.is_scrolling);
+        }
+    }
+
+    fn handle_keyboard(&mut self, rl: &RaylibHandle) {
+        let key_down = |key| rl.is_key_pressed(key) || rl.is_key_pressed_repeat(key);
+        let page = self.visible_row_count().max(1) as i32;
+
+        if key_down(KeyboardKey::KEY_DOWN) {
+            self.move_hover(1);
+        } else if key_down(KeyboardKey::KEY_UP) {
+            self.move_hover(-1);
+        } else if key_down(KeyboardKey::KEY_PAGE_DOWN) {
+            self.move_hover(page);
+        } else if key_down(KeyboardKey::KEY_PAGE_UP) {
+            self.move_hover(-page);
+        } else if rl.is_key_pressed(KeyboardKey::KEY_HOME) {
+            self.move_hover(-(self.row_count() as i32));
+        } else if rl.is_key_pressed(KeyboardKey::KEY_END) {
+            self.move_hover(self.row_count() as i32);
+        }
+
+        // Type-ahead: letters typed in quick succession build up a prefix
+        let now = rl.get_time();
+        if now - self.type_ahead_time > TYPE_AHEAD_TIMEOUT {
+            self.type_ahead.clear();
+        }
+        let typing = !self.type_ahead.is_empty();
+        while let Some(c) = next_char_pressed() {
+            // A space only extends a prefix that is already being typed
+            if c == ' ' && self.type_ahead.is_empty() {
+                continue;
+            }
+            self.type_ahead_time = now;
+            self.type_ahead_char(c);
+        }
+
+        if rl.is_key_pressed(KeyboardKey::KEY_ENTER) || (rl.is_key_pressed(KeyboardKey::KEY_SPACE) && !typing) {
+            self.activate_hovered();
+        }
+
+        if rl.is_key_pressed(KeyboardKey::KEY_ESCAPE) {
+            if self.clear_on_escape {
+                self.clear_selection();
+            }
+            self.close();
+        }
+    }
+
+    /// Row showing the current selection
+    fn selected_row(&self) -> Option<usize> {
+        let index = self.selected_index?;
+        self.rows().iter().position(|row| *row == ListRow::Item(index))
+    }
+
+    /// Scroll the list just enough for `row` to be visible
+    fn scroll_to_row(&mut self, row: usize) {
+        if row < self.scroll_offset {
+            self.scroll_offset = row;
+        } else if row >= self.scroll_offset + self.visible_row_count() {
+            self.scroll_offset = row + 1 - self.visible_row_count();
+        }
+    }
+
+    /// Move the hovered row by `delta`, clamped to the list and skipping rows that can't be selected
+    pub fn move_hover(&mut self, delta: i32) {
+        let rows = self.row_count() as i32;
+        if rows == 0 {
+            return;
+        }
+        let start = match self.hover_index {
+            Some(row) => (row as i32 + delta).clamp(0, rows - 1),
+            None if delta < 0 => rows - 1,
+            None => 0,
+        };
+        // Look further in the direction of travel first, then back the other way
+        let step = if delta < 0 { -1 } else { 1 };
+        if let Some(target) = self.nearest_selectable(start, step).or_else(|| self.nearest_selectable(start, -step)) {
+            self.hover_index = Some(target);
+            self.scroll_to_row(target);
+        }
+    }
+
+    fn nearest_selectable(&self, from: i32, step: i32) -> Option<usize> {
+        let rows = self.row_count() as i32;
+        let mut row = from;
+        while (0..rows).contains(&row) {
+            if self.row_selectable(row as usize) {
+                return Some(row as usize);
+            }
+            row += step;
+        }
+        None
+    }
+
+    /// Add a typed character to the type-ahead prefix and hover the next matching row
+    pub fn type_ahead_char(&mut self, c: char) {
+        self.type_ahead.push(c);
+        // A single letter cycles through matches, a longer prefix refines the current one
+        let start = match self.hover_index {
+            Some(row) if self.type_ahead.chars().count() == 1 => row + 1,
+            Some(row) => row,
+            None => 0,
+        };
+        if let Some(row) = self.find_prefix(&self.type_ahead, start) {
+            self.hover_index = Some(row);
+            self.scroll_to_row(row);
+        }
+    }
+
+    /// First item row at or after `start` (wrapping) whose label starts with `prefix`, ignoring case
+    pub fn find_prefix(&self, prefix: &str, start: usize) -> Option<usize> {
+        let rows = self.row_count();
+        let prefix = prefix.to_lowercase();
+        (0..rows)
+            .map(|i| (start + i) % rows)
+            .find(|&row| {
+                self.row_item(row).is_some()
+                    && self.row_selectable(row)
+                    && self.row_label(row).to_lowercase().starts_with(&prefix)
+            })
+    }
+
+    /// Select (or toggle, in multi-select mode) the hovered row
+    pub fn activate_hovered(&mut self) {
+        let Some(row) = self.hover_index.filter(|&row| self.row_selectable(row)) else {
+            return;
+        };
+        match (self.row_item(row), self.multi_select) {
+            (Some(index), true) => self.toggle_index(index),
+            (None, true) => self.clear_selection(),
+            (item, false) => {
+                self.selected_index = item;
+                self.close();
+            }
+        }
     }
 
     pub fn draw(&self, d: &mut impl RaylibDraw) {
         // Draw main dropdown box
-        d.draw_rectangle_rec(self.bounds, self.style.background_color);
-        d.draw_rectangle_lines_ex(self.bounds, self.style.border_thickness, self.style.border_color);
+        let state = SkinState::from_flags(true, false, self.is_open, self.is_hovered);
+        if !self.style.draw_skin(d, self.bounds, state) {
+            d.draw_rectangle_rec(self.bounds, self.style.background_color);
+            d.draw_rectangle_lines_ex(self.bounds, self.style.border_thickness, self.style.border_color);
+        }
 
         // Draw selected item or placeholder
-        if let Some(selected) = self.selected_index {
-            if self.has_deselect_option() {
-                let real_idx = selected + 1;
-                if real_idx < self.items.len() {
-                    d.draw_text(
-                        &self.items[real_idx],
-                        self.bounds.x as i32 + self.style.padding as i32,
-                        (self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0) as i32,
-                        self.style.font_size,
-                        self.style.text_color,
-                    );
-                }
-            } else {
-                if selected < self.items.len() {
-                    d.draw_text(
-                        &self.items[selected],
-                        self.bounds.x as i32 + self.style.padding as i32,
-                        (self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0) as i32,
-                        self.style.font_size,
-                        self.style.text_color,
-                    );
-                }
-            }
+        if self.multi_select {
+            self.draw_multi_summary(d);
+        } else if let Some(label) = self.get_selected_item() {
+            d.draw_text(
+                &label,
+                self.bounds.x as i32 + self.style.padding as i32,
+                (self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0) as i32,
+                self.style.font_size,
+                self.style.text_color,
+            );
+        }
+
+        if self.selected_indices.is_empty() && self.selected_index.is_none() && !self.placeholder.is_empty() {
+            d.draw_text(
+                &self.placeholder,
+                self.bounds.x as i32 + self.style.padding as i32,
+                (self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0) as i32,
+                self.style.font_size,
+                self.style.placeholder_color,
+            );
         }
 
         // Draw dropdown arrow
@@ -155,100 +627,395 @@ impl Dropdown {
         let arrow_x = self.bounds.x + self.bounds.width - arrow_size - self.style.padding;
         let arrow_y = self.bounds.y + (self.bounds.height - arrow_size) / 2.0;
         
-        d.draw_triangle(
-            Vector2::new(arrow_x, arrow_y),
-            Vector2::new(arrow_x + arrow_size, arrow_y),
-            Vector2::new(arrow_x + arrow_size / 2.0, arrow_y + arrow_size),
+        // Point towards the list when it opens above the box
+        let direction = if self.is_open && self.opens_upward() {
+            ArrowDirection::Up
+        } else {
+            ArrowDirection::Down
+        };
+        draw_arrow_glyph(
+            d,
+            Rectangle::new(arrow_x, arrow_y, arrow_size, arrow_size),
+            direction,
             self.style.text_color,
         );
 
         // Draw dropdown items when open
         if self.is_open {
-            let visible_items = self.items.len().min(self.max_visible_items);
+            let visible_items = self.visible_row_count();
             for i in 0..visible_items {
-                let item_index = i + self.scroll_offset;
-                if item_index >= self.items.len() {
+                let row = i + self.scroll_offset;
+                if row >= self.row_count() {
                     break;
                 }
 
-                let item_bounds = self.get_item_bounds(i);
-                let background_color = if Some(item_index) == self.hover_index {
-                    self.style.hover_color
-                } else {
-                    self.style.background_color
-                };
+                self.draw_row(d, self.list_item_bounds(i), row);
+            }
 
-                d.draw_rectangle_rec(item_bounds, background_color);
-                d.draw_rectangle_lines_ex(item_bounds, self.style.border_thickness, self.style.border_color);
-                d.draw_text(
-                    &self.items[item_index],
-                    item_bounds.x as i32 + self.style.padding as i32,
-                    (item_bounds.y + (item_bounds.height - self.style.font_size as f32) / 2.0) as i32,
-                    self.style.font_size,
-                    self.style.text_color,
-                );
+            if self.multi_select {
+                self.draw_bulk_actions(d);
             }
 
-            // Draw scroll indicators if needed
-            if self.items.len() > self.max_visible_items {
-                if self.scroll_offset > 0 {
-                    self.draw_scroll_indicator(d, true);
-                }
-                if self.scroll_offset < self.items.len() - self.max_visible_items {
-                    self.draw_scroll_indicator(d, false);
-                }
+            self.draw_scrollbar(d);
+        }
+    }
+
+    /// Area the open list has to stay inside: the parent if set, otherwise the screen
+    fn clamp_area(&self) -> Option<Rectangle> {
+        self.parent_bounds.or(self.screen_bounds)
+    }
+
+    /// Where the open list goes, worked out again only when the box, area, rows or row height change
+    fn placement(&self) -> Placement {
+        let key = PlacementKey {
+            bounds: self.bounds,
+            area: self.clamp_area(),
+            rows: self.row_count(),
+            max_visible_items: self.max_visible_items,
+            item_height: self.item_height,
+            multi_select: self.multi_select,
+        };
+        if let Some((cached, placement)) = self.placement_cache.get() {
+            if cached == key {
+                return placement;
             }
         }
+        let placement = self.compute_placement();
+        self.placement_cache.set(Some((key, placement)));
+        placement
+    }
+
+    /// Where the open list goes and how many rows fit, flipping above the box when there is more room there
+    fn compute_placement(&self) -> Placement {
+        let extra_rows = self.multi_select as usize;
+        let wanted_rows = self.row_count().min(self.max_visible_items);
+        let Some(area) = self.clamp_area() else {
+            let height = (wanted_rows + extra_rows) as f32 * self.item_height;
+            let list = Rectangle::new(self.bounds.x, self.bounds.y + self.bounds.height, self.bounds.width, height);
+            return (list, wanted_rows, false);
+        };
+
+        let space_below = area.y + area.height - (self.bounds.y + self.bounds.height);
+        let space_above = self.bounds.y - area.y;
+        let needed = (wanted_rows + extra_rows) as f32 * self.item_height;
+        let opens_up = needed > space_below && space_above > space_below;
+
+        // Show fewer rows (and scroll) when neither side has room for all of them
+        let space = if opens_up { space_above } else { space_below };
+        let fitting_rows = ((space / self.item_height) as usize).saturating_sub(extra_rows).max(1);
+        let rows = wanted_rows.min(fitting_rows);
+        let height = (rows + extra_rows) as f32 * self.item_height;
+
+        let y = if opens_up { self.bounds.y - height } else { self.bounds.y + self.bounds.height };
+        let x = self.bounds.x.min(area.x + area.width - self.bounds.width).max(area.x);
+        (Rectangle::new(x, y, self.bounds.width, height), rows, opens_up)
+    }
+
+    /// Bounds of the whole open list, including the select-all / clear-all row
+    pub fn list_bounds(&self) -> Rectangle {
+        self.placement().0
+    }
+
+    /// Number of rows shown at once after fitting the list into the screen or parent
+    pub fn visible_row_count(&self) -> usize {
+        self.placement().1
+    }
+
+    pub fn opens_upward(&self) -> bool {
+        self.placement().2
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.row_count().saturating_sub(self.visible_row_count())
     }
 
     fn get_item_bounds(&self, index: usize) -> Rectangle {
+        let list = self.list_bounds();
         Rectangle::new(
-            self.bounds.x,
-            self.bounds.y + self.bounds.height * (index as f32 + 1.0),
-            self.bounds.width,
-            self.bounds.height,
+            list.x,
+            list.y + self.item_height * index as f32,
+            list.width,
+            self.item_height,
         )
     }
 
-    fn draw_scroll_indicator(&self, d: &mut impl RaylibDraw, is_up: bool) {
-        let x = self.bounds.x + self.bounds.width - 15.0;
-        let y = if is_up {
-            self.bounds.y + self.bounds.height
+    /// Track along the right edge of the item rows, present only when the list scrolls
+    fn scrollbar_track(&self) -> Option<Rectangle> {
+        if self.max_scroll() == 0 {
+            return None;
+        }
+        let first = self.list_item_bounds(0);
+        let rows = Rectangle::new(first.x, first.y, first.width, self.item_height * self.visible_row_count() as f32);
+        Some(scrollbar_track(rows))
+    }
+
+    fn scrollbar_thumb(&self, track: Rectangle) -> Rectangle {
+        let (visible, total) = (self.visible_row_count() as f32, self.row_count() as f32);
+        scrollbar_thumb(ScrollAxis::Vertical, track, visible, total, self.scroll_offset as f32)
+    }
+
+    /// Grab the thumb where it was clicked, or centre it on the click when the track was hit
+    fn start_scroll_drag(&mut self, mouse: Vector2) {
+        let Some(track) = self.scrollbar_track() else {
+            return;
+        };
+        self.scroll_grab = start_scroll_drag(ScrollAxis::Vertical, self.scrollbar_thumb(track), mouse);
+        self.is_scrolling = true;
+        self.drag_scroll(mouse);
+    }
+
+    fn drag_scroll(&mut self, mouse: Vector2) {
+        let Some(track) = self.scrollbar_track() else {
+            return;
+        };
+        let thumb = self.scrollbar_thumb(track);
+        let offset = drag_scroll(ScrollAxis::Vertical, track, thumb, self.scroll_grab, mouse, self.max_scroll() as f32);
+        self.scroll_offset = offset.round() as usize;
+    }
+
+    fn draw_scrollbar(&self, d: &mut impl RaylibDraw) {
+        if let Some(track) = self.scrollbar_track() {
+            draw_scrollbar(d, &self.style, track, self.scrollbar_thumb(track), self.is_scrolling);
+        }
+    }
+
+    fn draw_row(&self, d: &mut impl RaylibDraw, bounds: Rectangle, row: usize) {
+        let background_color = if Some(row) == self.hover_index {
+            self.style.hover_color
         } else {
-            self.bounds.y + self.bounds.height * (self.max_visible_items as f32 + 1.0)
+            self.style.background_color
         };
+        d.draw_rectangle_rec(bounds, background_color);
+        d.draw_rectangle_lines_ex(bounds, self.style.border_thickness, self.style.border_color);
 
-        d.draw_triangle(
-            Vector2::new(x, y + (if is_up { 10.0 } else { 0.0 })),
-            Vector2::new(x + 10.0, y + (if is_up { 10.0 } else { 0.0 })),
-            Vector2::new(x + 5.0, y + (if is_up { 0.0 } else { 10.0 })),
-            self.style.text_color,
-        );
+        let text_y = (bounds.y + (bounds.height - self.style.font_size as f32) / 2.0) as i32;
+        let item = match self.rows().get(row) {
+            Some(ListRow::Item(index)) => *index,
+            Some(ListRow::Separator) => {
+                draw_separator_row(d, &self.style, bounds);
+                return;
+            }
+            Some(ListRow::Header(_)) => {
+                d.draw_text(
+                    &self.row_label(row),
+                    bounds.x as i32 + self.style.padding as i32,
+                    text_y,
+                    self.style.font_size,
+                    self.style.active_color,
+                );
+                return;
+            }
+            _ => {
+                d.draw_text(
+                    &self.row_label(row),
+                    bounds.x as i32 + self.style.padding as i32,
+                    text_y,
+                    self.style.font_size,
+                    self.style.text_color,
+                );
+                return;
+            }
+        };
+
+        let entry = &self.items[item];
+        let label = self.row_label(row);
+        let content = RowContent {
+            label: &label,
+            enabled: entry.enabled,
+            checked: self.multi_select.then(|| self.selected_indices.contains(&item)),
+            icon: entry.icon.as_ref(),
+            secondary_text: entry.secondary_text.as_deref(),
+            right_inset: if self.max_scroll() > 0 { SCROLLBAR_WIDTH } else { 0.0 },
+            mnemonic: None,
+        };
+        draw_item_row(d, &self.style, bounds, &content);
     }
 
-    pub fn get_selected_item(&self) -> Option<&String> {
-        if let Some(idx) = self.selected_index {
-            if self.has_deselect_option() {
-                // If deselect option is present, real items start at index 1
-                let real_idx = idx + 1;
-                if real_idx < self.items.len() {
-                    return Some(&self.items[real_idx]);
-                } else {
-                    return None;
+    /// Bounds of the i-th visible list row, below the select-all / clear-all row in multi-select mode
+    fn list_item_bounds(&self, index: usize) -> Rectangle {
+        self.get_item_bounds(index + self.multi_select as usize)
+    }
+
+    fn bulk_action_bounds(&self) -> (Rectangle, Rectangle) {
+        let row = self.get_item_bounds(0);
+        let half = row.width / 2.0;
+        (
+            Rectangle::new(row.x, row.y, half, row.height),
+            Rectangle::new(row.x + half, row.y, half, row.height),
+        )
+    }
+
+    fn handle_multi_select_click(&mut self, mouse: Vector2) {
+        let (select_all, clear_all) = self.bulk_action_bounds();
+        if select_all.check_collision_point_rec(mouse) {
+            self.select_all();
+            return;
+        }
+        if clear_all.check_collision_point_rec(mouse) {
+            self.clear_selection();
+            return;
+        }
+
+        let visible_items = self.visible_row_count();
+        for i in 0..visible_items {
+            if self.list_item_bounds(i).check_collision_point_rec(mouse) {
+                let row = i + self.scroll_offset;
+                match self.row_item(row) {
+                    Some(index) => self.toggle_index(index),
+                    None if self.row_selectable(row) => self.clear_selection(),
+                    None => {}
                 }
-            } else {
-                if idx < self.items.len() {
-                    return Some(&self.items[idx]);
-                } else {
-                    return None;
+                // The list stays open while toggling
+                return;
+            }
+        }
+
+        // Click was not on the list, close it
+        self.is_open = false;
+    }
+
+    /// Chips that fit in the closed box as (item index, chip bounds, close bounds), plus how many did not fit
+    fn chip_layout(&self) -> (Vec<(usize, Rectangle, Rectangle)>, usize) {
+        let chip_height = self.bounds.height - self.style.padding * 2.0;
+        let chip_font = (self.style.font_size as f32 * 0.8) as i32;
+        let max_x = self.bounds.x + self.bounds.width - self.style.font_size as f32 - self.style.padding * 2.0;
+        let mut x = self.bounds.x + self.style.padding;
+        let mut chips = Vec::new();
+        for (n, &idx) in self.selected_indices.iter().enumerate() {
+            let label = self.item_text(idx).unwrap_or_default();
+            let text_width = measure_text(&label, chip_font);
+            let chip_width = text_width + chip_height + self.style.padding * 2.0;
+            if x + chip_width > max_x {
+                return (chips, self.selected_indices.len() - n);
+            }
+            let chip = Rectangle::new(x, self.bounds.y + self.style.padding, chip_width, chip_height);
+            let close = Rectangle::new(chip.x + chip.width - chip_height, chip.y, chip_height, chip_height);
+            chips.push((idx, chip, close));
+            x += chip_width + self.style.padding;
+        }
+        (chips, 0)
+    }
+
+    fn draw_multi_summary(&self, d: &mut impl RaylibDraw) {
+        let text_y = (self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0) as i32;
+        match self.summary {
+            SelectionSummary::Count => {
+                let summary = match self.selected_indices.len() {
+                    0 => return,
+                    1 => self.item_text(self.selected_indices[0]).unwrap_or_default(),
+                    n => format!("{} selected", n),
+                };
+                d.draw_text(
+                    &summary,
+                    self.bounds.x as i32 + self.style.padding as i32,
+                    text_y,
+                    self.style.font_size,
+                    self.style.text_color,
+                );
+            }
+            SelectionSummary::Chips => {
+                let chip_font = (self.style.font_size as f32 * 0.8) as i32;
+                let (chips, hidden) = self.chip_layout();
+                let mut end_x = self.bounds.x + self.style.padding;
+                for (idx, chip, close) in chips {
+                    d.draw_rectangle_rounded(chip, 0.5, 8, self.style.hover_color);
+                    let label = self.item_text(idx).unwrap_or_default();
+                    d.draw_text(
+                        &label,
+                        (chip.x + self.style.padding) as i32,
+                        (chip.y + (chip.height - chip_font as f32) / 2.0) as i32,
+                        chip_font,
+                        self.style.text_color,
+                    );
+                    // Draw the remove "x"
+                    let inset = close.width * 0.3;
+                    d.draw_line_ex(
+                        Vector2::new(close.x + inset, close.y + inset),
+                        Vector2::new(close.x + close.width - inset, close.y + close.height - inset),
+                        1.5,
+                        self.style.text_color,
+                    );
+                    d.draw_line_ex(
+                        Vector2::new(close.x + close.width - inset, close.y + inset),
+                        Vector2::new(close.x + inset, close.y + close.height - inset),
+                        1.5,
+                        self.style.text_color,
+                    );
+                    end_x = chip.x + chip.width + self.style.padding;
+                }
+                if hidden > 0 {
+                    d.draw_text(&format!("+{}", hidden), end_x as i32, text_y, chip_font, self.style.text_color);
                 }
             }
         }
-        None
     }
 
-    pub fn add_item(&mut self, item: String) {
-        self.items.push(item);
+    fn draw_bulk_actions(&self, d: &mut impl RaylibDraw) {
+        let (select_all, clear_all) = self.bulk_action_bounds();
+        for (bounds, label) in [(select_all, "Select all"), (clear_all, "Clear all")] {
+            d.draw_rectangle_rec(bounds, self.style.background_color);
+            d.draw_rectangle_lines_ex(bounds, self.style.border_thickness, self.style.border_color);
+            d.draw_text(
+                label,
+                bounds.x as i32 + self.style.padding as i32,
+                (bounds.y + (bounds.height - self.style.font_size as f32) / 2.0) as i32,
+                self.style.font_size,
+                self.style.active_color,
+            );
+        }
+    }
+
+    /// Draw the tooltip if it is showing; call after drawing everything else so it stays on top
+    pub fn draw_tooltip(&self, d: &mut impl RaylibDraw) {
+        if let Some(tooltip) = &self.tooltip {
+            tooltip.draw(d, &self.style);
+        }
+    }
+
+    /// Display text of the selected item
+    pub fn get_selected_item(&self) -> Option<String> {
+        self.selected_index.and_then(|idx| self.item_text(idx))
+    }
+
+    pub fn selected_value(&self) -> Option<&T> {
+        self.selected_index.and_then(|idx| self.items.get(idx)).map(|item| &item.value)
+    }
+
+    pub fn add_item(&mut self, item: T) {
+        self.items.push(DropdownItem::new(item));
+    }
+
+    /// Add an item with an icon, secondary text or disabled state
+    pub fn add_entry(&mut self, entry: DropdownItem<T>) {
+        self.items.push(entry);
+    }
+
+    /// Start a new group; the header goes before the next item added
+    pub fn add_header(&mut self, label: &str) {
+        self.markers.push((self.items.len(), RowMarker::Header(label.to_string())));
+    }
+
+    /// Draw a line before the next item added
+    pub fn add_separator(&mut self) {
+        self.markers.push((self.items.len(), RowMarker::Separator));
+    }
+
+    /// Headers and separators, each placed before an item index
+    pub fn markers(&self) -> &[(usize, RowMarker)] {
+        &self.markers
+    }
+
+    /// Remove every header and separator, keeping the items
+    pub fn clear_markers(&mut self) {
+        self.markers.clear();
+        // Markers added back before the next draw could match the old count
+        self.row_cache.borrow_mut().key = None;
+    }
+
+    pub fn set_item_enabled(&mut self, index: usize, enabled: bool) {
+        if let Some(item) = self.items.get_mut(index) {
+            item.enabled = enabled;
+        }
     }
 
     pub fn remove_item(&mut self, index: usize) {
@@ -261,17 +1028,68 @@ impl Dropdown {
                     self.selected_index = Some(selected - 1);
                 }
             }
+            for (before, _) in &mut self.markers {
+                if *before > index {
+                    *before -= 1;
+                }
+            }
+            // Markers moved without the counts changing, so a later insert could leave the cache looking current
+            self.row_cache.borrow_mut().key = None;
+            self.selected_indices.retain(|&i| i != index);
+            for selected in &mut self.selected_indices {
+                if *selected > index {
+                    *selected -= 1;
+                }
+            }
         }
     }
 
     pub fn clear_items(&mut self) {
         self.items.clear();
+        self.clear_markers();
         self.selected_index = None;
+        self.selected_indices.clear();
         self.scroll_offset = 0;
     }
 
     pub fn clear_selection(&mut self) {
         self.selected_index = None;
+        self.selected_indices.clear();
+    }
+
+    /// Display text of the item at `index`
+    pub fn item_text(&self, index: usize) -> Option<String> {
+        self.items.get(index).map(|item| (self.display)(&item.value))
+    }
+
+    /// Add or remove an item from the multi-selection.
+    /// Disabled items can be removed from the selection but not added
+    pub fn toggle_index(&mut self, index: usize) {
+        if let Some(pos) = self.selected_indices.iter().position(|&i| i == index) {
+            self.selected_indices.remove(pos);
+        } else if self.items.get(index).is_some_and(|item| item.enabled) {
+            self.selected_indices.push(index);
+            self.selected_indices.sort_unstable();
+        }
+        self.selected_index = self.selected_indices.first().copied();
+    }
+
+    pub fn select_all(&mut self) {
+        self.selected_indices = (0..self.items.len()).filter(|&i| self.items[i].enabled).collect();
+        self.selected_index = self.selected_indices.first().copied();
+    }
+
+    pub fn selected_indices(&self) -> &[usize] {
+        &self.selected_indices
+    }
+
+    /// Display text of every selected item in multi-select mode
+    pub fn get_selected_items(&self) -> Vec<String> {
+        self.selected_indices.iter().filter_map(|&i| self.item_text(i)).collect()
+    }
+
+    pub fn selected_values(&self) -> Vec<&T> {
+        self.selected_indices.iter().filter_map(|&i| self.items.get(i)).map(|item| &item.value).collect()
     }
 
     pub fn open(&mut self) {
@@ -280,6 +1098,7 @@ impl Dropdown {
 
     pub fn close(&mut self) {
         self.is_open = false;
+        self.type_ahead.clear();
     }
 
     pub fn toggle(&mut self) {
@@ -287,4 +1106,218 @@ impl Dropdown {
     }
 }
 
+/// What an item row shows; shared by Dropdown and ContextMenu so both lists look alike
+pub(crate) struct RowContent<'a> {
+    pub label: &'a str,
+    pub enabled: bool,
+    /// `Some` reserves a check box column, ticked when true
+    pub checked: Option<bool>,
+    pub icon: Option<&'a ItemIcon>,
+    /// Right-aligned text such as a shortcut
+    pub secondary_text: Option<&'a str>,
+    /// Space kept free on the right, e.g. for a scroll bar
+    pub right_inset: f32,
+    /// Char index of the label letter to underline
+    pub mnemonic: Option<usize>,
+}
+
+/// Draw an item row's check box, icon, label and secondary text over an already drawn background
+pub(crate) fn draw_item_row(d: &mut impl RaylibDraw, style: &Style, bounds: Rectangle, content: &RowContent) {
+    let text_y = (bounds.y + (bounds.height - style.font_size as f32) / 2.0) as i32;
+    let text_color = if content.enabled {
+        style.text_color
+    } else {
+        style.text_color_disabled
+    };
+    let mut text_x = bounds.x + style.padding;
+
+    if let Some(checked) = content.checked {
+        draw_check_glyph(d, style, bounds, checked);
+        text_x += bounds.height;
+    }
 
+    if let Some(icon) = content.icon {
+        let icon_size = bounds.height * 0.6;
+        let icon_bounds = Rectangle::new(text_x, bounds.y + (bounds.height - icon_size) / 2.0, icon_size, icon_size);
+        match icon {
+            ItemIcon::Texture(texture) => {
+                let tint = if content.enabled { Color::WHITE } else { Color::WHITE.alpha(0.4) };
+                d.draw_texture_pro(
+                    texture.as_ref(),
+                    Rectangle::new(0.0, 0.0, texture.width as f32, texture.height as f32),
+                    icon_bounds,
+                    Vector2::zero(),
+                    0.0,
+                    tint,
+                );
+            }
+            ItemIcon::Glyph(glyph) => {
+                d.draw_text(glyph, icon_bounds.x as i32, text_y, style.font_size, text_color);
+            }
+        }
+        text_x += icon_size + style.padding;
+    }
+
+    d.draw_text(content.label, text_x as i32, text_y, style.font_size, text_color);
+    if let Some(index) = content.mnemonic {
+        draw_mnemonic_underline(d, content.label, index, text_x, text_y as f32, style.font_size, text_color);
+    }
+
+    // Secondary text sits against the right edge
+    if let Some(secondary) = content.secondary_text {
+        let width = measure_text(secondary, style.font_size);
+        d.draw_text(
+            secondary,
+            (bounds.x + bounds.width - style.padding - content.right_inset - width) as i32,
+            text_y,
+            style.font_size,
+            style.placeholder_color,
+        );
+    }
+}
+
+pub(crate) fn draw_separator_row(d: &mut impl RaylibDraw, style: &Style, bounds: Rectangle) {
+    let y = bounds.y + bounds.height / 2.0;
+    d.draw_line_ex(
+        Vector2::new(bounds.x + style.padding, y),
+        Vector2::new(bounds.x + bounds.width - style.padding, y),
+        1.0,
+        style.border_color,
+    );
+}
+
+/// Which way a triangle glyph points
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ArrowDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Triangle filling `bounds`, as on the dropdown box and tree expanders
+pub(crate) fn draw_arrow_glyph(d: &mut impl RaylibDraw, bounds: Rectangle, direction: ArrowDirection, color: Color) {
+    let (left, top) = (bounds.x, bounds.y);
+    let (right, bottom) = (bounds.x + bounds.width, bounds.y + bounds.height);
+    let (center_x, center_y) = (left + bounds.width / 2.0, top + bounds.height / 2.0);
+    // Counter-clockwise on screen, as raylib expects
+    let points = match direction {
+        ArrowDirection::Up => [(center_x, top), (left, bottom), (right, bottom)],
+        ArrowDirection::Down => [(left, top), (center_x, bottom), (right, top)],
+        ArrowDirection::Left => [(right, top), (left, center_y), (right, bottom)],
+        ArrowDirection::Right => [(left, top), (left, bottom), (right, center_y)],
+    };
+    let [a, b, c] = points.map(|(x, y)| Vector2::new(x, y));
+    d.draw_triangle(a, b, c, color);
+}
+
+pub(crate) fn draw_check_glyph(d: &mut impl RaylibDraw, style: &Style, row: Rectangle, checked: bool) {
+    let state = if checked { CheckState::Checked } else { CheckState::Unchecked };
+    draw_check_state_glyph(d, style, row, state);
+}
+
+/// Check box glyph at the left of `row`; the mixed state draws a dash
+pub(crate) fn draw_check_state_glyph(d: &mut impl RaylibDraw, style: &Style, row: Rectangle, state: CheckState) {
+    let size = row.height * 0.5;
+    let glyph = Rectangle::new(
+        row.x + style.padding,
+        row.y + (row.height - size) / 2.0,
+        size,
+        size,
+    );
+    d.draw_rectangle_lines_ex(glyph, 1.0, style.border_color);
+    let center_x = glyph.x + glyph.width / 2.0;
+    let center_y = glyph.y + glyph.height / 2.0;
+    let half = glyph.width * 0.3;
+    if state == CheckState::Indeterminate {
+        d.draw_line_ex(
+            Vector2::new(center_x - half, center_y),
+            Vector2::new(center_x + half, center_y),
+            2.0,
+            style.check_color,
+        );
+    } else if state == CheckState::Checked {
+        d.draw_line_ex(
+            Vector2::new(center_x - half, center_y),
+            Vector2::new(center_x, center_y + half),
+            2.0,
+            style.check_color,
+        );
+        d.draw_line_ex(
+            Vector2::new(center_x, center_y + half),
+            Vector2::new(center_x + half, center_y - half),
+            2.0,
+            style.check_color,
+        );
+    }
+}
+
+/// Strip along the right edge of `area` that a scroll bar runs in
+pub(crate) fn scrollbar_track(area: Rectangle) -> Rectangle {
+    Rectangle::new(area.x + area.width - SCROLLBAR_WIDTH, area.y, SCROLLBAR_WIDTH, area.height)
+}
+
+/// Start and length of `rect` along `axis`
+fn span(axis: ScrollAxis, rect: Rectangle) -> (f32, f32) {
+    match axis {
+        ScrollAxis::Vertical => (rect.y, rect.height),
+        ScrollAxis::Horizontal => (rect.x, rect.width),
+    }
+}
+
+/// Position of `point` along `axis`
+fn coordinate(axis: ScrollAxis, point: Vector2) -> f32 {
+    match axis {
+        ScrollAxis::Vertical => point.y,
+        ScrollAxis::Horizontal => point.x,
+    }
+}
+
+/// Thumb for `visible` of `total` units along `axis`, scrolled by `offset` units
+pub(crate) fn scrollbar_thumb(axis: ScrollAxis, track: Rectangle, visible: f32, total: f32, offset: f32) -> Rectangle {
+    let (start, track_length) = span(axis, track);
+    let length = (track_length * visible / total.max(1.0)).max(MIN_THUMB_LENGTH).min(track_length);
+    let progress = offset / (total - visible).max(1.0);
+    let thumb_start = start + (track_length - length) * progress;
+    match axis {
+        ScrollAxis::Vertical => Rectangle::new(track.x, thumb_start, track.width, length),
+        ScrollAxis::Horizontal => Rectangle::new(thumb_start, track.y, length, track.height),
+    }
+}
+
+/// How far along the thumb it is held: where it was clicked, or its centre when the track was hit
+pub(crate) fn start_scroll_drag(axis: ScrollAxis, thumb: Rectangle, mouse: Vector2) -> f32 {
+    let (start, length) = span(axis, thumb);
+    if thumb.check_collision_point_rec(mouse) {
+        coordinate(axis, mouse) - start
+    } else {
+        length / 2.0
+    }
+}
+
+/// Scroll offset for a thumb held `grab` pixels from its start and dragged to `mouse`
+pub(crate) fn drag_scroll(axis: ScrollAxis, track: Rectangle, thumb: Rectangle, grab: f32, mouse: Vector2, max_scroll: f32) -> f32 {
+    let (start, track_length) = span(axis, track);
+    let travel = (track_length - span(axis, thumb).1).max(1.0);
+    ((coordinate(axis, mouse) - grab - start) / travel).clamp(0.0, 1.0) * max_scroll
+}
+
+/// Ask for a resize arrow along `axis` over the thumb, and keep it for the whole drag
+pub(crate) fn scrollbar_cursor(axis: ScrollAxis, thumb: Rectangle, mouse: Vector2, dragging: bool) {
+    let shape = match axis {
+        ScrollAxis::Vertical => CursorShape::ResizeNS,
+        ScrollAxis::Horizontal => CursorShape::ResizeEW,
+    };
+    if dragging {
+        capture_cursor(shape);
+    } else if thumb.check_collision_point_rec(mouse) {
+        request_cursor(shape);
+    }
+}
+
+/// Faint track with the thumb over it, highlighted while dragged
+pub(crate) fn draw_scrollbar(d: &mut impl RaylibDraw, style: &Style, track: Rectangle, thumb: Rectangle, dragging: bool) {
+    d.draw_rectangle_rec(track, style.border_color.alpha(0.2));
+    let thumb_color = if dragging { style.border_color_active } else { style.border_color };
+    d.draw_rectangle_rec(thumb, thumb_color);
+}
@@ -0,0 +1,636 @@
+use crate::style::Style;
+use crate::text::{draw_text_in, measure_text_in};
+use crate::cursor::{request_cursor, CursorShape};
+use raylib::prelude::*;
+
+/// Default colors for links in rich text and `Link`
+pub(crate) const LINK_COLOR: Color = Color::new(40, 100, 220, 255);
+pub(crate) const LINK_HOVER_COLOR: Color = Color::new(20, 60, 160, 255);
+pub(crate) const LINK_VISITED_COLOR: Color = Color::new(110, 60, 170, 255);
+
+/// How each line sits between the left and right edges
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right,
+    /// Stretch the gaps so wrapped lines fill the width; a paragraph's last line stays left
+    Justify,
+}
+
+/// A stretch of text sharing one format
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextSpan {
+    pub text: String,
+    pub bold: bool,
+    pub underline: bool,
+    /// None uses the style's text color, or the link color for links
+    pub color: Option<Color>,
+    /// None uses the style's font size
+    pub size: Option<i32>,
+    /// Target reported when the span is clicked
+    pub link: Option<String>,
+}
+
+impl TextSpan {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            ..Self::default()
+        }
+    }
+
+    fn font_size(&self, base_size: i32) -> i32 {
+        self.size.unwrap_or(base_size)
+    }
+}
+
+/// Text from one span placed on one line
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextRun {
+    /// Index into the spans
+    pub span: usize,
+    pub text: String,
+    pub bounds: Rectangle,
+}
+
+/// What happened in a rich text during the last update
+#[derive(Clone, Debug, PartialEq)]
+pub enum RichTextEvent {
+    LinkClicked(String),
+}
+
+/// Split markup into spans. Tags are `[b]`, `[u]`, `[color=#ff8800]` or `[color=red]`,
+/// `[size=24]` and `[link=target]`, each closed by `[/b]` and so on; `[[` is a literal '['.
+/// Anything that isn't a known tag is kept as text.
+pub fn parse_markup(markup: &str) -> Vec<TextSpan> {
+    let mut spans = Vec::new();
+    let mut current = TextSpan::default();
+    // Tag name and the format to restore when it closes
+    let mut open: Vec<(String, TextSpan)> = Vec::new();
+    let mut rest = markup;
+
+    while let Some(start) = rest.find('[') {
+        current.text.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(escaped) = rest.strip_prefix("[[") {
+            current.text.push('[');
+            rest = escaped;
+            continue;
+        }
+
+        let tag = rest[1..].find(']').map(|end| &rest[1..end + 1]);
+        let format = tag.and_then(|tag| apply_tag(tag, &current, &mut open));
+        match (tag, format) {
+            (Some(tag), Some(format)) => {
+                if !current.text.is_empty() {
+                    spans.push(current.clone());
+                }
+                current = format;
+                rest = &rest[tag.len() + 2..];
+            }
+            _ => {
+                current.text.push('[');
+                rest = &rest[1..];
+            }
+        }
+    }
+    current.text.push_str(rest);
+    if !current.text.is_empty() {
+        spans.push(current);
+    }
+    spans
+}
+
+/// The format after `tag`, with no text, or None if `tag` isn't a tag that can apply here
+fn apply_tag(tag: &str, current: &TextSpan, open: &mut Vec<(String, TextSpan)>) -> Option<TextSpan> {
+    let restore = TextSpan {
+        text: String::new(),
+        ..current.clone()
+    };
+
+    if let Some(name) = tag.strip_prefix('/') {
+        // Closing a tag also closes any opened inside it
+        let index = open.iter().rposition(|(open_name, _)| open_name == name)?;
+        return open.drain(index..).next().map(|(_, format)| format);
+    }
+
+    let (name, value) = match tag.split_once('=') {
+        Some((name, value)) => (name, Some(value.trim())),
+        None => (tag, None),
+    };
+    let mut format = restore.clone();
+    match (name, value) {
+        ("b", None) => format.bold = true,
+        ("u", None) => format.underline = true,
+        ("color", Some(value)) => format.color = Some(parse_color(value)?),
+        ("size", Some(value)) => format.size = Some(value.parse().ok().filter(|size| *size > 0)?),
+        ("link", Some(value)) if !value.is_empty() => format.link = Some(value.to_string()),
+        _ => return None,
+    }
+    open.push((name.to_string(), restore));
+    Some(format)
+}
+
+/// "#rrggbb", "#rrggbbaa" or one of raylib's color names in lower case
+pub fn parse_color(value: &str) -> Option<Color> {
+    if let Some(hex) = value.strip_prefix('#') {
+        if !(hex.len() == 6 || hex.len() == 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        let alpha = if hex.len() == 8 { channel(6)? } else { 255 };
+        return Some(Color::new(channel(0)?, channel(2)?, channel(4)?, alpha));
+    }
+
+    Some(match value {
+        "black" => Color::BLACK,
+        "white" => Color::WHITE,
+        "gray" => Color::GRAY,
+        "lightgray" => Color::LIGHTGRAY,
+        "darkgray" => Color::DARKGRAY,
+        "red" => Color::RED,
+        "maroon" => Color::MAROON,
+        "orange" => Color::ORANGE,
+        "yellow" => Color::YELLOW,
+        "gold" => Color::GOLD,
+        "green" => Color::GREEN,
+        "lime" => Color::LIME,
+        "darkgreen" => Color::DARKGREEN,
+        "skyblue" => Color::SKYBLUE,
+        "blue" => Color::BLUE,
+        "darkblue" => Color::DARKBLUE,
+        "purple" => Color::PURPLE,
+        "violet" => Color::VIOLET,
+        "pink" => Color::PINK,
+        "brown" => Color::BROWN,
+        _ => return None,
+    })
+}
+
+/// Width of a span's text in the style's fonts; bold without a bold font is drawn twice, a pixel apart
+fn measure_span(style: &Style, text: &str, span: &TextSpan) -> f32 {
+    let size = span.font_size(style.font_size);
+    match (span.bold, &style.bold_font) {
+        (true, Some(bold)) => measure_text_in(Some(bold), text, size),
+        (true, None) => measure_text_in(style.font.as_deref(), text, size) + 1.0,
+        (false, _) => measure_text_in(style.font.as_deref(), text, size),
+    }
+}
+
+pub(crate) fn draw_run(d: &mut impl RaylibDraw, style: &Style, run: &TextRun, span: &TextSpan, color: Color) {
+    let size = span.font_size(style.font_size);
+    let (x, y) = (run.bounds.x, run.bounds.y);
+    match (span.bold, &style.bold_font) {
+        (true, Some(bold)) => draw_text_in(d, Some(bold), &run.text, x, y, size, color),
+        (true, None) => {
+            draw_text_in(d, style.font.as_deref(), &run.text, x, y, size, color);
+            draw_text_in(d, style.font.as_deref(), &run.text, x + 1.0, y, size, color);
+        }
+        (false, _) => draw_text_in(d, style.font.as_deref(), &run.text, x, y, size, color),
+    }
+    if span.underline || span.link.is_some() {
+        let underline_y = y + run.bounds.height + 1.0;
+        d.draw_line_ex(
+            Vector2::new(x, underline_y),
+            Vector2::new(x + run.bounds.width, underline_y),
+            1.0,
+            color,
+        );
+    }
+}
+
+/// One piece of a word, in a single span
+struct Piece {
+    span: usize,
+    text: String,
+    width: f32,
+}
+
+#[derive(Default)]
+struct Word {
+    pieces: Vec<Piece>,
+    /// Space before the word and the span it came from
+    gap: f32,
+    gap_span: Option<usize>,
+    width: f32,
+}
+
+#[derive(Default)]
+struct Line {
+    words: Vec<Word>,
+    width: f32,
+    height: f32,
+    ends_paragraph: bool,
+}
+
+/// Break a word wider than `max_width` between characters into words that fit, measuring each piece
+fn split_word(word: Word, max_width: f32, measure: impl Fn(&str, usize) -> f32) -> Vec<Word> {
+    let mut chunks = vec![Word::default()];
+    for piece in word.pieces {
+        for c in piece.text.chars() {
+            let chunk = chunks.last_mut().expect("always has a chunk");
+            let is_empty = chunk.pieces.is_empty();
+            let last = chunk.pieces.last_mut().filter(|last| last.span == piece.span);
+            let (mut text, previous) = last.as_ref().map_or((String::new(), 0.0), |last| (last.text.clone(), last.width));
+            text.push(c);
+            let width = measure(&text, piece.span);
+
+            if !is_empty && chunk.width - previous + width > max_width {
+                let text = c.to_string();
+                let width = measure(&text, piece.span);
+                chunks.push(Word {
+                    pieces: vec![Piece { span: piece.span, text, width }],
+                    width,
+                    ..Word::default()
+                });
+                continue;
+            }
+            match last {
+                Some(last) => {
+                    last.text = text;
+                    last.width = width;
+                }
+                None => chunk.pieces.push(Piece { span: piece.span, text, width }),
+            }
+            chunk.width += width - previous;
+        }
+    }
+    chunks
+}
+
+/// Place `spans` in `bounds`, wrapping at spaces when `wrap` is set and always at '\n'.
+/// With `wrap`, words wider than the bounds are broken between characters.
+/// Lines are `line_spacing` apart and smaller text sits on the bottom of its line.
+pub(crate) fn layout_spans(
+    spans: &[TextSpan],
+    bounds: Rectangle,
+    align: TextAlign,
+    wrap: bool,
+    line_spacing: f32,
+    base_size: i32,
+    measure: impl Fn(&str, &TextSpan) -> f32,
+) -> Vec<TextRun> {
+    let mut lines = vec![Line::default()];
+    let mut word = Word::default();
+    let mut gap: Option<usize> = None;
+
+    // Words end at spaces, line breaks and the end of the text
+    let finish_word = |word: &mut Word, gap: &mut Option<usize>, lines: &mut Vec<Line>| {
+        if word.pieces.is_empty() {
+            return;
+        }
+        let mut word = std::mem::take(word);
+        for piece in &mut word.pieces {
+            piece.width = measure(&piece.text, &spans[piece.span]);
+        }
+        word.width = word.pieces.iter().map(|piece| piece.width).sum();
+        let chunks = if wrap && word.width > bounds.width {
+            split_word(word, bounds.width, |text, span| measure(text, &spans[span]))
+        } else {
+            vec![word]
+        };
+
+        // Every chunk after the first of a broken word starts a new line
+        for (index, mut word) in chunks.into_iter().enumerate() {
+            let mut line = lines.last_mut().expect("layout always has a line");
+            word.gap_span = if line.words.is_empty() || index > 0 { None } else { gap.take() };
+            word.gap = word.gap_span.map_or(0.0, |span| measure(" ", &spans[span]));
+            if wrap && !line.words.is_empty() && (index > 0 || line.width + word.gap + word.width > bounds.width) {
+                lines.push(Line::default());
+                line = lines.last_mut().expect("line was just pushed");
+                word.gap = 0.0;
+                word.gap_span = None;
+            }
+            line.width += word.gap + word.width;
+            for piece in &word.pieces {
+                line.height = line.height.max(spans[piece.span].font_size(base_size) as f32);
+            }
+            line.words.push(word);
+        }
+        *gap = None;
+    };
+
+    for (index, span) in spans.iter().enumerate() {
+        for c in span.text.chars() {
+            match c {
+                '\n' => {
+                    finish_word(&mut word, &mut gap, &mut lines);
+                    let line = lines.last_mut().expect("layout always has a line");
+                    line.ends_paragraph = true;
+                    if line.words.is_empty() {
+                        line.height = span.font_size(base_size) as f32;
+                    }
+                    lines.push(Line::default());
+                }
+                ' ' => {
+                    finish_word(&mut word, &mut gap, &mut lines);
+                    gap.get_or_insert(index);
+                }
+                _ => match word.pieces.last_mut() {
+                    Some(piece) if piece.span == index => piece.text.push(c),
+                    _ => word.pieces.push(Piece {
+                        span: index,
+                        text: c.to_string(),
+                        width: 0.0,
+                    }),
+                },
+            }
+        }
+    }
+    finish_word(&mut word, &mut gap, &mut lines);
+    if let Some(last) = lines.last_mut() {
+        last.ends_paragraph = true;
+        if last.words.is_empty() && last.height == 0.0 {
+            last.height = base_size as f32;
+        }
+    }
+
+    let mut runs: Vec<TextRun> = Vec::new();
+    let mut y = bounds.y;
+    for line in &lines {
+        let free = (bounds.width - line.width).max(0.0);
+        let justify = align == TextAlign::Justify && !line.ends_paragraph && line.words.len() > 1;
+        let extra = if justify { free / (line.words.len() - 1) as f32 } else { 0.0 };
+        let mut x = bounds.x
+            + match align {
+                TextAlign::Left | TextAlign::Justify => 0.0,
+                TextAlign::Center => free / 2.0,
+                TextAlign::Right => free,
+            };
+        let line_start = runs.len();
+
+        for (word_index, word) in line.words.iter().enumerate() {
+            if word_index > 0 {
+                x += word.gap + extra;
+            }
+            for (piece_index, piece) in word.pieces.iter().enumerate() {
+                let size = spans[piece.span].font_size(base_size) as f32;
+                // Join words of one span into a single run, so underlines continue across the space;
+                // justified gaps are wider than a space, so those stay apart
+                let joins_previous = !justify
+                    && piece_index == 0
+                    && word.gap_span == Some(piece.span)
+                    && runs.len() > line_start
+                    && runs.last().is_some_and(|run| run.span == piece.span);
+                if let Some(run) = runs.last_mut().filter(|_| joins_previous) {
+                    run.text.push(' ');
+                    run.text.push_str(&piece.text);
+                    run.bounds.width = x + piece.width - run.bounds.x;
+                } else {
+                    runs.push(TextRun {
+                        span: piece.span,
+                        text: piece.text.clone(),
+                        bounds: Rectangle::new(x, y + line.height - size, piece.width, size),
+                    });
+                }
+                x += piece.width;
+            }
+        }
+        y += line.height + line_spacing;
+    }
+    runs
+}
+
+/// Height from the top of the first run to the bottom of the last
+fn runs_height(runs: &[TextRun], top: f32) -> f32 {
+    runs.iter().map(|run| run.bounds.y + run.bounds.height - top).fold(0.0, f32::max)
+}
+
+/// The run under `point`
+pub(crate) fn run_at(runs: &[TextRun], point: Vector2) -> Option<&TextRun> {
+    runs.iter().find(|run| run.bounds.check_collision_point_rec(point))
+}
+
+/// Plain text in the style's font and text color, wrapped to the width and centred vertically
+pub struct Label {
+    pub bounds: Rectangle,
+    pub text: String,
+    pub style: Style,
+    pub align: TextAlign,
+    pub wrap: bool,
+    /// Extra pixels between lines
+    pub line_spacing: f32,
+    pub is_hovered: bool,
+}
+
+impl Label {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, text: &str) -> Self {
+        Self {
+            bounds: Rectangle::new(x, y, width, height),
+            text: text.to_string(),
+            style: Style::default(),
+            align: TextAlign::Left,
+            wrap: true,
+            line_spacing: 4.0,
+            is_hovered: false,
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn with_line_spacing(mut self, spacing: f32) -> Self {
+        self.line_spacing = spacing;
+        self
+    }
+
+    pub fn update(&mut self, mouse: Vector2) {
+        self.is_hovered = self.bounds.check_collision_point_rec(mouse);
+    }
+
+    /// Where each line is drawn
+    pub fn layout(&self) -> Vec<TextRun> {
+        let (mut runs, height) = self.top_aligned_layout();
+        let offset = ((self.bounds.height - height) / 2.0).max(0.0);
+        for run in &mut runs {
+            run.bounds.y += offset;
+        }
+        runs
+    }
+
+    pub fn content_height(&self) -> f32 {
+        self.top_aligned_layout().1
+    }
+
+    /// Lines laid out from the top of the bounds, and their height
+    fn top_aligned_layout(&self) -> (Vec<TextRun>, f32) {
+        let spans = [TextSpan::new(&self.text)];
+        let runs = layout_spans(&spans, self.bounds, self.align, self.wrap, self.line_spacing, self.style.font_size, |text, span| {
+            measure_span(&self.style, text, span)
+        });
+        let height = runs_height(&runs, self.bounds.y);
+        (runs, height)
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        let span = TextSpan::new(&self.text);
+        for run in self.layout() {
+            draw_run(d, &self.style, &run, &span, self.style.text_color);
+        }
+    }
+}
+
+/// Formatted text parsed from markup, with clickable links
+pub struct RichText {
+    pub bounds: Rectangle,
+    pub spans: Vec<TextSpan>,
+    pub style: Style,
+    pub align: TextAlign,
+    pub wrap: bool,
+    /// Extra pixels between lines
+    pub line_spacing: f32,
+    pub link_color: Color,
+    pub link_hover_color: Color,
+    pub visited_color: Color,
+    /// Targets of links that have been clicked
+    pub visited: Vec<String>,
+    /// Span of the link under the mouse
+    pub hover_link: Option<usize>,
+    /// Span of the link the mouse went down on, until it is released
+    pub pressed_link: Option<usize>,
+    pub events: Vec<RichTextEvent>,
+}
+
+impl RichText {
+    /// Text is laid out from the top of `bounds` and wrapped to its width
+    pub fn new(x: f32, y: f32, width: f32, height: f32, markup: &str) -> Self {
+        Self {
+            bounds: Rectangle::new(x, y, width, height),
+            spans: parse_markup(markup),
+            style: Style::default(),
+            align: TextAlign::Left,
+            wrap: true,
+            line_spacing: 4.0,
+            link_color: LINK_COLOR,
+            link_hover_color: LINK_HOVER_COLOR,
+            visited_color: LINK_VISITED_COLOR,
+            visited: Vec::new(),
+            hover_link: None,
+            pressed_link: None,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn with_line_spacing(mut self, spacing: f32) -> Self {
+        self.line_spacing = spacing;
+        self
+    }
+
+    pub fn with_link_colors(mut self, normal: Color, hover: Color) -> Self {
+        self.link_color = normal;
+        self.link_hover_color = hover;
+        self
+    }
+
+    pub fn with_visited_color(mut self, color: Color) -> Self {
+        self.visited_color = color;
+        self
+    }
+
+    pub fn set_markup(&mut self, markup: &str) {
+        self.spans = parse_markup(markup);
+        self.hover_link = None;
+        self.pressed_link = None;
+    }
+
+    /// The text without markup
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        self.events.clear();
+        self.handle_mouse(
+            mouse,
+            rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT),
+            rl.is_mouse_button_released(MouseButton::MOUSE_BUTTON_LEFT),
+        );
+    }
+
+    /// Hover and clicks from the left button's state; a link is clicked when pressed and released on it
+    pub(crate) fn handle_mouse(&mut self, mouse: Vector2, pressed: bool, released: bool) {
+        self.hover_link = self.link_at(mouse);
+        if self.hover_link.is_some() {
+            request_cursor(CursorShape::PointingHand);
+        }
+        if pressed {
+            self.pressed_link = self.hover_link;
+        }
+        if released {
+            if let Some(span) = self.pressed_link.take().filter(|&span| self.hover_link == Some(span)) {
+                self.click_link(span);
+            }
+        }
+    }
+
+    /// Follow the link in span `span` as if it had been clicked
+    pub fn click_link(&mut self, span: usize) {
+        let Some(target) = self.spans.get(span).and_then(|span| span.link.clone()) else {
+            return;
+        };
+        if !self.visited.contains(&target) {
+            self.visited.push(target.clone());
+        }
+        self.events.push(RichTextEvent::LinkClicked(target));
+    }
+
+    /// Where each piece of text is drawn
+    pub fn layout(&self) -> Vec<TextRun> {
+        layout_spans(&self.spans, self.bounds, self.align, self.wrap, self.line_spacing, self.style.font_size, |text, span| {
+            measure_span(&self.style, text, span)
+        })
+    }
+
+    pub fn content_height(&self) -> f32 {
+        runs_height(&self.layout(), self.bounds.y)
+    }
+
+    /// Span index of the link under `point`
+    pub fn link_at(&self, point: Vector2) -> Option<usize> {
+        run_at(&self.layout(), point)
+            .map(|run| run.span)
+            .filter(|&span| self.spans[span].link.is_some())
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        for run in self.layout() {
+            let span = &self.spans[run.span];
+            let color = match (span.color, &span.link) {
+                (Some(color), _) => color,
+                (None, Some(_)) if self.hover_link == Some(run.span) => self.link_hover_color,
+                (None, Some(target)) if self.visited.contains(target) => self.visited_color,
+                (None, Some(_)) => self.link_color,
+                (None, None) => self.style.text_color,
+            };
+            draw_run(d, &self.style, &run, span, color);
+        }
+    }
+}
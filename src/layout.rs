@@ -0,0 +1,119 @@
+use crate::button::Button;
+use crate::checkbox::Checkbox;
+use crate::combobox::ComboBox;
+use crate::dropdown::Dropdown;
+use crate::label::{Label, RichText};
+use crate::link::Link;
+use crate::listbox::ListBox;
+use crate::table::Table;
+use crate::tree::TreeView;
+use crate::radio::RadioButton;
+use crate::tabs::{TabBar, TabView};
+use crate::textfield::TextField;
+use crate::toggle::Toggle;
+use raylib::prelude::*;
+
+/// Mouse position containers hand to children that shouldn't react to the mouse
+pub const NO_MOUSE: Vector2 = Vector2 { x: f32::MIN, y: f32::MIN };
+
+/// Widgets that containers can position
+pub trait Bounded {
+    fn bounds(&self) -> Rectangle;
+    fn set_position(&mut self, x: f32, y: f32);
+}
+
+macro_rules! impl_bounded {
+    ($($widget:ty),+ $(,)?) => {
+        $(
+            impl Bounded for $widget {
+                fn bounds(&self) -> Rectangle {
+                    self.bounds
+                }
+
+                fn set_position(&mut self, x: f32, y: f32) {
+                    self.bounds.x = x;
+                    self.bounds.y = y;
+                }
+            }
+        )+
+    };
+}
+
+impl_bounded!(Button, TextField, Checkbox, Toggle, RadioButton, TabBar, Label, RichText);
+
+impl<T> Bounded for Dropdown<T> {
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.bounds.x = x;
+        self.bounds.y = y;
+    }
+}
+
+impl<T> Bounded for ListBox<T> {
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.bounds.x = x;
+        self.bounds.y = y;
+    }
+}
+
+impl Bounded for Table {
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.bounds.x = x;
+        self.bounds.y = y;
+    }
+}
+
+impl Bounded for Link {
+    fn bounds(&self) -> Rectangle {
+        self.label.bounds
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.label.bounds.x = x;
+        self.label.bounds.y = y;
+    }
+}
+
+impl Bounded for TreeView {
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.bounds.x = x;
+        self.bounds.y = y;
+    }
+}
+
+impl Bounded for ComboBox {
+    fn bounds(&self) -> Rectangle {
+        self.text_field.bounds
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.text_field.set_position(x, y);
+    }
+}
+
+impl<P> Bounded for TabView<P> {
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.bounds.x = x;
+        self.bounds.y = y;
+        self.bar.set_position(x, y);
+    }
+}
@@ -5,6 +5,26 @@ pub mod dropdown;
 pub mod textfield;
 pub mod button;
 pub mod style;
+pub mod radio;
+pub mod toggle;
+pub mod combobox;
+pub mod text;
+pub mod tabs;
+pub mod layout;
+pub mod cursor;
+pub mod window;
+pub mod scroll;
+pub mod modal;
+pub mod tooltip;
+pub mod context_menu;
+pub mod shortcuts;
+pub mod menu_bar;
+pub mod listbox;
+pub mod table;
+pub mod tree;
+pub mod label;
+pub mod link;
+pub mod skin;
 pub mod tests;
 
 pub const PADDING: f32 = 20.0;
@@ -23,10 +43,37 @@ macro_rules! draw_all {
     };
 }
 
+/// Draw the tooltips of several widgets; use after `draw_all!` so they appear on top
+#[macro_export]
+macro_rules! draw_tooltips {
+    ($rl:expr, $($element:expr),+ $(,)?) => {
+        $( $element.draw_tooltip($rl); )+
+    };
+}
+
 // Re-export commonly used items
 pub use style::{Style, presets};
-pub use button::Button;
+pub use button::{desaturate, Button, ButtonIcon, IconPlacement};
 pub use textfield::TextField;
-pub use checkbox::Checkbox;
-pub use dropdown::Dropdown;
+pub use checkbox::{Checkbox, CheckState};
+pub use dropdown::{Dropdown, DropdownItem, ItemIcon, RowMarker, SelectionSummary};
+pub use radio::{RadioButton, RadioGroup, RadioLayout};
+pub use toggle::Toggle;
+pub use combobox::{ComboBox, MatchMode};
+pub use tabs::{Tab, TabBar, TabEvent, TabView};
+pub use layout::Bounded;
+pub use cursor::{capture_cursor, request_cursor, CursorResolver, CursorShape};
+pub use window::{ResizeEdges, Window, WindowStack};
+pub use scroll::{ScrollArea, ScrollAxis, ScrollMode};
+pub use modal::{ConfirmResult, Dialog, DialogKind, DialogResult, ModalLayer};
+pub use tooltip::{Tooltip, TooltipPlacement};
+pub use context_menu::{ContextMenu, MenuEntry, MenuEvent, MenuItem};
+pub use shortcuts::{Binding, KeyChord, KeymapError, ShortcutScope, Shortcuts};
+pub use menu_bar::{Menu, MenuBar};
+pub use listbox::{ListBox, ListBoxEvent, RowState};
+pub use table::{CellEdit, CellEditor, Column, EditorWidget, SortDirection, Table, TableEvent};
+pub use label::{Label, RichText, RichTextEvent, TextAlign, TextRun, TextSpan};
+pub use link::{Link, LinkEvent};
+pub use skin::{NineSlice, Skin, SkinState};
+pub use tree::{DropPosition, NodeLoader, TreeEvent, TreeNode, TreeRow, TreeView};
 pub use tests::{TestResults, run_all_tests};
\ No newline at end of file
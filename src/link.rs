@@ -0,0 +1,153 @@
+use crate::cursor::{request_cursor, CursorShape};
+use crate::label::{draw_run, Label, TextSpan, LINK_COLOR, LINK_HOVER_COLOR, LINK_VISITED_COLOR};
+use crate::style::Style;
+use crate::text::measure_text_in;
+use raylib::prelude::*;
+
+/// What happened to a link during the last update
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinkEvent {
+    /// Carries the link's target; opening it is up to the app
+    Clicked(String),
+}
+
+/// Underlined text that reports its target when clicked, or when Enter or Space is pressed while focused
+pub struct Link {
+    /// The text, sized to fit it; its hover state is the link's
+    pub label: Label,
+    pub target: String,
+    pub link_color: Color,
+    pub link_hover_color: Color,
+    pub visited_color: Color,
+    pub visited: bool,
+    pub enabled: bool,
+    /// The mouse went down on the link and hasn't been released yet
+    pub is_pressed: bool,
+    pub is_focused: bool,
+    pub events: Vec<LinkEvent>,
+}
+
+impl Link {
+    pub fn new(x: f32, y: f32, text: &str, target: &str) -> Self {
+        let mut link = Self {
+            label: Label::new(x, y, 0.0, 0.0, text).with_wrap(false),
+            target: target.to_string(),
+            link_color: LINK_COLOR,
+            link_hover_color: LINK_HOVER_COLOR,
+            visited_color: LINK_VISITED_COLOR,
+            visited: false,
+            enabled: true,
+            is_pressed: false,
+            is_focused: false,
+            events: Vec::new(),
+        };
+        link.fit_to_text();
+        link
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.label.style = style;
+        self.fit_to_text();
+        self
+    }
+
+    pub fn with_colors(mut self, normal: Color, hover: Color, visited: Color) -> Self {
+        self.link_color = normal;
+        self.link_hover_color = hover;
+        self.visited_color = visited;
+        self
+    }
+
+    pub fn with_visited(mut self, visited: bool) -> Self {
+        self.visited = visited;
+        self
+    }
+
+    /// Size the bounds to the text; call after changing the text or style
+    pub fn fit_to_text(&mut self) {
+        let style = &self.label.style;
+        self.label.bounds.width = measure_text_in(style.font.as_deref(), &self.label.text, style.font_size);
+        self.label.bounds.height = style.font_size as f32;
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        self.events.clear();
+        self.handle_mouse(
+            mouse,
+            rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT),
+            rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT),
+            rl.is_mouse_button_released(MouseButton::MOUSE_BUTTON_LEFT),
+        );
+
+        if self.is_focused && (rl.is_key_pressed(KeyboardKey::KEY_ENTER) || rl.is_key_pressed(KeyboardKey::KEY_SPACE)) {
+            self.activate();
+        }
+    }
+
+    /// Hover, focus and clicks from the left button's state; a click needs both the press and the release on the link
+    pub(crate) fn handle_mouse(&mut self, mouse: Vector2, pressed: bool, down: bool, released: bool) {
+        self.label.update(mouse);
+        if !self.enabled {
+            self.label.is_hovered = false;
+            self.is_pressed = false;
+            self.is_focused = false;
+            return;
+        }
+
+        let hovered = self.label.is_hovered;
+        if hovered {
+            request_cursor(CursorShape::PointingHand);
+        }
+        if pressed {
+            self.is_focused = hovered;
+            self.is_pressed = hovered;
+        }
+        if released && self.is_pressed && hovered {
+            self.activate();
+        }
+        if !down {
+            self.is_pressed = false;
+        }
+    }
+
+    /// Follow the link as if it had been clicked
+    pub fn activate(&mut self) {
+        if self.enabled {
+            self.visited = true;
+            self.events.push(LinkEvent::Clicked(self.target.clone()));
+        }
+    }
+
+    pub fn is_clicked(&self) -> bool {
+        !self.events.is_empty()
+    }
+
+    pub fn color(&self) -> Color {
+        if !self.enabled {
+            self.label.style.text_color_disabled
+        } else if self.label.is_hovered || self.is_pressed {
+            self.link_hover_color
+        } else if self.visited {
+            self.visited_color
+        } else {
+            self.link_color
+        }
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        let span = TextSpan {
+            underline: true,
+            ..TextSpan::new(&self.label.text)
+        };
+        let color = self.color();
+        for run in self.label.layout() {
+            draw_run(d, &self.label.style, &run, &span, color);
+        }
+
+        if self.is_focused {
+            let bounds = self.label.bounds;
+            let ring = Rectangle::new(bounds.x - 2.0, bounds.y - 2.0, bounds.width + 4.0, bounds.height + 5.0);
+            d.draw_rectangle_lines_ex(ring, 1.0, self.label.style.border_color_active);
+        }
+    }
+}
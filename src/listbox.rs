@@ -0,0 +1,455 @@
+use crate::cursor::{request_cursor, CursorShape};
+use crate::dropdown::{
+    drag_scroll, draw_scrollbar, scrollbar_cursor, scrollbar_thumb, scrollbar_track, start_scroll_drag, SCROLLBAR_WIDTH,
+};
+use crate::scroll::ScrollAxis;
+use crate::style::Style;
+use crate::text::truncate_with_ellipsis;
+use raylib::prelude::*;
+use std::ops::Range;
+
+/// What happened in a list box during the last update
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ListBoxEvent {
+    SelectionChanged,
+    /// An item was double-clicked or Enter was pressed on it
+    Activated(usize),
+}
+
+/// How a row is being shown, passed to custom row renderers
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RowState {
+    pub index: usize,
+    pub bounds: Rectangle,
+    pub selected: bool,
+    pub focused: bool,
+    pub hovered: bool,
+}
+
+/// An always-visible, scrollable list of typed values; only the visible rows are drawn
+pub struct ListBox<T = String> {
+    pub bounds: Rectangle,
+    pub items: Vec<T>,
+    pub display: Box<dyn Fn(&T) -> String>,
+    pub style: Style,
+    pub item_height: f32,
+    pub multi_select: bool,
+    /// Selected item indices, in ascending order
+    pub selected: Vec<usize>,
+    /// Item the keyboard acts on
+    pub focus_index: Option<usize>,
+    pub hover_index: Option<usize>,
+    /// First visible row
+    pub scroll_offset: usize,
+    /// Receives keyboard input; set by clicking the list
+    pub is_focused: bool,
+    pub is_scrolling: bool,
+    /// Seconds between clicks that count as a double-click
+    pub double_click_time: f64,
+    pub events: Vec<ListBoxEvent>,
+    /// Where Shift+click and Shift+arrow ranges start
+    anchor: Option<usize>,
+    last_click: Option<(usize, f64)>,
+    scroll_grab: f32,
+}
+
+impl<T: ToString + 'static> ListBox<T> {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, items: Vec<T>) -> Self {
+        Self::new_with_display(x, y, width, height, items, |item: &T| item.to_string())
+    }
+}
+
+impl<T> ListBox<T> {
+    /// Create a list box for values that are labelled by `display`
+    pub fn new_with_display(
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        items: Vec<T>,
+        display: impl Fn(&T) -> String + 'static,
+    ) -> Self {
+        Self {
+            bounds: Rectangle::new(x, y, width, height),
+            items,
+            display: Box::new(display),
+            style: Style::default(),
+            item_height: 24.0,
+            multi_select: false,
+            selected: Vec::new(),
+            focus_index: None,
+            hover_index: None,
+            scroll_offset: 0,
+            is_focused: false,
+            is_scrolling: false,
+            double_click_time: 0.4,
+            events: Vec::new(),
+            anchor: None,
+            last_click: None,
+            scroll_grab: 0.0,
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_item_height(mut self, height: f32) -> Self {
+        self.item_height = height;
+        self
+    }
+
+    /// Allow Ctrl+click, Shift+click and Shift+arrow selection of several items
+    pub fn with_multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &mut RaylibHandle) {
+        self.events.clear();
+        let hovered = self.bounds.check_collision_point_rec(mouse);
+
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.is_focused = hovered;
+            if self.scrollbar_track().is_some_and(|track| track.check_collision_point_rec(mouse)) {
+                self.start_scroll_drag(mouse);
+            } else if let Some(index) = self.item_at(mouse) {
+                let ctrl = rl.is_key_down(KeyboardKey::KEY_LEFT_CONTROL) || rl.is_key_down(KeyboardKey::KEY_RIGHT_CONTROL);
+                let shift = rl.is_key_down(KeyboardKey::KEY_LEFT_SHIFT) || rl.is_key_down(KeyboardKey::KEY_RIGHT_SHIFT);
+                self.click_item(index, ctrl, shift, rl.get_time());
+            }
+        }
+
+        if self.is_scrolling && rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.drag_scroll(mouse);
+        }
+        if rl.is_mouse_button_released(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.is_scrolling = false;
+        }
+
+        if hovered {
+            let wheel_move = rl.get_mouse_wheel_move() as i32;
+            if wheel_move != 0 {
+                self.scroll_offset = (self.scroll_offset as i32 - wheel_move).clamp(0, self.max_scroll() as i32) as usize;
+            }
+        }
+        self.hover_index = if self.is_scrolling { None } else { self.item_at(mouse) };
+        if self.hover_index.is_some() {
+            request_cursor(CursorShape::PointingHand);
+        }
+        if let Some(track) = self.scrollbar_track() {
+            scrollbar_cursor(ScrollAxis::Vertical, self.scrollbar_thumb(track), mouse, self.is_scrolling);
+        }
+
+        if self.is_focused {
+            self.handle_keyboard(rl);
+        }
+    }
+
+    /// Arrows, Page Up/Down and Home/End move the focus (Shift extends the selection),
+    /// Ctrl+A selects everything, Space selects the focused item and Enter activates it
+    pub fn handle_keyboard(&mut self, rl: &RaylibHandle) {
+        let key_down = |key| rl.is_key_pressed(key) || rl.is_key_pressed_repeat(key);
+        let ctrl = rl.is_key_down(KeyboardKey::KEY_LEFT_CONTROL) || rl.is_key_down(KeyboardKey::KEY_RIGHT_CONTROL);
+        let shift = rl.is_key_down(KeyboardKey::KEY_LEFT_SHIFT) || rl.is_key_down(KeyboardKey::KEY_RIGHT_SHIFT);
+        let page = self.visible_row_count().max(1) as i32;
+
+        if key_down(KeyboardKey::KEY_DOWN) {
+            self.move_focus(1, shift);
+        } else if key_down(KeyboardKey::KEY_UP) {
+            self.move_focus(-1, shift);
+        } else if key_down(KeyboardKey::KEY_PAGE_DOWN) {
+            self.move_focus(page, shift);
+        } else if key_down(KeyboardKey::KEY_PAGE_UP) {
+            self.move_focus(-page, shift);
+        } else if rl.is_key_pressed(KeyboardKey::KEY_HOME) {
+            self.move_focus(-(self.items.len() as i32), shift);
+        } else if rl.is_key_pressed(KeyboardKey::KEY_END) {
+            self.move_focus(self.items.len() as i32, shift);
+        }
+
+        if ctrl && rl.is_key_pressed(KeyboardKey::KEY_A) {
+            self.select_all();
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_SPACE) {
+            if let Some(index) = self.focus_index {
+                if ctrl && self.multi_select {
+                    self.toggle(index);
+                } else {
+                    self.select(index);
+                }
+            }
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_ENTER) {
+            if let Some(index) = self.focus_index {
+                self.events.push(ListBoxEvent::Activated(index));
+            }
+        }
+    }
+
+    /// Select as a click would: Ctrl toggles, Shift selects a range from the last click,
+    /// and a second click on the same item within `double_click_time` activates it
+    pub fn click_item(&mut self, index: usize, ctrl: bool, shift: bool, time: f64) {
+        if index >= self.items.len() {
+            return;
+        }
+        if self.multi_select && shift {
+            self.select_range_to(index, ctrl);
+        } else if self.multi_select && ctrl {
+            self.toggle(index);
+        } else {
+            self.select(index);
+        }
+        self.focus_index = Some(index);
+
+        let double_click = self
+            .last_click
+            .is_some_and(|(last, at)| last == index && time - at <= self.double_click_time);
+        if double_click {
+            self.events.push(ListBoxEvent::Activated(index));
+            self.last_click = None;
+        } else {
+            self.last_click = Some((index, time));
+        }
+    }
+
+    /// Move the focus by `delta` items; `extend` grows the selection from the anchor instead of replacing it
+    pub fn move_focus(&mut self, delta: i32, extend: bool) {
+        if self.items.is_empty() {
+            return;
+        }
+        // Without focus, moving down starts just above the first item and moving up just below the last
+        let start = match self.focus_index {
+            Some(current) => current as i32,
+            None if delta < 0 => self.items.len() as i32,
+            None => -1,
+        };
+        let index = (start + delta).clamp(0, self.items.len() as i32 - 1) as usize;
+        if self.multi_select && extend {
+            self.select_range_to(index, false);
+        } else {
+            self.select(index);
+        }
+        self.focus_index = Some(index);
+        self.scroll_to(index);
+    }
+
+    /// Select only `index`, making it the anchor for range selection
+    pub fn select(&mut self, index: usize) {
+        if index >= self.items.len() {
+            return;
+        }
+        self.anchor = Some(index);
+        self.set_selection(vec![index]);
+    }
+
+    /// Add or remove `index` from a multi-selection
+    pub fn toggle(&mut self, index: usize) {
+        if index >= self.items.len() {
+            return;
+        }
+        self.anchor = Some(index);
+        let mut selected = self.selected.clone();
+        match selected.binary_search(&index) {
+            Ok(position) => {
+                selected.remove(position);
+            }
+            Err(position) => selected.insert(position, index),
+        }
+        self.set_selection(selected);
+    }
+
+    /// Select from the anchor to `index`, replacing the selection or, with `add`, adding to it
+    fn select_range_to(&mut self, index: usize, add: bool) {
+        let anchor = self.anchor.unwrap_or(index);
+        let range = anchor.min(index)..=anchor.max(index);
+        let mut selected = if add { self.selected.clone() } else { Vec::new() };
+        selected.extend(range);
+        selected.sort_unstable();
+        selected.dedup();
+        self.anchor = Some(anchor);
+        self.set_selection(selected);
+    }
+
+    pub fn select_all(&mut self) {
+        if self.multi_select {
+            self.set_selection((0..self.items.len()).collect());
+        }
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+        self.set_selection(Vec::new());
+    }
+
+    fn set_selection(&mut self, selected: Vec<usize>) {
+        if selected != self.selected {
+            self.selected = selected;
+            self.events.push(ListBoxEvent::SelectionChanged);
+        }
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.binary_search(&index).is_ok()
+    }
+
+    /// The first selected item
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected.first().copied()
+    }
+
+    pub fn selected_value(&self) -> Option<&T> {
+        self.selected_index().and_then(|index| self.items.get(index))
+    }
+
+    pub fn selected_values(&self) -> Vec<&T> {
+        self.selected.iter().filter_map(|&index| self.items.get(index)).collect()
+    }
+
+    /// Replace the items, dropping the selection and scrolling back to the top
+    pub fn set_items(&mut self, items: Vec<T>) {
+        self.items = items;
+        self.clear_selection();
+        self.focus_index = None;
+        self.hover_index = None;
+        self.scroll_offset = 0;
+        self.last_click = None;
+    }
+
+    pub fn visible_row_count(&self) -> usize {
+        ((self.bounds.height / self.item_height).floor() as usize).max(1)
+    }
+
+    /// Items with a row on screen; rows past the end of the list are not included
+    pub fn visible_range(&self) -> Range<usize> {
+        let end = (self.scroll_offset + self.visible_row_count()).min(self.items.len());
+        self.scroll_offset.min(end)..end
+    }
+
+    pub fn max_scroll(&self) -> usize {
+        self.items.len().saturating_sub(self.visible_row_count())
+    }
+
+    /// Scroll just enough for `index` to be visible
+    pub fn scroll_to(&mut self, index: usize) {
+        if index < self.scroll_offset {
+            self.scroll_offset = index;
+        } else if index >= self.scroll_offset + self.visible_row_count() {
+            self.scroll_offset = index + 1 - self.visible_row_count();
+        }
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll());
+    }
+
+    /// Bounds of the row showing `index`, if it is visible
+    pub fn row_bounds(&self, index: usize) -> Option<Rectangle> {
+        if !self.visible_range().contains(&index) {
+            return None;
+        }
+        let width = self.bounds.width - if self.max_scroll() > 0 { SCROLLBAR_WIDTH } else { 0.0 };
+        Some(Rectangle::new(
+            self.bounds.x,
+            self.bounds.y + (index - self.scroll_offset) as f32 * self.item_height,
+            width,
+            self.item_height,
+        ))
+    }
+
+    pub fn item_at(&self, point: Vector2) -> Option<usize> {
+        self.visible_range()
+            .find(|&index| self.row_bounds(index).is_some_and(|row| row.check_collision_point_rec(point)))
+    }
+
+    /// Track along the right edge, present only when the list scrolls
+    pub fn scrollbar_track(&self) -> Option<Rectangle> {
+        if self.max_scroll() == 0 {
+            return None;
+        }
+        Some(scrollbar_track(self.bounds))
+    }
+
+    fn scrollbar_thumb(&self, track: Rectangle) -> Rectangle {
+        let (visible, total) = (self.visible_row_count() as f32, self.items.len() as f32);
+        scrollbar_thumb(ScrollAxis::Vertical, track, visible, total, self.scroll_offset as f32)
+    }
+
+    /// Grab the thumb where it was clicked, or centre it on the click when the track was hit
+    fn start_scroll_drag(&mut self, mouse: Vector2) {
+        let Some(track) = self.scrollbar_track() else {
+            return;
+        };
+        self.scroll_grab = start_scroll_drag(ScrollAxis::Vertical, self.scrollbar_thumb(track), mouse);
+        self.is_scrolling = true;
+        self.drag_scroll(mouse);
+    }
+
+    fn drag_scroll(&mut self, mouse: Vector2) {
+        let Some(track) = self.scrollbar_track() else {
+            return;
+        };
+        let thumb = self.scrollbar_thumb(track);
+        let offset = drag_scroll(ScrollAxis::Vertical, track, thumb, self.scroll_grab, mouse, self.max_scroll() as f32);
+        self.scroll_offset = offset.round() as usize;
+    }
+
+    fn row_state(&self, index: usize, bounds: Rectangle) -> RowState {
+        RowState {
+            index,
+            bounds,
+            selected: self.is_selected(index),
+            focused: self.is_focused && self.focus_index == Some(index),
+            hovered: self.hover_index == Some(index),
+        }
+    }
+
+    /// Draw with the default rows: the item's label over a selection or hover highlight
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        self.draw_with(d, |d, item, row| {
+            let color = if row.selected {
+                self.style.text_color_pressed
+            } else {
+                self.style.text_color
+            };
+            let label = truncate_with_ellipsis(&(self.display)(item), row.bounds.width - self.style.padding * 2.0, self.style.font_size);
+            d.draw_text(
+                &label,
+                (row.bounds.x + self.style.padding) as i32,
+                (row.bounds.y + (row.bounds.height - self.style.font_size as f32) / 2.0) as i32,
+                self.style.font_size,
+                color,
+            );
+        });
+    }
+
+    /// Draw with `render_row` drawing each visible row's content over its highlight
+    pub fn draw_with<D: RaylibDraw>(&self, d: &mut D, mut render_row: impl FnMut(&mut D, &T, RowState)) {
+        d.draw_rectangle_rec(self.bounds, self.style.background_color);
+
+        for index in self.visible_range() {
+            let Some(bounds) = self.row_bounds(index) else {
+                continue;
+            };
+            let state = self.row_state(index, bounds);
+            if state.selected {
+                d.draw_rectangle_rec(bounds, self.style.pressed_color);
+            } else if state.hovered {
+                d.draw_rectangle_rec(bounds, self.style.hover_color);
+            }
+            render_row(d, &self.items[index], state);
+            if state.focused {
+                d.draw_rectangle_lines_ex(bounds, 1.0, self.style.border_color_active);
+            }
+        }
+
+        if let Some(track) = self.scrollbar_track() {
+            draw_scrollbar(d, &self.style, track, self.scrollbar_thumb(track), self.is_scrolling);
+        }
+
+        let border_color = if self.is_focused {
+            self.style.border_color_active
+        } else {
+            self.style.border_color
+        };
+        d.draw_rectangle_lines_ex(self.bounds, self.style.border_thickness, border_color);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::context_menu::{ContextMenu, MenuEntry, MenuEvent, MenuItem};
+use crate::cursor::{request_cursor, CursorShape};
+use crate::shortcuts::{key_from_name, parse_sequence, ShortcutScope, Shortcuts};
+use crate::style::Style;
+use crate::text::{draw_mnemonic_underline, measure_text, parse_mnemonic};
+use raylib::prelude::*;
+
+/// One title in the bar and the menu it opens
+pub struct Menu {
+    pub title: String,
+    /// Char index of the title letter that opens the menu with Alt
+    pub mnemonic: Option<usize>,
+    pub popup: ContextMenu,
+}
+
+impl Menu {
+    /// A '&' in the title marks the Alt mnemonic, as in "&File"
+    pub fn new(title: &str, entries: Vec<MenuEntry>) -> Self {
+        let (title, mnemonic) = parse_mnemonic(title);
+        Self {
+            title,
+            mnemonic,
+            popup: ContextMenu::new(Rectangle::new(0.0, 0.0, 0.0, 0.0), entries),
+        }
+    }
+
+    pub fn mnemonic_char(&self) -> Option<char> {
+        self.mnemonic.and_then(|index| self.title.chars().nth(index))
+    }
+}
+
+/// A row of menu titles across the top of the window, each opening a dropdown menu
+pub struct MenuBar {
+    pub bounds: Rectangle,
+    pub menus: Vec<Menu>,
+    pub style: Style,
+    pub open_index: Option<usize>,
+    pub hover_index: Option<usize>,
+    pub events: Vec<MenuEvent>,
+    last_mouse: Vector2,
+    screen_bounds: Option<Rectangle>,
+}
+
+impl MenuBar {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, menus: Vec<Menu>) -> Self {
+        Self {
+            bounds: Rectangle::new(x, y, width, height),
+            menus,
+            style: Style::default(),
+            open_index: None,
+            hover_index: None,
+            events: Vec::new(),
+            last_mouse: Vector2::zero(),
+            screen_bounds: None,
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        for menu in &mut self.menus {
+            menu.popup.style = style.clone();
+        }
+        self.style = style;
+        self
+    }
+
+    /// Bind every item's shortcut text to its id in the global scope of a shared registry.
+    /// Bindings already there are kept, so it can be called again after changing items.
+    pub fn register_accelerators(&self, shortcuts: &mut Shortcuts) {
+        fn collect(entries: &[MenuEntry], shortcuts: &mut Shortcuts) {
+            for entry in entries {
+                if let MenuEntry::Item(item) = entry {
+                    if let Some(sequence) = item.shortcut.as_deref().and_then(parse_sequence) {
+                        let bound = shortcuts.bindings.iter().any(|binding| {
+                            binding.sequence == sequence && binding.action == item.id && binding.scope == ShortcutScope::Global
+                        });
+                        if !bound {
+                            shortcuts.bind_sequence(sequence, &item.id, ShortcutScope::Global, 0);
+                        }
+                    }
+                    collect(&item.submenu, shortcuts);
+                }
+            }
+        }
+
+        for menu in &self.menus {
+            collect(&menu.popup.entries, shortcuts);
+        }
+    }
+
+    /// Run the item whose accelerator the registry completed this frame; call after `update` and
+    /// `shortcuts.poll(rl)`. Keys won by a focused or modal binding, such as a text field's Ctrl+C, are left alone
+    pub fn handle_shortcuts(&mut self, shortcuts: &Shortcuts) {
+        if let Some(id) = shortcuts.fired_in(&ShortcutScope::Global) {
+            self.trigger(id);
+        }
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        self.events.clear();
+        // Menus are opened by the bar rather than their own update, so they need the screen from here
+        self.screen_bounds = Some(Rectangle::new(0.0, 0.0, rl.get_screen_width() as f32, rl.get_screen_height() as f32));
+
+        // Alt+letter opens the menu with that mnemonic
+        let alt = rl.is_key_down(KeyboardKey::KEY_LEFT_ALT) || rl.is_key_down(KeyboardKey::KEY_RIGHT_ALT);
+        if alt {
+            let pressed = self.menus.iter().position(|menu| {
+                menu.mnemonic_char()
+                    .and_then(|letter| key_from_name(&letter.to_string()))
+                    .is_some_and(|key| rl.is_key_pressed(key))
+            });
+            if let Some(index) = pressed {
+                self.open_menu(index);
+                self.menus[index].popup.move_focus(1);
+                return;
+            }
+        }
+
+        self.hover_index = self.title_at(mouse);
+        if self.hover_index.is_some() {
+            request_cursor(CursorShape::PointingHand);
+        }
+
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.click_at(mouse);
+            self.last_mouse = mouse;
+            return;
+        }
+
+        let Some(open) = self.open_index else {
+            return;
+        };
+
+        // Sliding across the titles switches between open menus
+        if let Some(index) = self.hover_index {
+            if index != open {
+                self.open_menu(index);
+                return;
+            }
+        }
+
+        if mouse != self.last_mouse {
+            self.menus[open].popup.hover_at(mouse);
+            self.last_mouse = mouse;
+        }
+        self.menus[open].popup.request_hover_cursor(mouse);
+
+        // Left/Right on the top level of a menu move to the neighbouring menu
+        let popup = &self.menus[open].popup;
+        let at_top_level = popup.open_path.is_empty();
+        if at_top_level && rl.is_key_pressed(KeyboardKey::KEY_LEFT) {
+            self.switch_menu(-1);
+            return;
+        }
+        let focused_has_submenu = popup
+            .focus_index
+            .and_then(|index| popup.entries.get(index))
+            .is_some_and(|entry| matches!(entry, MenuEntry::Item(item) if item.has_submenu()));
+        if at_top_level && !focused_has_submenu && rl.is_key_pressed(KeyboardKey::KEY_RIGHT) {
+            self.switch_menu(1);
+            return;
+        }
+
+        let popup = &mut self.menus[open].popup;
+        popup.handle_keyboard(rl);
+        self.events.append(&mut popup.events);
+        if !popup.is_open {
+            self.open_index = None;
+        }
+    }
+
+    /// Open a title's menu, toggle it closed, or pick an item; clicks elsewhere close the menu
+    pub fn click_at(&mut self, mouse: Vector2) {
+        if let Some(index) = self.title_at(mouse) {
+            if self.open_index == Some(index) {
+                self.close_menu();
+            } else {
+                self.open_menu(index);
+            }
+            return;
+        }
+
+        if let Some(open) = self.open_index {
+            let popup = &mut self.menus[open].popup;
+            if !popup.click_at(mouse) {
+                popup.close();
+            }
+            self.events.append(&mut popup.events);
+            if !popup.is_open {
+                self.open_index = None;
+            }
+        }
+    }
+
+    pub fn open_menu(&mut self, index: usize) {
+        self.close_menu();
+        let title = self.title_bounds(index);
+        if let Some(menu) = self.menus.get_mut(index) {
+            menu.popup.screen_bounds = self.screen_bounds;
+            menu.popup.open_at(Vector2::new(title.x, self.bounds.y + self.bounds.height));
+            self.open_index = Some(index);
+        }
+    }
+
+    pub fn close_menu(&mut self) {
+        if let Some(open) = self.open_index.take() {
+            self.menus[open].popup.close();
+        }
+    }
+
+    /// Open the menu `direction` steps away from the open one, wrapping around
+    pub fn switch_menu(&mut self, direction: i32) {
+        let (Some(open), count) = (self.open_index, self.menus.len() as i32) else {
+            return;
+        };
+        let next = (open as i32 + direction).rem_euclid(count) as usize;
+        self.open_menu(next);
+        self.menus[next].popup.move_focus(1);
+    }
+
+    /// Run the item with `id` as if it had been clicked, toggling checkable items
+    pub fn trigger(&mut self, id: &str) {
+        for menu in &mut self.menus {
+            if let Some(item) = find_item_mut(&mut menu.popup.entries, id) {
+                if !item.enabled {
+                    return;
+                }
+                let event = match &mut item.checked {
+                    Some(checked) => {
+                        *checked = !*checked;
+                        MenuEvent::Toggled(id.to_string(), *checked)
+                    }
+                    None => MenuEvent::Activated(id.to_string()),
+                };
+                self.events.push(event);
+                return;
+            }
+        }
+    }
+
+    /// Find an item in any menu by id
+    pub fn item(&self, id: &str) -> Option<&MenuItem> {
+        self.menus.iter().find_map(|menu| menu.popup.item(id))
+    }
+
+    fn title_width(&self, index: usize) -> f32 {
+        measure_text(&self.menus[index].title, self.style.font_size) + self.style.padding * 4.0
+    }
+
+    pub fn title_bounds(&self, index: usize) -> Rectangle {
+        let x = self.bounds.x + (0..index).map(|i| self.title_width(i)).sum::<f32>();
+        Rectangle::new(x, self.bounds.y, self.title_width(index), self.bounds.height)
+    }
+
+    pub fn title_at(&self, point: Vector2) -> Option<usize> {
+        (0..self.menus.len()).find(|&index| self.title_bounds(index).check_collision_point_rec(point))
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        d.draw_rectangle_rec(self.bounds, self.style.background_color);
+        d.draw_line_ex(
+            Vector2::new(self.bounds.x, self.bounds.y + self.bounds.height),
+            Vector2::new(self.bounds.x + self.bounds.width, self.bounds.y + self.bounds.height),
+            self.style.border_thickness,
+            self.style.border_color,
+        );
+
+        let text_y = self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0;
+        for (index, menu) in self.menus.iter().enumerate() {
+            let title = self.title_bounds(index);
+            let is_open = self.open_index == Some(index);
+            if is_open {
+                d.draw_rectangle_rec(title, self.style.pressed_color);
+            } else if self.hover_index == Some(index) {
+                d.draw_rectangle_rec(title, self.style.hover_color);
+            }
+
+            let text_color = if is_open {
+                self.style.text_color_pressed
+            } else {
+                self.style.text_color
+            };
+            let text_x = title.x + self.style.padding * 2.0;
+            d.draw_text(&menu.title, text_x as i32, text_y as i32, self.style.font_size, text_color);
+            if let Some(mnemonic) = menu.mnemonic {
+                draw_mnemonic_underline(d, &menu.title, mnemonic, text_x, text_y, self.style.font_size, text_color);
+            }
+        }
+
+        if let Some(open) = self.open_index {
+            self.menus[open].popup.draw(d);
+        }
+    }
+}
+
+fn find_item_mut<'a>(entries: &'a mut [MenuEntry], id: &str) -> Option<&'a mut MenuItem> {
+    for entry in entries {
+        if let MenuEntry::Item(item) = entry {
+            if item.id == id {
+                return Some(item);
+            }
+            if let Some(found) = find_item_mut(&mut item.submenu, id) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
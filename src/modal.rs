@@ -0,0 +1,304 @@
+use crate::button::Button;
+use crate::layout::NO_MOUSE;
+use crate::style::{presets, Style};
+use crate::text::{measure_text, wrap_text};
+use crate::textfield::TextField;
+use raylib::prelude::*;
+use std::collections::VecDeque;
+
+const DIALOG_WIDTH: f32 = 380.0;
+const BUTTON_WIDTH: f32 = 90.0;
+const BUTTON_HEIGHT: f32 = 32.0;
+const INPUT_HEIGHT: f32 = 30.0;
+const SPACING: f32 = 12.0;
+
+/// Answer to a `confirm` dialog
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfirmResult {
+    Yes,
+    No,
+    Cancel,
+}
+
+/// What a dialog returned when it closed
+#[derive(Clone, Debug, PartialEq)]
+pub enum DialogResult {
+    /// An alert was dismissed
+    Dismissed,
+    Confirm(ConfirmResult),
+    /// The prompt's text, or `None` if it was cancelled
+    Prompt(Option<String>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DialogKind {
+    Alert,
+    Confirm,
+    Prompt,
+}
+
+/// A single dialog box; create them through `ModalLayer`
+pub struct Dialog {
+    pub id: usize,
+    pub kind: DialogKind,
+    pub message: String,
+    pub bounds: Rectangle,
+    pub buttons: Vec<Button>,
+    pub input: Option<TextField>,
+}
+
+impl Dialog {
+    fn new(id: usize, kind: DialogKind, message: &str) -> Self {
+        let (labels, styles): (Vec<&str>, Vec<Style>) = match kind {
+            DialogKind::Alert => (vec!["OK"], vec![presets::button_primary()]),
+            DialogKind::Confirm => (
+                vec!["Yes", "No", "Cancel"],
+                vec![presets::button_primary(), presets::button_secondary(), presets::button_secondary()],
+            ),
+            DialogKind::Prompt => (
+                vec!["OK", "Cancel"],
+                vec![presets::button_primary(), presets::button_secondary()],
+            ),
+        };
+        let buttons = labels
+            .into_iter()
+            .zip(styles)
+            .map(|(label, style)| Button::new(0.0, 0.0, BUTTON_WIDTH, BUTTON_HEIGHT, label).with_style(style))
+            .collect();
+
+        let input = (kind == DialogKind::Prompt).then(|| {
+            let mut field = TextField::new(0.0, 0.0, DIALOG_WIDTH - SPACING * 2.0, INPUT_HEIGHT, 256)
+                .with_style(presets::textfield_default());
+            field.activate();
+            field
+        });
+
+        Self {
+            id,
+            kind,
+            message: message.to_string(),
+            bounds: Rectangle::new(0.0, 0.0, DIALOG_WIDTH, 0.0),
+            buttons,
+            input,
+        }
+    }
+
+    /// Result of pressing the button at `index`
+    pub fn button_result(&self, index: usize) -> DialogResult {
+        match (self.kind, index) {
+            (DialogKind::Alert, _) => DialogResult::Dismissed,
+            (DialogKind::Confirm, 0) => DialogResult::Confirm(ConfirmResult::Yes),
+            (DialogKind::Confirm, 1) => DialogResult::Confirm(ConfirmResult::No),
+            (DialogKind::Confirm, _) => DialogResult::Confirm(ConfirmResult::Cancel),
+            (DialogKind::Prompt, 0) => DialogResult::Prompt(self.input.as_ref().map(|field| field.text.clone())),
+            (DialogKind::Prompt, _) => DialogResult::Prompt(None),
+        }
+    }
+
+    /// Result of Enter: the first button
+    pub fn default_result(&self) -> DialogResult {
+        self.button_result(0)
+    }
+
+    /// Result of Esc: the last button
+    pub fn cancel_result(&self) -> DialogResult {
+        self.button_result(self.buttons.len().saturating_sub(1))
+    }
+
+    /// The message wrapped to the dialog's inner width
+    pub(crate) fn message_lines(&self, font_size: i32) -> Vec<String> {
+        wrap_text(&self.message, DIALOG_WIDTH - SPACING * 2.0, font_size)
+    }
+
+    /// Size the dialog to its contents and center it in `area`
+    pub fn layout(&mut self, area: Rectangle, style: &Style) {
+        let line_height = style.font_size as f32 + 4.0;
+        let message_height = self.message_lines(style.font_size).len().max(1) as f32 * line_height;
+        let input_height = if self.input.is_some() { INPUT_HEIGHT + SPACING } else { 0.0 };
+        let height = SPACING + message_height + SPACING + input_height + BUTTON_HEIGHT + SPACING;
+
+        self.bounds = Rectangle::new(
+            area.x + (area.width - DIALOG_WIDTH) / 2.0,
+            area.y + (area.height - height) / 2.0,
+            DIALOG_WIDTH,
+            height,
+        );
+
+        let mut y = self.bounds.y + SPACING + message_height + SPACING;
+        if let Some(field) = &mut self.input {
+            field.bounds.x = self.bounds.x + SPACING;
+            field.bounds.y = y;
+            y += INPUT_HEIGHT + SPACING;
+        }
+
+        // Buttons are right-aligned with the default action first
+        let count = self.buttons.len() as f32;
+        let mut x = self.bounds.x + self.bounds.width - SPACING - count * BUTTON_WIDTH - (count - 1.0) * SPACING;
+        for button in &mut self.buttons {
+            button.bounds.x = x;
+            button.bounds.y = y;
+            x += BUTTON_WIDTH + SPACING;
+        }
+    }
+
+    fn draw(&self, d: &mut impl RaylibDraw, style: &Style) {
+        d.draw_rectangle_rounded(self.bounds, 0.05, 8, style.background_color);
+        d.draw_rectangle_rounded_lines(self.bounds, 0.05, 8, style.border_color);
+
+        let line_height = style.font_size as f32 + 4.0;
+        let mut y = self.bounds.y + SPACING;
+        for line in self.message_lines(style.font_size) {
+            let x = self.bounds.x + (self.bounds.width - measure_text(&line, style.font_size)) / 2.0;
+            d.draw_text(&line, x as i32, y as i32, style.font_size, style.text_color);
+            y += line_height;
+        }
+
+        if let Some(field) = &self.input {
+            field.draw(d);
+        }
+        for button in &self.buttons {
+            button.draw(d);
+        }
+    }
+}
+
+/// Shows one dialog at a time above everything else and queues the rest.
+/// While a dialog is open, widgets underneath should not be updated, or be given `child_mouse`.
+pub struct ModalLayer {
+    pub dialogs: VecDeque<Dialog>,
+    pub style: Style,
+    pub dim_color: Color,
+    next_id: usize,
+    screen_bounds: Rectangle,
+}
+
+impl Default for ModalLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModalLayer {
+    pub fn new() -> Self {
+        Self {
+            dialogs: VecDeque::new(),
+            style: Style::default(),
+            dim_color: Color::new(0, 0, 0, 120),
+            next_id: 0,
+            screen_bounds: Rectangle::new(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_dim_color(mut self, color: Color) -> Self {
+        self.dim_color = color;
+        self
+    }
+
+    fn push(&mut self, kind: DialogKind, message: &str) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.dialogs.push_back(Dialog::new(id, kind, message));
+        id
+    }
+
+    /// Show a message with an OK button; returns the dialog's id
+    pub fn alert(&mut self, message: &str) -> usize {
+        self.push(DialogKind::Alert, message)
+    }
+
+    /// Ask a Yes/No/Cancel question; returns the dialog's id
+    pub fn confirm(&mut self, message: &str) -> usize {
+        self.push(DialogKind::Confirm, message)
+    }
+
+    /// Ask for a line of text; returns the dialog's id
+    pub fn prompt(&mut self, message: &str, default_text: &str) -> usize {
+        let id = self.push(DialogKind::Prompt, message);
+        if let Some(field) = self.dialogs.back_mut().and_then(|dialog| dialog.input.as_mut()) {
+            field.set_text(default_text);
+        }
+        id
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.dialogs.is_empty()
+    }
+
+    pub fn current(&self) -> Option<&Dialog> {
+        self.dialogs.front()
+    }
+
+    /// The mouse position widgets underneath should see
+    pub fn child_mouse(&self, mouse: Vector2) -> Vector2 {
+        if self.is_open() {
+            NO_MOUSE
+        } else {
+            mouse
+        }
+    }
+
+    /// Close the current dialog with `result`, returning it with the dialog's id
+    pub fn close(&mut self, result: DialogResult) -> Option<(usize, DialogResult)> {
+        self.dialogs.pop_front().map(|dialog| (dialog.id, result))
+    }
+
+    /// Close the current dialog with its default (Enter) action
+    pub fn accept(&mut self) -> Option<(usize, DialogResult)> {
+        let result = self.current()?.default_result();
+        self.close(result)
+    }
+
+    /// Close the current dialog with its cancel (Esc) action
+    pub fn cancel(&mut self) -> Option<(usize, DialogResult)> {
+        let result = self.current()?.cancel_result();
+        self.close(result)
+    }
+
+    /// Handle input for the current dialog. Returns the dialog's id and result on the frame it closes.
+    pub fn update(&mut self, mouse: Vector2, rl: &mut RaylibHandle) -> Option<(usize, DialogResult)> {
+        let area = Rectangle::new(0.0, 0.0, rl.get_screen_width() as f32, rl.get_screen_height() as f32);
+        self.screen_bounds = area;
+        let style = self.style.clone();
+        let dialog = self.dialogs.front_mut()?;
+        dialog.layout(area, &style);
+
+        if rl.is_key_pressed(KeyboardKey::KEY_ENTER) || rl.is_key_pressed(KeyboardKey::KEY_KP_ENTER) {
+            return self.accept();
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_ESCAPE) {
+            return self.cancel();
+        }
+
+        if let Some(field) = &mut dialog.input {
+            field.update(mouse, rl);
+            // Keep typing going to the prompt even after a click elsewhere in the dialog
+            if !field.is_active {
+                field.activate();
+            }
+        }
+
+        let mut clicked = None;
+        for (index, button) in dialog.buttons.iter_mut().enumerate() {
+            button.update(mouse, rl);
+            if button.is_clicked(rl) {
+                clicked = Some(index);
+            }
+        }
+        let result = dialog.button_result(clicked?);
+        self.close(result)
+    }
+
+    /// Dim the screen seen by the last `update` and draw the current dialog; call after drawing everything else
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        let Some(dialog) = self.current() else {
+            return;
+        };
+        d.draw_rectangle_rec(self.screen_bounds, self.dim_color);
+        dialog.draw(d, &self.style);
+    }
+}
@@ -0,0 +1,300 @@
+use raylib::prelude::*;
+use crate::cursor::{request_cursor, CursorShape};
+use crate::style::Style;
+use std::ffi::CString;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RadioLayout {
+    Vertical,
+    Horizontal,
+}
+
+pub struct RadioButton {
+    pub bounds: Rectangle,
+    pub is_selected: bool,
+    pub style: Style,
+    pub label: String,
+    pub is_hovered: bool,
+    pub animation_progress: f32,
+    pub is_clicked: bool,
+}
+
+impl RadioButton {
+    pub fn new(x: f32, y: f32, size: f32, label: &str) -> Self {
+        Self {
+            bounds: Rectangle::new(x, y, size, size),
+            is_selected: false,
+            style: Style::default(),
+            label: label.to_string(),
+            is_hovered: false,
+            animation_progress: 0.0,
+            is_clicked: false,
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        self.is_hovered = self.bounds.check_collision_point_rec(mouse);
+        if self.is_hovered {
+            request_cursor(CursorShape::PointingHand);
+        }
+
+        // A radio button can only be selected by clicking, never deselected
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) && self.is_hovered {
+            self.is_clicked = true;
+            if !self.is_selected {
+                self.is_selected = true;
+                self.animation_progress = 0.0;
+            }
+        }
+
+        // Update animation
+        let animation_speed = 4.0;
+        if self.is_selected && self.animation_progress < 1.0 {
+            self.animation_progress += rl.get_frame_time() * animation_speed;
+            if self.animation_progress > 1.0 {
+                self.animation_progress = 1.0;
+            }
+        } else if !self.is_selected && self.animation_progress > 0.0 {
+            self.animation_progress -= rl.get_frame_time() * animation_speed;
+            if self.animation_progress < 0.0 {
+                self.animation_progress = 0.0;
+            }
+        }
+
+        // Reset click state
+        if rl.is_mouse_button_released(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.is_clicked = false;
+        }
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        let radius = self.bounds.width.min(self.bounds.height) / 2.0;
+        let center = Vector2::new(self.bounds.x + radius, self.bounds.y + radius);
+
+        // Draw background
+        let background_color = if self.is_hovered {
+            self.style.hover_color
+        } else {
+            self.style.background_color
+        };
+        d.draw_circle_v(center, radius, background_color);
+
+        // Draw border
+        let border_thickness = if self.is_clicked { 3.0 } else { self.style.border_thickness };
+        d.draw_ring(
+            center,
+            (radius - border_thickness).max(0.0),
+            radius,
+            0.0,
+            360.0,
+            32,
+            self.style.border_color,
+        );
+
+        // Draw inner dot with animation
+        if self.animation_progress > 0.0 {
+            let dot_color = Color::new(
+                self.style.check_color.r,
+                self.style.check_color.g,
+                self.style.check_color.b,
+                (self.style.check_color.a as f32 * self.animation_progress) as u8,
+            );
+            d.draw_circle_v(center, radius * 0.5 * self.animation_progress, dot_color);
+        }
+
+        // Draw label with proper alignment
+        let label_x = self.bounds.x + self.bounds.width + 10.0;
+        let label_y = self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0;
+
+        d.draw_text(
+            &self.label,
+            label_x as i32,
+            label_y as i32,
+            self.style.font_size,
+            self.style.text_color,
+        );
+    }
+
+    /// Width of the circle plus its label, used to lay out horizontal groups
+    pub fn total_width(&self) -> f32 {
+        let cstr = CString::new(self.label.as_str()).unwrap_or_default();
+        let label_width = unsafe { raylib::ffi::MeasureText(cstr.as_ptr(), self.style.font_size) } as f32;
+        self.bounds.width + 10.0 + label_width
+    }
+}
+
+/// A set of radio buttons where at most one option is selected at a time
+pub struct RadioGroup<T> {
+    pub position: Vector2,
+    pub buttons: Vec<RadioButton>,
+    pub values: Vec<T>,
+    pub selected_index: Option<usize>,
+    pub layout: RadioLayout,
+    pub spacing: f32,
+    pub style: Style,
+    pub is_focused: bool,
+}
+
+impl<T> RadioGroup<T> {
+    pub fn new(x: f32, y: f32, size: f32, options: Vec<(&str, T)>) -> Self {
+        let mut buttons = Vec::with_capacity(options.len());
+        let mut values = Vec::with_capacity(options.len());
+        for (label, value) in options {
+            buttons.push(RadioButton::new(x, y, size, label));
+            values.push(value);
+        }
+
+        let mut group = Self {
+            position: Vector2::new(x, y),
+            buttons,
+            values,
+            selected_index: None,
+            layout: RadioLayout::Vertical,
+            spacing: 10.0,
+            style: Style::default(),
+            is_focused: false,
+        };
+        group.layout_buttons();
+        group
+    }
+
+    /// Create a radio group from values that implement ToString, using them as labels
+    pub fn from_enum(x: f32, y: f32, size: f32, options: Vec<T>) -> Self
+    where
+        T: ToString,
+    {
+        let labels: Vec<String> = options.iter().map(|opt| opt.to_string()).collect();
+        let options = labels.iter().map(|label| label.as_str()).zip(options).collect();
+        Self::new(x, y, size, options)
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        for button in &mut self.buttons {
+            button.style = style.clone();
+        }
+        self.style = style;
+        self.layout_buttons();
+        self
+    }
+
+    pub fn with_layout(mut self, layout: RadioLayout) -> Self {
+        self.layout = layout;
+        self.layout_buttons();
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self.layout_buttons();
+        self
+    }
+
+    pub fn with_selected(mut self, index: usize) -> Self {
+        self.select(index);
+        self
+    }
+
+    /// Position every button from `position`, `layout` and `spacing`
+    pub fn layout_buttons(&mut self) {
+        let mut x = self.position.x;
+        let mut y = self.position.y;
+        for button in &mut self.buttons {
+            button.bounds.x = x;
+            button.bounds.y = y;
+            match self.layout {
+                RadioLayout::Vertical => y += button.bounds.height + self.spacing,
+                RadioLayout::Horizontal => x += button.total_width() + self.spacing,
+            }
+        }
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        self.layout_buttons();
+
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.is_focused = self.buttons.iter().any(|b| b.bounds.check_collision_point_rec(mouse));
+        }
+
+        // Keep buttons in sync with selected_index in case it was set directly
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            button.is_selected = Some(i) == self.selected_index;
+        }
+
+        for button in &mut self.buttons {
+            button.update(mouse, rl);
+        }
+
+        // A button that just became selected takes over the selection
+        let newly_selected = self
+            .buttons
+            .iter()
+            .enumerate()
+            .find(|(i, b)| b.is_selected && Some(*i) != self.selected_index)
+            .map(|(i, _)| i);
+        if let Some(index) = newly_selected {
+            self.select(index);
+        }
+
+        // Arrow keys move the selection when focused
+        if self.is_focused && !self.buttons.is_empty() {
+            let (prev_key, next_key) = match self.layout {
+                RadioLayout::Vertical => (KeyboardKey::KEY_UP, KeyboardKey::KEY_DOWN),
+                RadioLayout::Horizontal => (KeyboardKey::KEY_LEFT, KeyboardKey::KEY_RIGHT),
+            };
+            let count = self.buttons.len();
+            if rl.is_key_pressed(next_key) {
+                let next = self.selected_index.map(|i| (i + 1) % count).unwrap_or(0);
+                self.select(next);
+            } else if rl.is_key_pressed(prev_key) {
+                let prev = self.selected_index.map(|i| (i + count - 1) % count).unwrap_or(count - 1);
+                self.select(prev);
+            }
+        }
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        for button in &self.buttons {
+            button.draw(d);
+        }
+
+        // Draw focus ring around the selected (or first) button
+        if self.is_focused {
+            if let Some(button) = self.buttons.get(self.selected_index.unwrap_or(0)) {
+                let radius = button.bounds.width.min(button.bounds.height) / 2.0;
+                let center = Vector2::new(button.bounds.x + radius, button.bounds.y + radius);
+                d.draw_circle_lines_v(center, radius + 3.0, self.style.border_color_active);
+            }
+        }
+    }
+
+    /// Select the option at `index`, deselecting every other option
+    pub fn select(&mut self, index: usize) {
+        if index >= self.buttons.len() {
+            return;
+        }
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            button.is_selected = i == index;
+        }
+        self.selected_index = Some(index);
+    }
+
+    pub fn clear_selection(&mut self) {
+        for button in &mut self.buttons {
+            button.is_selected = false;
+        }
+        self.selected_index = None;
+    }
+
+    pub fn selected(&self) -> Option<&T> {
+        self.selected_index.and_then(|i| self.values.get(i))
+    }
+
+    pub fn selected_label(&self) -> Option<&String> {
+        self.selected_index.and_then(|i| self.buttons.get(i)).map(|b| &b.label)
+    }
+}
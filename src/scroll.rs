@@ -0,0 +1,352 @@
+use crate::dropdown::{drag_scroll, draw_scrollbar, scrollbar_cursor, scrollbar_thumb, start_scroll_drag, SCROLLBAR_WIDTH};
+use crate::layout::{Bounded, NO_MOUSE};
+use crate::style::Style;
+use raylib::prelude::*;
+
+/// How wheel input turns into movement
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScrollMode {
+    /// Jump straight to the new offset
+    Instant,
+    /// Ease towards the new offset over a few frames
+    Smooth,
+    /// Keep gliding after the wheel stops, slowed by `friction`
+    Kinetic,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScrollAxis {
+    Vertical,
+    Horizontal,
+}
+
+/// A clipped viewport onto content larger than itself, with scroll bars
+pub struct ScrollArea {
+    pub bounds: Rectangle,
+    pub content_size: Vector2,
+    pub scroll: Vector2,
+    pub style: Style,
+    pub scroll_mode: ScrollMode,
+    pub wheel_step: f32,
+    pub friction: f32,
+    pub velocity: Vector2,
+    pub is_hovered: bool,
+    pub dragging: Option<ScrollAxis>,
+    target: Vector2,
+    drag_grab: f32,
+}
+
+impl ScrollArea {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            bounds: Rectangle::new(x, y, width, height),
+            content_size: Vector2::new(width, height),
+            scroll: Vector2::zero(),
+            style: Style::default(),
+            scroll_mode: ScrollMode::Instant,
+            wheel_step: 40.0,
+            friction: 6.0,
+            velocity: Vector2::zero(),
+            is_hovered: false,
+            dragging: None,
+            target: Vector2::zero(),
+            drag_grab: 0.0,
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_content_size(mut self, width: f32, height: f32) -> Self {
+        self.content_size = Vector2::new(width, height);
+        self
+    }
+
+    pub fn with_scroll_mode(mut self, mode: ScrollMode) -> Self {
+        self.scroll_mode = mode;
+        self
+    }
+
+    pub fn with_wheel_step(mut self, step: f32) -> Self {
+        self.wheel_step = step;
+        self
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        self.is_hovered = self.bounds.check_collision_point_rec(mouse);
+
+        if self.is_hovered {
+            let wheel = rl.get_mouse_wheel_move_v();
+            let (mut dx, mut dy) = (wheel.x, wheel.y);
+            // Shift turns the vertical wheel into horizontal scrolling
+            if dx == 0.0 && (rl.is_key_down(KeyboardKey::KEY_LEFT_SHIFT) || rl.is_key_down(KeyboardKey::KEY_RIGHT_SHIFT)) {
+                dx = dy;
+                dy = 0.0;
+            }
+            if dx != 0.0 || dy != 0.0 {
+                self.scroll_by(Vector2::new(-dx * self.wheel_step, -dy * self.wheel_step));
+            }
+        }
+
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.press(mouse);
+        }
+        if rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.drag_to(mouse);
+        } else {
+            self.release();
+        }
+        self.request_scrollbar_cursor(mouse);
+
+        self.step(rl.get_frame_time());
+    }
+
+    /// Ask for a resize arrow along the thumb's axis while it is hovered, and keep it for the whole drag
+    pub fn request_scrollbar_cursor(&self, mouse: Vector2) {
+        for axis in [ScrollAxis::Vertical, ScrollAxis::Horizontal] {
+            if let Some(thumb) = self.scrollbar_thumb(axis) {
+                scrollbar_cursor(axis, thumb, mouse, self.dragging == Some(axis));
+            }
+        }
+    }
+
+    /// Which scroll bars are needed; showing one can shrink the viewport enough to need the other
+    fn scrollbars_shown(&self) -> (bool, bool) {
+        let mut vertical = self.content_size.y > self.bounds.height;
+        let horizontal = self.content_size.x > self.bounds.width - if vertical { SCROLLBAR_WIDTH } else { 0.0 };
+        if horizontal {
+            vertical = vertical || self.content_size.y > self.bounds.height - SCROLLBAR_WIDTH;
+        }
+        (vertical, horizontal)
+    }
+
+    /// The visible part of the content, excluding the scroll bars
+    pub fn viewport(&self) -> Rectangle {
+        let (vertical, horizontal) = self.scrollbars_shown();
+        Rectangle::new(
+            self.bounds.x,
+            self.bounds.y,
+            self.bounds.width - if vertical { SCROLLBAR_WIDTH } else { 0.0 },
+            self.bounds.height - if horizontal { SCROLLBAR_WIDTH } else { 0.0 },
+        )
+    }
+
+    pub fn max_scroll(&self) -> Vector2 {
+        let viewport = self.viewport();
+        Vector2::new(
+            (self.content_size.x - viewport.width).max(0.0),
+            (self.content_size.y - viewport.height).max(0.0),
+        )
+    }
+
+    fn clamp_offset(&self, offset: Vector2) -> Vector2 {
+        let max = self.max_scroll();
+        Vector2::new(offset.x.clamp(0.0, max.x), offset.y.clamp(0.0, max.y))
+    }
+
+    /// Jump to `offset` immediately, stopping any easing or momentum
+    pub fn set_scroll(&mut self, offset: Vector2) {
+        self.scroll = self.clamp_offset(offset);
+        self.target = self.scroll;
+        self.velocity = Vector2::zero();
+    }
+
+    /// Scroll to `offset`, easing there in `Smooth` mode
+    pub fn scroll_to(&mut self, offset: Vector2) {
+        if self.scroll_mode == ScrollMode::Smooth {
+            self.target = self.clamp_offset(offset);
+        } else {
+            self.set_scroll(offset);
+        }
+    }
+
+    /// Scroll by `delta` the way the wheel does in the current mode
+    pub fn scroll_by(&mut self, delta: Vector2) {
+        match self.scroll_mode {
+            ScrollMode::Instant => self.set_scroll(Vector2::new(self.scroll.x + delta.x, self.scroll.y + delta.y)),
+            ScrollMode::Smooth => self.target = self.clamp_offset(Vector2::new(self.target.x + delta.x, self.target.y + delta.y)),
+            // Velocity decays as e^(-friction * t), so this glides roughly `delta` in total
+            ScrollMode::Kinetic => {
+                self.velocity.x += delta.x * self.friction;
+                self.velocity.y += delta.y * self.friction;
+            }
+        }
+    }
+
+    /// Advance easing or momentum by `dt` seconds
+    pub fn step(&mut self, dt: f32) {
+        match self.scroll_mode {
+            ScrollMode::Instant => {}
+            ScrollMode::Smooth => {
+                let t = (dt * 12.0).min(1.0);
+                self.scroll.x += (self.target.x - self.scroll.x) * t;
+                self.scroll.y += (self.target.y - self.scroll.y) * t;
+                if (self.target.x - self.scroll.x).abs() < 0.5 && (self.target.y - self.scroll.y).abs() < 0.5 {
+                    self.scroll = self.target;
+                }
+            }
+            ScrollMode::Kinetic => {
+                let moved = self.clamp_offset(Vector2::new(
+                    self.scroll.x + self.velocity.x * dt,
+                    self.scroll.y + self.velocity.y * dt,
+                ));
+                // Hitting an edge stops momentum on that axis
+                if moved.x != self.scroll.x + self.velocity.x * dt {
+                    self.velocity.x = 0.0;
+                }
+                if moved.y != self.scroll.y + self.velocity.y * dt {
+                    self.velocity.y = 0.0;
+                }
+                self.scroll = moved;
+
+                let decay = (-self.friction * dt).exp();
+                self.velocity.x *= decay;
+                self.velocity.y *= decay;
+                if self.velocity.x.abs() < 1.0 && self.velocity.y.abs() < 1.0 {
+                    self.velocity = Vector2::zero();
+                }
+                self.target = self.scroll;
+            }
+        }
+    }
+
+    /// Scroll the least amount that brings `rect` (in content coordinates) fully into view
+    pub fn ensure_visible(&mut self, rect: Rectangle) {
+        let viewport = self.viewport();
+        let mut offset = if self.scroll_mode == ScrollMode::Smooth { self.target } else { self.scroll };
+        if rect.x < offset.x {
+            offset.x = rect.x;
+        } else if rect.x + rect.width > offset.x + viewport.width {
+            offset.x = rect.x + rect.width - viewport.width;
+        }
+        if rect.y < offset.y {
+            offset.y = rect.y;
+        } else if rect.y + rect.height > offset.y + viewport.height {
+            offset.y = rect.y + rect.height - viewport.height;
+        }
+        self.scroll_to(offset);
+    }
+
+    /// Scroll so a widget positioned with `place` is fully visible
+    pub fn scroll_to_widget(&mut self, widget: &impl Bounded) {
+        let bounds = widget.bounds();
+        let position = self.to_content(Vector2::new(bounds.x, bounds.y));
+        self.ensure_visible(Rectangle::new(position.x, position.y, bounds.width, bounds.height));
+    }
+
+    /// Convert a screen position to content coordinates
+    pub fn to_content(&self, point: Vector2) -> Vector2 {
+        let viewport = self.viewport();
+        Vector2::new(point.x - viewport.x + self.scroll.x, point.y - viewport.y + self.scroll.y)
+    }
+
+    /// Move `widget` to (`x`, `y`) in content coordinates.
+    /// Call it every frame before updating the widget so it follows the scroll offset.
+    pub fn place(&self, widget: &mut impl Bounded, x: f32, y: f32) {
+        let viewport = self.viewport();
+        widget.set_position(viewport.x + x - self.scroll.x, viewport.y + y - self.scroll.y);
+    }
+
+    /// The mouse position children should see: the real one only over the visible viewport
+    pub fn child_mouse(&self, mouse: Vector2) -> Vector2 {
+        if self.dragging.is_none() && self.viewport().check_collision_point_rec(mouse) {
+            mouse
+        } else {
+            NO_MOUSE
+        }
+    }
+
+    pub fn scrollbar_track(&self, axis: ScrollAxis) -> Option<Rectangle> {
+        let (vertical, horizontal) = self.scrollbars_shown();
+        let viewport = self.viewport();
+        match axis {
+            ScrollAxis::Vertical if vertical => Some(Rectangle::new(
+                viewport.x + viewport.width,
+                viewport.y,
+                SCROLLBAR_WIDTH,
+                viewport.height,
+            )),
+            ScrollAxis::Horizontal if horizontal => Some(Rectangle::new(
+                viewport.x,
+                viewport.y + viewport.height,
+                viewport.width,
+                SCROLLBAR_WIDTH,
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn scrollbar_thumb(&self, axis: ScrollAxis) -> Option<Rectangle> {
+        let track = self.scrollbar_track(axis)?;
+        let viewport = self.viewport();
+        Some(match axis {
+            ScrollAxis::Vertical => scrollbar_thumb(axis, track, viewport.height, self.content_size.y, self.scroll.y),
+            ScrollAxis::Horizontal => scrollbar_thumb(axis, track, viewport.width, self.content_size.x, self.scroll.x),
+        })
+    }
+
+    /// Start dragging a scroll bar if `mouse` is on one; clicking the track jumps the thumb there
+    pub fn press(&mut self, mouse: Vector2) -> bool {
+        for axis in [ScrollAxis::Vertical, ScrollAxis::Horizontal] {
+            let (Some(track), Some(thumb)) = (self.scrollbar_track(axis), self.scrollbar_thumb(axis)) else {
+                continue;
+            };
+            if !track.check_collision_point_rec(mouse) {
+                continue;
+            }
+            self.drag_grab = start_scroll_drag(axis, thumb, mouse);
+            self.dragging = Some(axis);
+            self.drag_to(mouse);
+            return true;
+        }
+        false
+    }
+
+    pub fn drag_to(&mut self, mouse: Vector2) {
+        let Some(axis) = self.dragging else {
+            return;
+        };
+        let (Some(track), Some(thumb)) = (self.scrollbar_track(axis), self.scrollbar_thumb(axis)) else {
+            return;
+        };
+        let max = self.max_scroll();
+        let mut offset = self.scroll;
+        match axis {
+            ScrollAxis::Vertical => offset.y = drag_scroll(axis, track, thumb, self.drag_grab, mouse, max.y),
+            ScrollAxis::Horizontal => offset.x = drag_scroll(axis, track, thumb, self.drag_grab, mouse, max.x),
+        }
+        self.set_scroll(offset);
+    }
+
+    pub fn release(&mut self) {
+        self.dragging = None;
+    }
+
+    /// Draw the background, the clipped contents and the scroll bars.
+    /// `contents` draws children already positioned with `place`.
+    pub fn draw<D: RaylibDraw>(&self, d: &mut D, contents: impl FnOnce(&mut RaylibScissorMode<'_, D>)) {
+        d.draw_rectangle_rec(self.bounds, self.style.background_color);
+
+        let viewport = self.viewport();
+        {
+            let mut clipped = d.begin_scissor_mode(
+                viewport.x as i32,
+                viewport.y as i32,
+                viewport.width as i32,
+                viewport.height as i32,
+            );
+            contents(&mut clipped);
+        }
+
+        for axis in [ScrollAxis::Vertical, ScrollAxis::Horizontal] {
+            let (Some(track), Some(thumb)) = (self.scrollbar_track(axis), self.scrollbar_thumb(axis)) else {
+                continue;
+            };
+            draw_scrollbar(d, &self.style, track, thumb, self.dragging == Some(axis));
+        }
+
+        d.draw_rectangle_lines_ex(self.bounds, self.style.border_thickness, self.style.border_color);
+    }
+}
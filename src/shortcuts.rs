@@ -0,0 +1,537 @@
+use raylib::prelude::*;
+use std::fmt;
+
+/// A key plus the modifiers that must be held with it, e.g. Ctrl+Shift+S
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub key: KeyboardKey,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl KeyChord {
+    pub fn new(key: KeyboardKey) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    pub fn with_ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    pub fn with_shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    pub fn with_alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Parse text like "Ctrl+S", "Ctrl+Shift+Z", "Alt+F4" or "Delete"
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts: Vec<&str> = text.split('+').map(str::trim).collect();
+        // "Ctrl++" names the plus key itself
+        if text.ends_with("++") {
+            parts.pop();
+            parts.pop();
+            parts.push("+");
+        }
+        let (key_name, modifiers) = parts.split_last()?;
+
+        let mut chord = Self::new(key_from_name(key_name)?);
+        for modifier in modifiers {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" | "cmd" => chord.ctrl = true,
+                "shift" => chord.shift = true,
+                "alt" | "option" => chord.alt = true,
+                _ => return None,
+            }
+        }
+        Some(chord)
+    }
+
+    /// Whether the modifiers held right now are exactly this chord's. Ctrl+V does not match
+    /// while Shift is also held; bind Ctrl+Shift+V as well to accept both
+    pub fn modifiers_match(&self, rl: &RaylibHandle) -> bool {
+        let held = Self::held(self.key, rl);
+        held.ctrl == self.ctrl && held.shift == self.shift && held.alt == self.alt
+    }
+
+    /// `key` with the modifiers held right now
+    pub fn held(key: KeyboardKey, rl: &RaylibHandle) -> Self {
+        Self {
+            key,
+            ctrl: rl.is_key_down(KeyboardKey::KEY_LEFT_CONTROL) || rl.is_key_down(KeyboardKey::KEY_RIGHT_CONTROL),
+            shift: rl.is_key_down(KeyboardKey::KEY_LEFT_SHIFT) || rl.is_key_down(KeyboardKey::KEY_RIGHT_SHIFT),
+            alt: rl.is_key_down(KeyboardKey::KEY_LEFT_ALT) || rl.is_key_down(KeyboardKey::KEY_RIGHT_ALT),
+        }
+    }
+
+    /// The key went down this frame with exactly these modifiers held
+    pub fn is_pressed(&self, rl: &RaylibHandle) -> bool {
+        rl.is_key_pressed(self.key) && self.modifiers_match(rl)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+        if self.shift {
+            write!(f, "Shift+")?;
+        }
+        if self.alt {
+            write!(f, "Alt+")?;
+        }
+        write!(f, "{}", key_name(self.key))
+    }
+}
+
+const NAMED_KEYS: &[(&str, KeyboardKey)] = &[
+    ("Enter", KeyboardKey::KEY_ENTER),
+    ("Escape", KeyboardKey::KEY_ESCAPE),
+    ("Tab", KeyboardKey::KEY_TAB),
+    ("Space", KeyboardKey::KEY_SPACE),
+    ("Backspace", KeyboardKey::KEY_BACKSPACE),
+    ("Delete", KeyboardKey::KEY_DELETE),
+    ("Insert", KeyboardKey::KEY_INSERT),
+    ("Home", KeyboardKey::KEY_HOME),
+    ("End", KeyboardKey::KEY_END),
+    ("PageUp", KeyboardKey::KEY_PAGE_UP),
+    ("PageDown", KeyboardKey::KEY_PAGE_DOWN),
+    ("Up", KeyboardKey::KEY_UP),
+    ("Down", KeyboardKey::KEY_DOWN),
+    ("Left", KeyboardKey::KEY_LEFT),
+    ("Right", KeyboardKey::KEY_RIGHT),
+    ("F1", KeyboardKey::KEY_F1),
+    ("F2", KeyboardKey::KEY_F2),
+    ("F3", KeyboardKey::KEY_F3),
+    ("F4", KeyboardKey::KEY_F4),
+    ("F5", KeyboardKey::KEY_F5),
+    ("F6", KeyboardKey::KEY_F6),
+    ("F7", KeyboardKey::KEY_F7),
+    ("F8", KeyboardKey::KEY_F8),
+    ("F9", KeyboardKey::KEY_F9),
+    ("F10", KeyboardKey::KEY_F10),
+    ("F11", KeyboardKey::KEY_F11),
+    ("F12", KeyboardKey::KEY_F12),
+    ("-", KeyboardKey::KEY_MINUS),
+    ("=", KeyboardKey::KEY_EQUAL),
+    ("+", KeyboardKey::KEY_EQUAL),
+    (",", KeyboardKey::KEY_COMMA),
+    (".", KeyboardKey::KEY_PERIOD),
+    ("/", KeyboardKey::KEY_SLASH),
+    (";", KeyboardKey::KEY_SEMICOLON),
+    ("'", KeyboardKey::KEY_APOSTROPHE),
+    ("[", KeyboardKey::KEY_LEFT_BRACKET),
+    ("]", KeyboardKey::KEY_RIGHT_BRACKET),
+    ("\\", KeyboardKey::KEY_BACKSLASH),
+    ("`", KeyboardKey::KEY_GRAVE),
+];
+
+/// Highest raylib key code, KEY_KB_MENU
+const MAX_KEY_CODE: i32 = 348;
+
+const MODIFIER_KEYS: &[KeyboardKey] = &[
+    KeyboardKey::KEY_LEFT_CONTROL,
+    KeyboardKey::KEY_RIGHT_CONTROL,
+    KeyboardKey::KEY_LEFT_SHIFT,
+    KeyboardKey::KEY_RIGHT_SHIFT,
+    KeyboardKey::KEY_LEFT_ALT,
+    KeyboardKey::KEY_RIGHT_ALT,
+    KeyboardKey::KEY_LEFT_SUPER,
+    KeyboardKey::KEY_RIGHT_SUPER,
+];
+
+/// Look up a key by the name used in shortcut text: a letter, a digit, or a name like "F5" or "PageUp"
+pub fn key_from_name(name: &str) -> Option<KeyboardKey> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        // Letter and digit key codes are their ASCII codes
+        if c.is_ascii_alphanumeric() {
+            return key_from_i32(c.to_ascii_uppercase() as i32);
+        }
+    }
+    match name.to_ascii_lowercase().as_str() {
+        "esc" => Some(KeyboardKey::KEY_ESCAPE),
+        "del" => Some(KeyboardKey::KEY_DELETE),
+        "return" => Some(KeyboardKey::KEY_ENTER),
+        lower => NAMED_KEYS
+            .iter()
+            .find(|(key_name, _)| key_name.to_ascii_lowercase() == lower)
+            .map(|(_, key)| *key),
+    }
+}
+
+/// The display name of `key`, the inverse of `key_from_name`
+pub fn key_name(key: KeyboardKey) -> String {
+    let code = key as i32;
+    if (b'A' as i32..=b'Z' as i32).contains(&code) || (b'0' as i32..=b'9' as i32).contains(&code) {
+        return (code as u8 as char).to_string();
+    }
+    NAMED_KEYS
+        .iter()
+        .find(|(_, named)| *named == key)
+        .map_or_else(|| format!("{:?}", key), |(name, _)| name.to_string())
+}
+
+/// Parse a space-separated sequence of chords, e.g. "Ctrl+K Ctrl+S"
+pub fn parse_sequence(text: &str) -> Option<Vec<KeyChord>> {
+    let sequence = text
+        .split_whitespace()
+        .map(KeyChord::parse)
+        .collect::<Option<Vec<_>>>()?;
+    (!sequence.is_empty()).then_some(sequence)
+}
+
+/// Display text for a chord sequence, the inverse of `parse_sequence`
+pub fn sequence_text(sequence: &[KeyChord]) -> String {
+    sequence.iter().map(KeyChord::to_string).collect::<Vec<_>>().join(" ")
+}
+
+/// When a binding is active
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShortcutScope {
+    /// Whenever no modal is open
+    Global,
+    /// Only while the widget with this name has focus and no modal is open
+    Focused(String),
+    /// Only while a modal is open
+    Modal,
+}
+
+impl ShortcutScope {
+    /// More specific scopes win ties in priority
+    fn rank(&self) -> u8 {
+        match self {
+            ShortcutScope::Global => 0,
+            ShortcutScope::Focused(_) => 1,
+            ShortcutScope::Modal => 2,
+        }
+    }
+}
+
+/// A chord sequence bound to an action in a scope
+#[derive(Clone, Debug, PartialEq)]
+pub struct Binding {
+    pub sequence: Vec<KeyChord>,
+    pub action: String,
+    pub scope: ShortcutScope,
+    /// Higher priority wins when several active bindings share a sequence
+    pub priority: i32,
+}
+
+/// A line of a keymap file that couldn't be read
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeymapError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "keymap line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for KeymapError {}
+
+/// The keymap `Shortcuts::defaults` loads
+pub const DEFAULT_KEYMAP: &str = include_str!("../assets/default.keymap");
+
+/// Maps key chords and chord sequences to named actions
+#[derive(Clone, Debug)]
+pub struct Shortcuts {
+    pub bindings: Vec<Binding>,
+    /// Name of the focused widget, for `ShortcutScope::Focused` bindings
+    pub focused: Option<String>,
+    /// While set, only `ShortcutScope::Modal` bindings are active
+    pub modal_open: bool,
+    /// Seconds to wait for the next chord of a sequence
+    pub sequence_timeout: f32,
+    /// Chords typed so far of an unfinished sequence
+    pub pending: Vec<KeyChord>,
+    /// The binding completed by the last `poll` or `feed`, for widgets sharing this registry
+    pub fired: Option<Binding>,
+    pending_time: f32,
+}
+
+impl Default for Shortcuts {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            focused: None,
+            modal_open: false,
+            sequence_timeout: 1.5,
+            pending: Vec::new(),
+            fired: None,
+            pending_time: 0.0,
+        }
+    }
+}
+
+impl Shortcuts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The built-in keymap, including the text field editing bindings
+    pub fn defaults() -> Self {
+        Self::from_keymap(DEFAULT_KEYMAP).expect("built-in keymap is valid")
+    }
+
+    /// Parse a keymap. Each line is `sequence = action [priority]`, and `[global]`,
+    /// `[modal]` or `[focused:name]` headers set the scope of the lines after them.
+    /// `#` starts a comment.
+    pub fn from_keymap(text: &str) -> Result<Self, KeymapError> {
+        let mut shortcuts = Self::new();
+        let mut scope = ShortcutScope::Global;
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: &str| KeymapError {
+                line: index + 1,
+                message: message.to_string(),
+            };
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+                scope = match header.trim() {
+                    "global" => ShortcutScope::Global,
+                    "modal" => ShortcutScope::Modal,
+                    other => match other.strip_prefix("focused:") {
+                        Some(name) if !name.trim().is_empty() => ShortcutScope::Focused(name.trim().to_string()),
+                        _ => return Err(error(&format!("unknown scope \"{}\"", other))),
+                    },
+                };
+                continue;
+            }
+
+            let Some((keys, binding)) = line.split_once('=') else {
+                return Err(error("expected `keys = action`"));
+            };
+            let sequence = parse_sequence(keys).ok_or_else(|| error(&format!("can't parse keys \"{}\"", keys.trim())))?;
+            let mut parts = binding.split_whitespace();
+            let action = parts.next().ok_or_else(|| error("missing action"))?;
+            let priority = match parts.next() {
+                Some(priority) => priority
+                    .parse()
+                    .map_err(|_| error(&format!("priority \"{}\" is not a number", priority)))?,
+                None => 0,
+            };
+            if parts.next().is_some() {
+                return Err(error("unexpected text after the priority"));
+            }
+            shortcuts.bind_sequence(sequence, action, scope.clone(), priority);
+        }
+        Ok(shortcuts)
+    }
+
+    /// Read a keymap file; see `from_keymap` for the format
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(Self::from_keymap(&text)?)
+    }
+
+    /// Add the bindings of another keymap, e.g. user overrides loaded over `defaults()`
+    pub fn extend(&mut self, other: Shortcuts) {
+        for binding in other.bindings {
+            self.remove_binding(&binding.sequence, &binding.scope);
+            self.bindings.push(binding);
+        }
+    }
+
+    /// Bind a global chord or sequence like "Ctrl+K Ctrl+S" to `action`;
+    /// returns false if the text couldn't be parsed
+    pub fn bind(&mut self, keys: &str, action: &str) -> bool {
+        self.bind_in(keys, action, ShortcutScope::Global, 0)
+    }
+
+    /// Bind `keys` to `action` in `scope` with `priority`
+    pub fn bind_in(&mut self, keys: &str, action: &str, scope: ShortcutScope, priority: i32) -> bool {
+        match parse_sequence(keys) {
+            Some(sequence) => {
+                self.bind_sequence(sequence, action, scope, priority);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn bind_chord(&mut self, chord: KeyChord, action: &str) {
+        self.bind_sequence(vec![chord], action, ShortcutScope::Global, 0);
+    }
+
+    pub fn bind_sequence(&mut self, sequence: Vec<KeyChord>, action: &str, scope: ShortcutScope, priority: i32) {
+        self.bindings.push(Binding {
+            sequence,
+            action: action.to_string(),
+            scope,
+            priority,
+        });
+    }
+
+    /// Move `action` to new keys in the same scope; returns false if it isn't bound
+    /// or the keys couldn't be parsed
+    pub fn rebind(&mut self, action: &str, keys: &str) -> bool {
+        let Some(sequence) = parse_sequence(keys) else {
+            return false;
+        };
+        let Some(binding) = self.bindings.iter().find(|binding| binding.action == action) else {
+            return false;
+        };
+        let (scope, priority) = (binding.scope.clone(), binding.priority);
+        self.unbind(action);
+        self.bind_sequence(sequence, action, scope, priority);
+        true
+    }
+
+    /// Remove every binding for `action`
+    pub fn unbind(&mut self, action: &str) {
+        self.bindings.retain(|binding| binding.action != action);
+    }
+
+    fn remove_binding(&mut self, sequence: &[KeyChord], scope: &ShortcutScope) {
+        self.bindings
+            .retain(|binding| binding.sequence != sequence || binding.scope != *scope);
+    }
+
+    /// The first chord bound to `action`
+    pub fn chord_for(&self, action: &str) -> Option<KeyChord> {
+        self.sequence_for(action).and_then(|sequence| sequence.first().copied())
+    }
+
+    /// The keys bound to `action`, e.g. to show beside a menu item
+    pub fn sequence_for(&self, action: &str) -> Option<&[KeyChord]> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.action == action)
+            .map(|binding| binding.sequence.as_slice())
+    }
+
+    /// Actions bound to the single `chord`, in any scope
+    pub fn actions_for(&self, chord: KeyChord) -> Vec<&str> {
+        self.bindings
+            .iter()
+            .filter(|binding| binding.sequence == [chord])
+            .map(|binding| binding.action.as_str())
+            .collect()
+    }
+
+    /// Whether `scope` is active given the focused widget and modal state
+    pub fn is_active(&self, scope: &ShortcutScope) -> bool {
+        match scope {
+            ShortcutScope::Modal => self.modal_open,
+            _ if self.modal_open => false,
+            ShortcutScope::Global => true,
+            ShortcutScope::Focused(name) => self.focused.as_deref() == Some(name.as_str()),
+        }
+    }
+
+    /// Bindings for `sequence` that are active right now, best first
+    pub fn resolve(&self, sequence: &[KeyChord]) -> Vec<&Binding> {
+        let mut matches: Vec<&Binding> = self
+            .bindings
+            .iter()
+            .filter(|binding| binding.sequence == sequence && self.is_active(&binding.scope))
+            .collect();
+        // Stable sort, so among equal priority and scope the most recent binding wins
+        matches.reverse();
+        matches.sort_by_key(|binding| std::cmp::Reverse((binding.priority, binding.scope.rank())));
+        matches
+    }
+
+    /// Feed one pressed chord; returns the action once a binding's whole sequence is typed.
+    /// A chord that completes a binding fires right away, even if it also starts a longer one.
+    pub fn feed(&mut self, chord: KeyChord) -> Option<String> {
+        let mut typed = self.pending.clone();
+        typed.push(chord);
+        self.pending_time = 0.0;
+        self.fired = None;
+
+        if let Some(best) = self.resolve(&typed).first().map(|best| (*best).clone()) {
+            self.pending.clear();
+            let action = best.action.clone();
+            self.fired = Some(best);
+            return Some(action);
+        }
+
+        let continues = self.bindings.iter().any(|binding| {
+            binding.sequence.len() > typed.len() && binding.sequence.starts_with(&typed) && self.is_active(&binding.scope)
+        });
+        if continues {
+            self.pending = typed;
+            return None;
+        }
+
+        // A wrong key abandons the sequence, but may start a new one
+        let restart = !self.pending.is_empty();
+        self.pending.clear();
+        if restart {
+            self.feed(chord)
+        } else {
+            None
+        }
+    }
+
+    /// Forget a half-typed sequence once `sequence_timeout` passes without a key
+    pub fn tick(&mut self, dt: f32) {
+        if self.pending.is_empty() {
+            return;
+        }
+        self.pending_time += dt;
+        if self.pending_time >= self.sequence_timeout {
+            self.pending.clear();
+            self.pending_time = 0.0;
+        }
+    }
+
+    /// The action whose keys were completed this frame, if any
+    pub fn poll(&mut self, rl: &RaylibHandle) -> Option<String> {
+        self.tick(rl.get_frame_time());
+        self.fired = None;
+        let pressed = self.pressed_chord(|key| rl.is_key_pressed(key), |key| KeyChord::held(key, rl))?;
+        self.feed(pressed)
+    }
+
+    /// The action completed by the last `poll` or `feed`, if its binding is in `scope`
+    pub fn fired_in(&self, scope: &ShortcutScope) -> Option<&str> {
+        self.fired
+            .as_ref()
+            .filter(|binding| binding.scope == *scope)
+            .map(|binding| binding.action.as_str())
+    }
+
+    /// The chord to feed this frame: a bound chord that went down, or while a sequence is pending
+    /// any other key, so that a wrong key abandons the sequence
+    pub(crate) fn pressed_chord(
+        &self,
+        key_pressed: impl Fn(KeyboardKey) -> bool,
+        held: impl Fn(KeyboardKey) -> KeyChord,
+    ) -> Option<KeyChord> {
+        let bound = self
+            .bindings
+            .iter()
+            .filter(|binding| self.is_active(&binding.scope))
+            .flat_map(|binding| binding.sequence.iter())
+            .find(|chord| key_pressed(chord.key) && held(chord.key) == **chord)
+            .copied();
+        if bound.is_some() || self.pending.is_empty() {
+            return bound;
+        }
+        (0..=MAX_KEY_CODE)
+            .filter_map(key_from_i32)
+            .find(|&key| key != KeyboardKey::KEY_NULL && !MODIFIER_KEYS.contains(&key) && key_pressed(key))
+            .map(held)
+    }
+}
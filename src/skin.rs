@@ -0,0 +1,172 @@
+use raylib::prelude::*;
+use std::rc::Rc;
+
+/// Widget states a skin can have a texture for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkinState {
+    Normal,
+    Hover,
+    Pressed,
+    Disabled,
+    /// Text fields being typed in and dropdowns that are open
+    Focused,
+}
+
+impl SkinState {
+    /// Pick the state to draw; disabled wins, then pressed, focused and hovered
+    pub fn from_flags(enabled: bool, pressed: bool, focused: bool, hovered: bool) -> Self {
+        if !enabled {
+            SkinState::Disabled
+        } else if pressed {
+            SkinState::Pressed
+        } else if focused {
+            SkinState::Focused
+        } else if hovered {
+            SkinState::Hover
+        } else {
+            SkinState::Normal
+        }
+    }
+}
+
+/// A texture whose corners keep their size while its edges and center stretch to fill the bounds
+#[derive(Clone, Debug)]
+pub struct NineSlice {
+    pub texture: Rc<Texture2D>,
+    /// Region of the texture to use; the whole texture when `None`
+    pub source: Option<Rectangle>,
+    /// Border widths in texture pixels
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl NineSlice {
+    pub fn new(texture: Rc<Texture2D>, left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        Self {
+            texture,
+            source: None,
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Same border width on every side
+    pub fn uniform(texture: Rc<Texture2D>, border: i32) -> Self {
+        Self::new(texture, border, border, border, border)
+    }
+
+    /// Use one region of a sprite atlas
+    pub fn with_source(mut self, source: Rectangle) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn source_rect(&self) -> Rectangle {
+        self.source
+            .unwrap_or_else(|| Rectangle::new(0.0, 0.0, self.texture.width as f32, self.texture.height as f32))
+    }
+
+    pub fn patch_info(&self) -> NPatchInfo {
+        NPatchInfo {
+            source: self.source_rect(),
+            left: self.left,
+            top: self.top,
+            right: self.right,
+            bottom: self.bottom,
+            layout: NPatchLayout::NPATCH_NINE_PATCH,
+        }
+    }
+
+    /// Stretch over `bounds`; raylib shrinks the borders when the bounds are smaller than them
+    pub fn draw(&self, d: &mut impl RaylibDraw, bounds: Rectangle, tint: Color) {
+        d.draw_texture_n_patch(self.texture.as_ref(), self.patch_info(), bounds, Vector2::zero(), 0.0, tint);
+    }
+}
+
+/// Tint for the normal texture when a disabled widget has no texture of its own
+pub const SKIN_DISABLED_TINT: Color = Color::new(255, 255, 255, 128);
+
+/// Which state's texture to draw for `state`, and its tint, given the states that have one.
+/// Missing states fall back to the normal texture, faded when disabled.
+pub(crate) fn resolve_state(state: SkinState, has_texture: impl Fn(SkinState) -> bool) -> Option<(SkinState, Color)> {
+    if has_texture(state) {
+        Some((state, Color::WHITE))
+    } else if !has_texture(SkinState::Normal) {
+        None
+    } else if state == SkinState::Disabled {
+        Some((SkinState::Normal, SKIN_DISABLED_TINT))
+    } else {
+        Some((SkinState::Normal, Color::WHITE))
+    }
+}
+
+/// Nine-slice textures per state, drawn in place of a widget's background and border
+#[derive(Clone, Debug, Default)]
+pub struct Skin {
+    pub normal: Option<NineSlice>,
+    pub hover: Option<NineSlice>,
+    pub pressed: Option<NineSlice>,
+    pub disabled: Option<NineSlice>,
+    pub focused: Option<NineSlice>,
+}
+
+impl Skin {
+    pub fn new(normal: NineSlice) -> Self {
+        Self {
+            normal: Some(normal),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_hover(mut self, slice: NineSlice) -> Self {
+        self.hover = Some(slice);
+        self
+    }
+
+    pub fn with_pressed(mut self, slice: NineSlice) -> Self {
+        self.pressed = Some(slice);
+        self
+    }
+
+    pub fn with_disabled(mut self, slice: NineSlice) -> Self {
+        self.disabled = Some(slice);
+        self
+    }
+
+    pub fn with_focused(mut self, slice: NineSlice) -> Self {
+        self.focused = Some(slice);
+        self
+    }
+
+    /// The texture set for `state` itself, without falling back
+    pub fn slice(&self, state: SkinState) -> Option<&NineSlice> {
+        match state {
+            SkinState::Normal => self.normal.as_ref(),
+            SkinState::Hover => self.hover.as_ref(),
+            SkinState::Pressed => self.pressed.as_ref(),
+            SkinState::Disabled => self.disabled.as_ref(),
+            SkinState::Focused => self.focused.as_ref(),
+        }
+    }
+
+    /// The texture for `state` and its tint, falling back to the normal texture (faded when disabled)
+    pub fn texture_for(&self, state: SkinState) -> Option<(&NineSlice, Color)> {
+        let (state, tint) = resolve_state(state, |state| self.slice(state).is_some())?;
+        self.slice(state).map(|slice| (slice, tint))
+    }
+
+    /// Draw the texture for `state`; returns false when there is none, so the caller draws procedurally
+    pub fn draw(&self, d: &mut impl RaylibDraw, bounds: Rectangle, state: SkinState) -> bool {
+        match self.texture_for(state) {
+            Some((slice, tint)) => {
+                slice.draw(d, bounds, tint);
+                true
+            }
+            None => false,
+        }
+    }
+}
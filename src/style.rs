@@ -1,4 +1,6 @@
+use crate::skin::{Skin, SkinState};
 use raylib::prelude::*;
+use std::rc::Rc;
 
 /// Universal style struct for all UI components
 #[derive(Clone, Debug)]
@@ -25,14 +27,27 @@ pub struct Style {
     // Special colors for specific components
     pub check_color: Color,        // For checkboxes
     pub placeholder_color: Color,  // For text fields
+    pub tooltip_background_color: Color,
+    pub tooltip_border_color: Color,
+    pub tooltip_text_color: Color,
+    pub icon_tint: Color,          // Button icons, multiplied with the texture
+    pub icon_tint_hover: Color,
+    pub icon_tint_pressed: Color,
+    pub icon_tint_disabled: Color,
     
     // Typography
     pub font_size: i32,
+    pub tooltip_font_size: i32,
+    pub font: Option<Rc<Font>>,      // Text font for labels; None uses raylib's default font
+    pub bold_font: Option<Rc<Font>>, // Bold variant for rich text; None draws a faux bold
     
     // Layout
     pub padding: f32,
     pub corner_radius: f32,
     pub border_thickness: f32,
+    
+    // Textures
+    pub skin: Option<Skin>,          // Nine-slice textures drawn instead of the background and border
 }
 
 impl Default for Style {
@@ -60,14 +75,25 @@ impl Default for Style {
             // Special colors
             check_color: Color::GREEN,
             placeholder_color: Color::new(128, 128, 128, 255),
+            tooltip_background_color: Color::new(50, 50, 50, 240),
+            tooltip_border_color: Color::new(20, 20, 20, 255),
+            tooltip_text_color: Color::WHITE,
+            icon_tint: Color::WHITE,
+            icon_tint_hover: Color::WHITE,
+            icon_tint_pressed: Color::new(220, 220, 220, 255),
+            icon_tint_disabled: Color::new(255, 255, 255, 128),
             
             // Typography
             font_size: 20,
+            tooltip_font_size: 14,
+            font: None,
+            bold_font: None,
             
             // Layout
             padding: 5.0,
             corner_radius: 5.0,
             border_thickness: 2.0,
+            skin: None,
         }
     }
 }
@@ -97,10 +123,21 @@ impl Style {
             text_color_disabled: Color::new(128, 128, 128, 255),
             check_color: Color::GREEN,
             placeholder_color: Color::new(128, 128, 128, 255),
+            tooltip_background_color: Color::new(50, 50, 50, 240),
+            tooltip_border_color: Color::new(20, 20, 20, 255),
+            tooltip_text_color: Color::WHITE,
+            icon_tint: Color::WHITE,
+            icon_tint_hover: Color::WHITE,
+            icon_tint_pressed: Color::new(220, 220, 220, 255),
+            icon_tint_disabled: Color::new(255, 255, 255, 128),
             font_size: 20,
+            tooltip_font_size: 14,
+            font: None,
+            bold_font: None,
             padding: 5.0,
             corner_radius: 5.0,
             border_thickness: 2.0,
+            skin: None,
         }
     }
 
@@ -122,10 +159,21 @@ impl Style {
             text_color_disabled: Color::new(150, 150, 150, 255),
             check_color: Color::new(100, 150, 255, 255),
             placeholder_color: Color::new(150, 150, 150, 255),
+            tooltip_background_color: Color::new(50, 50, 50, 240),
+            tooltip_border_color: Color::new(20, 20, 20, 255),
+            tooltip_text_color: Color::WHITE,
+            icon_tint: Color::WHITE,
+            icon_tint_hover: Color::WHITE,
+            icon_tint_pressed: Color::new(220, 220, 220, 255),
+            icon_tint_disabled: Color::new(255, 255, 255, 128),
             font_size: 16,
+            tooltip_font_size: 14,
+            font: None,
+            bold_font: None,
             padding: 8.0,
             corner_radius: 6.0,
             border_thickness: 1.5,
+            skin: None,
         }
     }
 
@@ -147,10 +195,21 @@ impl Style {
             text_color_disabled: Color::new(100, 100, 100, 255),
             check_color: Color::new(100, 150, 255, 255),
             placeholder_color: Color::new(100, 100, 100, 255),
+            tooltip_background_color: Color::new(230, 230, 230, 245),
+            tooltip_border_color: Color::new(150, 150, 150, 255),
+            tooltip_text_color: Color::new(30, 30, 30, 255),
+            icon_tint: Color::WHITE,
+            icon_tint_hover: Color::WHITE,
+            icon_tint_pressed: Color::new(220, 220, 220, 255),
+            icon_tint_disabled: Color::new(255, 255, 255, 128),
             font_size: 16,
+            tooltip_font_size: 14,
+            font: None,
+            bold_font: None,
             padding: 8.0,
             corner_radius: 6.0,
             border_thickness: 1.5,
+            skin: None,
         }
     }
 
@@ -172,10 +231,21 @@ impl Style {
             text_color_disabled: Color::new(150, 150, 150, 255),
             check_color: Color::new(100, 150, 255, 255),
             placeholder_color: Color::new(150, 150, 150, 255),
+            tooltip_background_color: Color::new(50, 50, 50, 240),
+            tooltip_border_color: Color::new(20, 20, 20, 255),
+            tooltip_text_color: Color::WHITE,
+            icon_tint: Color::WHITE,
+            icon_tint_hover: Color::WHITE,
+            icon_tint_pressed: Color::new(220, 220, 220, 255),
+            icon_tint_disabled: Color::new(255, 255, 255, 128),
             font_size: 14,
+            tooltip_font_size: 12,
+            font: None,
+            bold_font: None,
             padding: 6.0,
             corner_radius: 4.0,
             border_thickness: 1.0,
+            skin: None,
         }
     }
 
@@ -203,12 +273,36 @@ impl Style {
         self
     }
 
+    /// Builder method to set tooltip colors
+    pub fn with_tooltip_colors(mut self, background: Color, border: Color, text: Color) -> Self {
+        self.tooltip_background_color = background;
+        self.tooltip_border_color = border;
+        self.tooltip_text_color = text;
+        self
+    }
+
+    /// Builder method to set the tints drawn over button icons in each state
+    pub fn with_icon_tints(mut self, normal: Color, hover: Color, pressed: Color, disabled: Color) -> Self {
+        self.icon_tint = normal;
+        self.icon_tint_hover = hover;
+        self.icon_tint_pressed = pressed;
+        self.icon_tint_disabled = disabled;
+        self
+    }
+
     /// Builder method to set typography
     pub fn with_typography(mut self, font_size: i32) -> Self {
         self.font_size = font_size;
         self
     }
 
+    /// Builder method to set the regular and bold fonts used by labels
+    pub fn with_fonts(mut self, font: Option<Rc<Font>>, bold_font: Option<Rc<Font>>) -> Self {
+        self.font = font;
+        self.bold_font = bold_font;
+        self
+    }
+
     /// Builder method to set layout properties
     pub fn with_layout(mut self, padding: f32, corner_radius: f32, border_thickness: f32) -> Self {
         self.padding = padding;
@@ -216,6 +310,17 @@ impl Style {
         self.border_thickness = border_thickness;
         self
     }
+
+    /// Builder method to draw widgets with nine-slice textures
+    pub fn with_skin(mut self, skin: Skin) -> Self {
+        self.skin = Some(skin);
+        self
+    }
+
+    /// Draw the skin's texture for `state` over `bounds`; returns false when the widget should draw itself
+    pub fn draw_skin(&self, d: &mut impl RaylibDraw, bounds: Rectangle, state: SkinState) -> bool {
+        self.skin.as_ref().is_some_and(|skin| skin.draw(d, bounds, state))
+    }
 }
 
 /// Predefined style presets for quick use
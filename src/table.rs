@@ -0,0 +1,898 @@
+use crate::checkbox::Checkbox;
+use crate::cursor::{capture_cursor, request_cursor, CursorShape};
+use crate::dropdown::{
+    drag_scroll, draw_arrow_glyph, draw_check_glyph, draw_scrollbar, scrollbar_cursor, scrollbar_thumb, scrollbar_track,
+    start_scroll_drag, ArrowDirection, Dropdown, SCROLLBAR_WIDTH,
+};
+use crate::scroll::ScrollAxis;
+use crate::style::Style;
+use crate::text::truncate_with_ellipsis;
+use crate::textfield::TextField;
+use raylib::prelude::*;
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// How close to a header's right edge a press starts resizing the column
+const RESIZE_GRIP: f32 = 4.0;
+/// How far a header has to be dragged before it starts moving
+const MOVE_THRESHOLD: f32 = 6.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// Which widget edits a column's cells in place
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellEditor {
+    /// Free text in a TextField
+    Text,
+    /// "true" / "false" toggled through a Checkbox; cells draw as check boxes
+    Check,
+    /// One of the given values, picked from a Dropdown
+    Choice(Vec<String>),
+}
+
+pub struct Column {
+    pub title: String,
+    pub width: f32,
+    pub min_width: f32,
+    pub sortable: bool,
+    /// Cells are read-only without an editor
+    pub editor: Option<CellEditor>,
+}
+
+impl Column {
+    pub fn new(title: &str, width: f32) -> Self {
+        Self {
+            title: title.to_string(),
+            width,
+            min_width: 30.0,
+            sortable: true,
+            editor: None,
+        }
+    }
+
+    pub fn with_min_width(mut self, width: f32) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    pub fn with_editor(mut self, editor: CellEditor) -> Self {
+        self.editor = Some(editor);
+        self
+    }
+}
+
+/// What happened in a table during the last update; rows and columns are data indices
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableEvent {
+    SelectionChanged(Option<usize>),
+    /// A row was double-clicked or Enter was pressed on it, and it has no editable cell
+    RowActivated(usize),
+    Sorted(usize, SortDirection),
+    ColumnResized(usize),
+    /// A column was dragged from one display position to another
+    ColumnMoved { from: usize, to: usize },
+    CellEdited { row: usize, column: usize, value: String },
+}
+
+/// The widget editing a cell
+pub enum EditorWidget {
+    Text(TextField),
+    Check(Checkbox),
+    Choice(Dropdown<String>),
+}
+
+/// A cell being edited in place
+pub struct CellEdit {
+    pub row: usize,
+    pub column: usize,
+    pub widget: EditorWidget,
+}
+
+/// A header drag in progress
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum HeaderDrag {
+    Resize { column: usize, grab_x: f32, start_width: f32 },
+    Move { column: usize, press_x: f32, moving: bool },
+}
+
+/// A grid of text cells with a frozen header; only the visible rows are drawn
+pub struct Table {
+    pub bounds: Rectangle,
+    pub columns: Vec<Column>,
+    /// Cell text, one Vec per row in column order
+    pub rows: Vec<Vec<String>>,
+    /// Display position to column index
+    pub column_order: Vec<usize>,
+    pub style: Style,
+    pub header_height: f32,
+    pub row_height: f32,
+    pub sort: Option<(usize, SortDirection)>,
+    pub selected_row: Option<usize>,
+    pub hover_row: Option<usize>,
+    /// First visible row, counted in display order
+    pub scroll_offset: usize,
+    pub is_focused: bool,
+    pub is_scrolling: bool,
+    pub double_click_time: f64,
+    pub editing: Option<CellEdit>,
+    pub events: Vec<TableEvent>,
+    /// Display position to row index, following the sort
+    order: Vec<usize>,
+    /// Row index to display position, the inverse of `order`
+    positions: Vec<usize>,
+    header_drag: Option<HeaderDrag>,
+    last_click: Option<(usize, f64)>,
+    scroll_grab: f32,
+}
+
+impl Table {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, columns: Vec<Column>) -> Self {
+        Self {
+            bounds: Rectangle::new(x, y, width, height),
+            column_order: (0..columns.len()).collect(),
+            columns,
+            rows: Vec::new(),
+            style: Style::default(),
+            header_height: 28.0,
+            row_height: 24.0,
+            sort: None,
+            selected_row: None,
+            hover_row: None,
+            scroll_offset: 0,
+            is_focused: false,
+            is_scrolling: false,
+            double_click_time: 0.4,
+            editing: None,
+            events: Vec::new(),
+            order: Vec::new(),
+            positions: Vec::new(),
+            header_drag: None,
+            last_click: None,
+            scroll_grab: 0.0,
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_rows(mut self, rows: Vec<Vec<String>>) -> Self {
+        self.set_rows(rows);
+        self
+    }
+
+    pub fn with_row_height(mut self, height: f32) -> Self {
+        self.row_height = height;
+        self
+    }
+
+    pub fn with_header_height(mut self, height: f32) -> Self {
+        self.header_height = height;
+        self
+    }
+
+    /// Replace every row, keeping the current sort and dropping the selection
+    pub fn set_rows(&mut self, rows: Vec<Vec<String>>) {
+        self.rows = rows;
+        self.selected_row = None;
+        self.hover_row = None;
+        self.editing = None;
+        self.scroll_offset = 0;
+        self.refresh_order();
+    }
+
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+        self.refresh_order();
+    }
+
+    pub fn cell(&self, row: usize, column: usize) -> Option<&str> {
+        self.rows.get(row)?.get(column).map(String::as_str)
+    }
+
+    /// Change a cell's text, re-sorting if the table is sorted
+    pub fn set_cell(&mut self, row: usize, column: usize, value: &str) {
+        let Some(cells) = self.rows.get_mut(row) else {
+            return;
+        };
+        if cells.len() <= column {
+            cells.resize(column + 1, String::new());
+        }
+        cells[column] = value.to_string();
+        self.refresh_order();
+    }
+
+    /// Sort by `column`; rows keep their indices, only the display order changes
+    pub fn sort_by(&mut self, column: usize, direction: SortDirection) {
+        if column >= self.columns.len() {
+            return;
+        }
+        self.sort = Some((column, direction));
+        self.refresh_order();
+        self.events.push(TableEvent::Sorted(column, direction));
+    }
+
+    /// Sort ascending by `column`, or flip the direction if it is already the sort column
+    pub fn toggle_sort(&mut self, column: usize) {
+        let direction = match self.sort {
+            Some((sorted, SortDirection::Ascending)) if sorted == column => SortDirection::Descending,
+            _ => SortDirection::Ascending,
+        };
+        self.sort_by(column, direction);
+    }
+
+    pub fn clear_sort(&mut self) {
+        self.sort = None;
+        self.refresh_order();
+    }
+
+    fn refresh_order(&mut self) {
+        self.order = (0..self.rows.len()).collect();
+        if let Some((column, direction)) = self.sort {
+            let rows = &self.rows;
+            self.order.sort_by(|&a, &b| {
+                let a = rows[a].get(column).map_or("", String::as_str);
+                let b = rows[b].get(column).map_or("", String::as_str);
+                let ordering = compare_cells(a, b);
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+        self.positions = vec![0; self.order.len()];
+        for (position, &row) in self.order.iter().enumerate() {
+            self.positions[row] = position;
+        }
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll());
+    }
+
+    /// Row indices in display order
+    pub fn display_order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Display position of `row`
+    pub fn display_index(&self, row: usize) -> Option<usize> {
+        self.positions.get(row).copied()
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &mut RaylibHandle) {
+        self.events.clear();
+
+        // The key or click that ends an edit doesn't also act on the table
+        if self.editing.is_some() {
+            self.update_editor(mouse, rl);
+            return;
+        }
+
+        let hovered = self.bounds.check_collision_point_rec(mouse);
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.is_focused = hovered;
+            if self.scrollbar_track().is_some_and(|track| track.check_collision_point_rec(mouse)) {
+                self.start_scroll_drag(mouse);
+            } else if !self.press_header(mouse) {
+                if let Some(row) = self.row_at(mouse) {
+                    let column = self.column_at(mouse.x);
+                    self.click_cell(row, column, rl.get_time());
+                }
+            }
+        }
+
+        if rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT) {
+            if self.is_scrolling {
+                self.drag_scroll(mouse);
+            }
+            self.drag_header(mouse);
+        }
+        if rl.is_mouse_button_released(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.is_scrolling = false;
+            self.release_header(mouse);
+        }
+
+        if matches!(self.header_drag, Some(HeaderDrag::Resize { .. })) {
+            capture_cursor(CursorShape::ResizeEW);
+        } else if self.header_drag.is_none() && self.resize_handle_at(mouse).is_some() {
+            request_cursor(CursorShape::ResizeEW);
+        }
+        if let Some(track) = self.scrollbar_track() {
+            scrollbar_cursor(ScrollAxis::Vertical, self.scrollbar_thumb(track), mouse, self.is_scrolling);
+        }
+
+        if hovered {
+            let wheel_move = rl.get_mouse_wheel_move() as i32;
+            if wheel_move != 0 {
+                self.scroll_offset = (self.scroll_offset as i32 - wheel_move).clamp(0, self.max_scroll() as i32) as usize;
+            }
+        }
+        self.hover_row = if self.is_scrolling || self.header_drag.is_some() {
+            None
+        } else {
+            self.row_at(mouse)
+        };
+        if self.hover_row.is_some() {
+            request_cursor(CursorShape::PointingHand);
+        }
+
+        if self.is_focused && self.editing.is_none() {
+            self.handle_keyboard(rl);
+        }
+    }
+
+    /// Up/Down, Page Up/Down and Home/End move the selection; Enter or F2 edits the first
+    /// editable cell of the selected row, or activates the row if none is editable
+    pub fn handle_keyboard(&mut self, rl: &RaylibHandle) {
+        let key_down = |key| rl.is_key_pressed(key) || rl.is_key_pressed_repeat(key);
+        let page = self.visible_row_count().max(1) as i32;
+
+        if key_down(KeyboardKey::KEY_DOWN) {
+            self.move_selection(1);
+        } else if key_down(KeyboardKey::KEY_UP) {
+            self.move_selection(-1);
+        } else if key_down(KeyboardKey::KEY_PAGE_DOWN) {
+            self.move_selection(page);
+        } else if key_down(KeyboardKey::KEY_PAGE_UP) {
+            self.move_selection(-page);
+        } else if rl.is_key_pressed(KeyboardKey::KEY_HOME) {
+            self.move_selection(-(self.rows.len() as i32));
+        } else if rl.is_key_pressed(KeyboardKey::KEY_END) {
+            self.move_selection(self.rows.len() as i32);
+        }
+
+        if rl.is_key_pressed(KeyboardKey::KEY_ENTER) || rl.is_key_pressed(KeyboardKey::KEY_F2) {
+            if let Some(row) = self.selected_row {
+                let editable = self
+                    .column_order
+                    .iter()
+                    .copied()
+                    .find(|&column| self.columns[column].editor.is_some());
+                match editable {
+                    Some(column) => {
+                        self.begin_edit(row, column);
+                    }
+                    None => self.events.push(TableEvent::RowActivated(row)),
+                }
+            }
+        }
+    }
+
+    /// Select as a click would; a second click on the same row within `double_click_time`
+    /// edits the clicked cell or activates the row. A click on an editable check cell toggles it.
+    pub fn click_cell(&mut self, row: usize, column: Option<usize>, time: f64) {
+        if row >= self.rows.len() {
+            return;
+        }
+        self.select_row(Some(row));
+
+        let editor = column.and_then(|column| self.columns[column].editor.as_ref());
+        if let (Some(column), Some(CellEditor::Check)) = (column, editor) {
+            self.begin_edit(row, column);
+            self.last_click = None;
+            return;
+        }
+
+        let double_click = self
+            .last_click
+            .is_some_and(|(last, at)| last == row && time - at <= self.double_click_time);
+        if !double_click {
+            self.last_click = Some((row, time));
+            return;
+        }
+        self.last_click = None;
+        match column {
+            Some(column) if editor.is_some() => {
+                self.begin_edit(row, column);
+            }
+            _ => self.events.push(TableEvent::RowActivated(row)),
+        }
+    }
+
+    pub fn select_row(&mut self, row: Option<usize>) {
+        let row = row.filter(|&row| row < self.rows.len());
+        if row != self.selected_row {
+            self.selected_row = row;
+            self.events.push(TableEvent::SelectionChanged(row));
+        }
+        if let Some(position) = row.and_then(|row| self.display_index(row)) {
+            self.scroll_to(position);
+        }
+    }
+
+    /// Move the selection `delta` rows in display order
+    pub fn move_selection(&mut self, delta: i32) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let position = match self.selected_row.and_then(|row| self.display_index(row)) {
+            Some(current) => (current as i32 + delta).clamp(0, self.order.len() as i32 - 1) as usize,
+            None if delta < 0 => self.order.len() - 1,
+            None => 0,
+        };
+        self.select_row(Some(self.order[position]));
+    }
+
+    /// Open the column's editor over the cell; returns false if the column has none.
+    /// Check cells are toggled and committed right away.
+    pub fn begin_edit(&mut self, row: usize, column: usize) -> bool {
+        self.cancel_edit();
+        let Some(editor) = self.columns.get(column).and_then(|column| column.editor.clone()) else {
+            return false;
+        };
+        let Some(value) = self.cell(row, column).map(str::to_string) else {
+            return false;
+        };
+        if let Some(position) = self.display_index(row) {
+            self.scroll_to(position);
+        }
+        let bounds = self.cell_bounds(row, column).unwrap_or(Rectangle::new(0.0, 0.0, 0.0, 0.0));
+
+        let widget = match editor {
+            CellEditor::Text => {
+                let mut field = TextField::new(bounds.x, bounds.y, bounds.width, bounds.height, 256)
+                    .with_style(self.style.clone());
+                field.set_text(&value);
+                field.activate();
+                EditorWidget::Text(field)
+            }
+            CellEditor::Check => {
+                let size = bounds.height * 0.5;
+                let mut checkbox = Checkbox::new(bounds.x + self.style.padding, bounds.y + (bounds.height - size) / 2.0, size, "")
+                    .with_style(self.style.clone());
+                checkbox.set_checked(value == "true");
+                checkbox.toggle();
+                EditorWidget::Check(checkbox)
+            }
+            CellEditor::Choice(choices) => {
+                let mut dropdown = Dropdown::new(bounds.x, bounds.y, bounds.width, bounds.height, choices)
+                    .with_style(self.style.clone())
+                    .with_item_height(self.row_height)
+                    .with_parent_bounds(self.bounds);
+                dropdown.selected_index = dropdown.items.iter().position(|item| item.value == value);
+                dropdown.open();
+                EditorWidget::Choice(dropdown)
+            }
+        };
+        self.editing = Some(CellEdit { row, column, widget });
+
+        if matches!(self.editing, Some(CellEdit { widget: EditorWidget::Check(_), .. })) {
+            self.commit_edit();
+        }
+        true
+    }
+
+    /// Write the editor's value into the cell, reporting it if it changed
+    pub fn commit_edit(&mut self) {
+        let Some(edit) = self.editing.take() else {
+            return;
+        };
+        let value = match &edit.widget {
+            EditorWidget::Text(field) => Some(field.text.clone()),
+            EditorWidget::Check(checkbox) => Some(checkbox.is_checked().to_string()),
+            EditorWidget::Choice(dropdown) => dropdown.selected_value().cloned(),
+        };
+        let Some(value) = value else {
+            return;
+        };
+        if self.cell(edit.row, edit.column) != Some(value.as_str()) {
+            self.set_cell(edit.row, edit.column, &value);
+            self.events.push(TableEvent::CellEdited {
+                row: edit.row,
+                column: edit.column,
+                value,
+            });
+        }
+    }
+
+    pub fn cancel_edit(&mut self) {
+        self.editing = None;
+    }
+
+    fn update_editor(&mut self, mouse: Vector2, rl: &mut RaylibHandle) {
+        let Some(edit) = &mut self.editing else {
+            return;
+        };
+        let escape = rl.is_key_pressed(KeyboardKey::KEY_ESCAPE);
+        if !escape {
+            match &mut edit.widget {
+                EditorWidget::Text(field) => field.update(mouse, rl),
+                EditorWidget::Check(_) => {}
+                EditorWidget::Choice(dropdown) => dropdown.update(mouse, rl),
+            }
+        }
+        self.finish_edit(escape, rl.is_key_pressed(KeyboardKey::KEY_ENTER));
+    }
+
+    /// After the editor has updated: Escape discards the edit, and Enter or an editor that closed itself commits it
+    pub(crate) fn finish_edit(&mut self, escape: bool, enter: bool) {
+        let Some(edit) = &self.editing else {
+            return;
+        };
+        if escape {
+            self.cancel_edit();
+            return;
+        }
+        let done = match &edit.widget {
+            EditorWidget::Text(field) => enter || !field.is_active,
+            EditorWidget::Check(_) => true,
+            EditorWidget::Choice(dropdown) => !dropdown.is_open,
+        };
+        if done {
+            self.commit_edit();
+        }
+    }
+
+    /// Start resizing or moving the header under `point`; returns whether a header was hit
+    pub fn press_header(&mut self, point: Vector2) -> bool {
+        if let Some(column) = self.resize_handle_at(point) {
+            self.header_drag = Some(HeaderDrag::Resize {
+                column,
+                grab_x: point.x,
+                start_width: self.columns[column].width,
+            });
+            return true;
+        }
+        match self.header_at(point) {
+            Some(column) => {
+                self.header_drag = Some(HeaderDrag::Move {
+                    column,
+                    press_x: point.x,
+                    moving: false,
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn drag_header(&mut self, point: Vector2) {
+        match &mut self.header_drag {
+            Some(HeaderDrag::Resize { column, grab_x, start_width }) => {
+                let column = &mut self.columns[*column];
+                column.width = (*start_width + point.x - *grab_x).max(column.min_width);
+            }
+            Some(HeaderDrag::Move { press_x, moving, .. }) if (point.x - *press_x).abs() >= MOVE_THRESHOLD => {
+                *moving = true;
+            }
+            _ => {}
+        }
+    }
+
+    /// Finish a header drag: a resize is reported, a moved header is dropped where the mouse is,
+    /// and a header that wasn't dragged toggles sorting
+    pub fn release_header(&mut self, point: Vector2) {
+        match self.header_drag.take() {
+            Some(HeaderDrag::Resize { column, start_width, .. }) if self.columns[column].width != start_width => {
+                self.events.push(TableEvent::ColumnResized(column));
+            }
+            Some(HeaderDrag::Move { column, moving: true, .. }) => {
+                let from = self.display_column(column);
+                let to = self.drop_position(point.x);
+                if let (Some(from), Some(to)) = (from, to) {
+                    if from != to {
+                        let moved = self.column_order.remove(from);
+                        self.column_order.insert(to, moved);
+                        self.events.push(TableEvent::ColumnMoved { from, to });
+                    }
+                }
+            }
+            Some(HeaderDrag::Move { column, moving: false, .. }) if self.columns[column].sortable => {
+                self.toggle_sort(column);
+            }
+            _ => {}
+        }
+    }
+
+    /// Display position of `column`
+    pub fn display_column(&self, column: usize) -> Option<usize> {
+        self.column_order.iter().position(|&shown| shown == column)
+    }
+
+    /// Display position a header dropped at `x` lands in
+    fn drop_position(&self, x: f32) -> Option<usize> {
+        if self.column_order.is_empty() {
+            return None;
+        }
+        let mut left = self.bounds.x;
+        for (position, &column) in self.column_order.iter().enumerate() {
+            let width = self.columns[column].width;
+            if x < left + width {
+                return Some(position);
+            }
+            left += width;
+        }
+        Some(self.column_order.len() - 1)
+    }
+
+    /// Left edge and width of `column`, clipped to the table
+    fn column_span(&self, column: usize) -> Option<(f32, f32)> {
+        let mut left = self.bounds.x;
+        for &shown in &self.column_order {
+            let width = self.columns[shown].width;
+            if shown == column {
+                let right = (left + width).min(self.content_right());
+                return (right > left).then_some((left, right - left));
+            }
+            left += width;
+        }
+        None
+    }
+
+    fn content_right(&self) -> f32 {
+        self.bounds.x + self.bounds.width - if self.max_scroll() > 0 { SCROLLBAR_WIDTH } else { 0.0 }
+    }
+
+    pub fn header_bounds(&self, column: usize) -> Option<Rectangle> {
+        let (x, width) = self.column_span(column)?;
+        Some(Rectangle::new(x, self.bounds.y, width, self.header_height))
+    }
+
+    /// Column whose header is under `point`
+    pub fn header_at(&self, point: Vector2) -> Option<usize> {
+        self.column_order
+            .iter()
+            .copied()
+            .find(|&column| self.header_bounds(column).is_some_and(|header| header.check_collision_point_rec(point)))
+    }
+
+    /// Column whose right header edge is under `point`
+    pub fn resize_handle_at(&self, point: Vector2) -> Option<usize> {
+        if point.y < self.bounds.y || point.y > self.bounds.y + self.header_height {
+            return None;
+        }
+        self.column_order.iter().copied().find(|&column| {
+            self.column_span(column)
+                .is_some_and(|(x, width)| (point.x - (x + self.columns[column].width.min(width))).abs() <= RESIZE_GRIP)
+        })
+    }
+
+    /// Column under `x`
+    pub fn column_at(&self, x: f32) -> Option<usize> {
+        self.column_order
+            .iter()
+            .copied()
+            .find(|&column| self.column_span(column).is_some_and(|(left, width)| x >= left && x < left + width))
+    }
+
+    pub fn visible_row_count(&self) -> usize {
+        (((self.bounds.height - self.header_height) / self.row_height).floor() as usize).max(1)
+    }
+
+    /// Display positions of the rows on screen
+    pub fn visible_range(&self) -> Range<usize> {
+        let end = (self.scroll_offset + self.visible_row_count()).min(self.order.len());
+        self.scroll_offset.min(end)..end
+    }
+
+    pub fn max_scroll(&self) -> usize {
+        self.rows.len().saturating_sub(self.visible_row_count())
+    }
+
+    /// Scroll just enough for the row at display `position` to be visible
+    pub fn scroll_to(&mut self, position: usize) {
+        if position < self.scroll_offset {
+            self.scroll_offset = position;
+        } else if position >= self.scroll_offset + self.visible_row_count() {
+            self.scroll_offset = position + 1 - self.visible_row_count();
+        }
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll());
+    }
+
+    /// Bounds of `row` below the header, if it is visible
+    pub fn row_bounds(&self, row: usize) -> Option<Rectangle> {
+        let position = self.display_index(row)?;
+        if !self.visible_range().contains(&position) {
+            return None;
+        }
+        Some(Rectangle::new(
+            self.bounds.x,
+            self.bounds.y + self.header_height + (position - self.scroll_offset) as f32 * self.row_height,
+            self.content_right() - self.bounds.x,
+            self.row_height,
+        ))
+    }
+
+    pub fn cell_bounds(&self, row: usize, column: usize) -> Option<Rectangle> {
+        let row = self.row_bounds(row)?;
+        let (x, width) = self.column_span(column)?;
+        Some(Rectangle::new(x, row.y, width, row.height))
+    }
+
+    /// Row under `point`, below the header
+    pub fn row_at(&self, point: Vector2) -> Option<usize> {
+        let top = self.bounds.y + self.header_height;
+        if point.y < top || point.x < self.bounds.x || point.x >= self.content_right() {
+            return None;
+        }
+        let position = self.scroll_offset + ((point.y - top) / self.row_height) as usize;
+        self.visible_range().contains(&position).then(|| self.order[position])
+    }
+
+    /// Track along the right edge below the header, present only when the rows scroll
+    pub fn scrollbar_track(&self) -> Option<Rectangle> {
+        if self.max_scroll() == 0 {
+            return None;
+        }
+        let body = Rectangle::new(
+            self.bounds.x,
+            self.bounds.y + self.header_height,
+            self.bounds.width,
+            self.bounds.height - self.header_height,
+        );
+        Some(scrollbar_track(body))
+    }
+
+    fn scrollbar_thumb(&self, track: Rectangle) -> Rectangle {
+        let (visible, total) = (self.visible_row_count() as f32, self.rows.len() as f32);
+        scrollbar_thumb(ScrollAxis::Vertical, track, visible, total, self.scroll_offset as f32)
+    }
+
+    /// Grab the thumb where it was clicked, or centre it on the click when the track was hit
+    fn start_scroll_drag(&mut self, mouse: Vector2) {
+        let Some(track) = self.scrollbar_track() else {
+            return;
+        };
+        self.scroll_grab = start_scroll_drag(ScrollAxis::Vertical, self.scrollbar_thumb(track), mouse);
+        self.is_scrolling = true;
+        self.drag_scroll(mouse);
+    }
+
+    fn drag_scroll(&mut self, mouse: Vector2) {
+        let Some(track) = self.scrollbar_track() else {
+            return;
+        };
+        let thumb = self.scrollbar_thumb(track);
+        let offset = drag_scroll(ScrollAxis::Vertical, track, thumb, self.scroll_grab, mouse, self.max_scroll() as f32);
+        self.scroll_offset = offset.round() as usize;
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        d.draw_rectangle_rec(self.bounds, self.style.background_color);
+
+        let text_offset = (self.row_height - self.style.font_size as f32) / 2.0;
+        for position in self.visible_range() {
+            let row = self.order[position];
+            let Some(row_bounds) = self.row_bounds(row) else {
+                continue;
+            };
+            if self.selected_row == Some(row) {
+                d.draw_rectangle_rec(row_bounds, self.style.pressed_color);
+            } else if self.hover_row == Some(row) {
+                d.draw_rectangle_rec(row_bounds, self.style.hover_color);
+            }
+            let text_color = if self.selected_row == Some(row) {
+                self.style.text_color_pressed
+            } else {
+                self.style.text_color
+            };
+
+            for &column in &self.column_order {
+                let Some(cell) = self.cell_bounds(row, column) else {
+                    continue;
+                };
+                let value = self.cell(row, column).unwrap_or("");
+                if self.columns[column].editor == Some(CellEditor::Check) {
+                    draw_check_glyph(d, &self.style, cell, value == "true");
+                } else {
+                    let label = truncate_with_ellipsis(value, cell.width - self.style.padding * 2.0, self.style.font_size);
+                    d.draw_text(
+                        &label,
+                        (cell.x + self.style.padding) as i32,
+                        (cell.y + text_offset) as i32,
+                        self.style.font_size,
+                        text_color,
+                    );
+                }
+                d.draw_line_ex(
+                    Vector2::new(cell.x + cell.width, cell.y),
+                    Vector2::new(cell.x + cell.width, cell.y + cell.height),
+                    1.0,
+                    self.style.border_color.alpha(0.3),
+                );
+            }
+        }
+
+        if let Some(track) = self.scrollbar_track() {
+            draw_scrollbar(d, &self.style, track, self.scrollbar_thumb(track), self.is_scrolling);
+        }
+
+        // The header is drawn last so it stays on top of the rows
+        self.draw_header(d);
+
+        if let Some(edit) = &self.editing {
+            match &edit.widget {
+                EditorWidget::Text(field) => field.draw(d),
+                EditorWidget::Check(checkbox) => checkbox.draw(d),
+                EditorWidget::Choice(dropdown) => dropdown.draw(d),
+            }
+        }
+
+        let border_color = if self.is_focused {
+            self.style.border_color_active
+        } else {
+            self.style.border_color
+        };
+        d.draw_rectangle_lines_ex(self.bounds, self.style.border_thickness, border_color);
+    }
+
+    fn draw_header(&self, d: &mut impl RaylibDraw) {
+        let header = Rectangle::new(self.bounds.x, self.bounds.y, self.bounds.width, self.header_height);
+        d.draw_rectangle_rec(header, self.style.active_color);
+
+        let moving = match self.header_drag {
+            Some(HeaderDrag::Move { column, moving: true, .. }) => Some(column),
+            _ => None,
+        };
+        let text_y = self.bounds.y + (self.header_height - self.style.font_size as f32) / 2.0;
+        for &column in &self.column_order {
+            let Some(bounds) = self.header_bounds(column) else {
+                continue;
+            };
+            if moving == Some(column) {
+                d.draw_rectangle_rec(bounds, self.style.pressed_color);
+            }
+
+            let arrow_size = self.style.font_size as f32 * 0.5;
+            let sorted = self.sort.filter(|(sorted, _)| *sorted == column).map(|(_, direction)| direction);
+            let reserved = if sorted.is_some() { arrow_size + self.style.padding } else { 0.0 };
+            let title = truncate_with_ellipsis(
+                &self.columns[column].title,
+                bounds.width - self.style.padding * 2.0 - reserved,
+                self.style.font_size,
+            );
+            d.draw_text(
+                &title,
+                (bounds.x + self.style.padding) as i32,
+                text_y as i32,
+                self.style.font_size,
+                self.style.text_color_pressed,
+            );
+
+            // Sort indicator, pointing up for ascending
+            if let Some(direction) = sorted {
+                let x = bounds.x + bounds.width - arrow_size - self.style.padding;
+                let y = self.bounds.y + (self.header_height - arrow_size) / 2.0;
+                let direction = match direction {
+                    SortDirection::Ascending => ArrowDirection::Up,
+                    SortDirection::Descending => ArrowDirection::Down,
+                };
+                draw_arrow_glyph(
+                    d,
+                    Rectangle::new(x, y, arrow_size, arrow_size),
+                    direction,
+                    self.style.text_color_pressed,
+                );
+            }
+
+            d.draw_line_ex(
+                Vector2::new(bounds.x + bounds.width, bounds.y),
+                Vector2::new(bounds.x + bounds.width, bounds.y + bounds.height),
+                1.0,
+                self.style.border_color,
+            );
+        }
+        d.draw_line_ex(
+            Vector2::new(header.x, header.y + header.height),
+            Vector2::new(header.x + header.width, header.y + header.height),
+            self.style.border_thickness,
+            self.style.border_color,
+        );
+    }
+}
+
+/// Numbers compare by value, everything else case-insensitively
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
@@ -0,0 +1,603 @@
+use raylib::prelude::*;
+use crate::style::Style;
+use crate::cursor::{request_cursor, CursorShape};
+use crate::dropdown::{draw_arrow_glyph, ArrowDirection};
+use crate::layout::NO_MOUSE;
+use crate::text::{measure_text, truncate_with_ellipsis};
+
+const ARROW_WIDTH: f32 = 20.0;
+const DRAG_THRESHOLD: f32 = 5.0;
+
+pub struct Tab {
+    pub label: String,
+    pub closable: bool,
+}
+
+impl Tab {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            closable: false,
+        }
+    }
+
+    pub fn with_closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// Something that happened to the tabs during the last update
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TabEvent {
+    Selected(usize),
+    /// The tab that was at this index was closed and removed
+    Closed(usize),
+    Moved { from: usize, to: usize },
+}
+
+pub struct TabBar {
+    pub bounds: Rectangle,
+    pub tabs: Vec<Tab>,
+    pub active_index: Option<usize>,
+    pub style: Style,
+    pub hover_index: Option<usize>,
+    pub min_tab_width: f32,
+    pub max_tab_width: f32,
+    pub scroll_offset: usize,
+    pub is_focused: bool,
+    pub reorderable: bool,
+    pub events: Vec<TabEvent>,
+    drag_index: Option<usize>,
+    drag_start_x: f32,
+    is_dragging: bool,
+}
+
+impl TabBar {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, labels: &[&str]) -> Self {
+        Self {
+            bounds: Rectangle::new(x, y, width, height),
+            tabs: labels.iter().map(|label| Tab::new(label)).collect(),
+            active_index: if labels.is_empty() { None } else { Some(0) },
+            style: Style::default(),
+            hover_index: None,
+            min_tab_width: 60.0,
+            max_tab_width: 180.0,
+            scroll_offset: 0,
+            is_focused: false,
+            reorderable: true,
+            events: Vec::new(),
+            drag_index: None,
+            drag_start_x: 0.0,
+            is_dragging: false,
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Give every tab a close button
+    pub fn with_closable_tabs(mut self) -> Self {
+        for tab in &mut self.tabs {
+            tab.closable = true;
+        }
+        self
+    }
+
+    pub fn with_tab_width(mut self, min: f32, max: f32) -> Self {
+        self.min_tab_width = min;
+        self.max_tab_width = max;
+        self
+    }
+
+    pub fn with_reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        self.events.clear();
+
+        let over_bar = self.bounds.check_collision_point_rec(mouse);
+        self.hover_index = if over_bar { self.tab_at(mouse) } else { None };
+        let over_arrow = over_bar && self.overflows() && {
+            let (left, right) = self.arrow_bounds();
+            left.check_collision_point_rec(mouse) || right.check_collision_point_rec(mouse)
+        };
+        if self.hover_index.is_some() || over_arrow {
+            request_cursor(CursorShape::PointingHand);
+        }
+
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.is_focused = over_bar;
+            if over_bar {
+                self.handle_press(mouse);
+            }
+        }
+
+        // Drag the pressed tab to a new slot once the mouse has moved far enough
+        if let Some(dragged) = self.drag_index {
+            if rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT) {
+                if (mouse.x - self.drag_start_x).abs() > DRAG_THRESHOLD {
+                    self.is_dragging = true;
+                }
+                if self.is_dragging {
+                    if let Some(target) = self.tab_at(Vector2::new(mouse.x, self.bounds.y + 1.0)) {
+                        if target != dragged {
+                            self.move_tab(dragged, target);
+                            self.drag_index = Some(target);
+                        }
+                    }
+                }
+            } else {
+                self.drag_index = None;
+                self.is_dragging = false;
+            }
+        }
+
+        if over_bar {
+            let wheel_move = rl.get_mouse_wheel_move() as i32;
+            if wheel_move != 0 {
+                self.scroll_by(-wheel_move);
+            }
+        }
+
+        // Ctrl+Tab / Ctrl+Shift+Tab cycle through the tabs
+        let ctrl = rl.is_key_down(KeyboardKey::KEY_LEFT_CONTROL) || rl.is_key_down(KeyboardKey::KEY_RIGHT_CONTROL);
+        if self.is_focused && ctrl && rl.is_key_pressed(KeyboardKey::KEY_TAB) {
+            let shift = rl.is_key_down(KeyboardKey::KEY_LEFT_SHIFT) || rl.is_key_down(KeyboardKey::KEY_RIGHT_SHIFT);
+            self.cycle(if shift { -1 } else { 1 });
+        }
+    }
+
+    fn handle_press(&mut self, mouse: Vector2) {
+        if self.overflows() {
+            let (left, right) = self.arrow_bounds();
+            if left.check_collision_point_rec(mouse) {
+                self.scroll_by(-1);
+                return;
+            }
+            if right.check_collision_point_rec(mouse) {
+                self.scroll_by(1);
+                return;
+            }
+        }
+
+        let Some(index) = self.tab_at(mouse) else {
+            return;
+        };
+        if let Some(close) = self.close_bounds(index) {
+            if close.check_collision_point_rec(mouse) {
+                self.close_tab(index);
+                return;
+            }
+        }
+
+        self.select(index);
+        if self.reorderable {
+            self.drag_index = Some(index);
+            self.drag_start_x = mouse.x;
+            self.is_dragging = false;
+        }
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        d.draw_rectangle_rec(self.bounds, self.style.background_color);
+
+        let strip = self.strip_bounds();
+        let text_y = (self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0) as i32;
+        for (index, rect) in self.tab_rects() {
+            if rect.x >= strip.x + strip.width {
+                break;
+            }
+            let is_active = Some(index) == self.active_index;
+            let background_color = if is_active {
+                self.style.active_color
+            } else if Some(index) == self.hover_index {
+                self.style.hover_color
+            } else {
+                self.style.background_color
+            };
+            let border_color = if is_active {
+                self.style.border_color_active
+            } else {
+                self.style.border_color
+            };
+
+            // Tabs cut off by the arrows are clipped to the strip
+            let visible = Rectangle::new(rect.x, rect.y, rect.width.min(strip.x + strip.width - rect.x), rect.height);
+            d.draw_rectangle_rec(visible, background_color);
+            d.draw_rectangle_lines_ex(visible, self.style.border_thickness, border_color);
+
+            let tab = &self.tabs[index];
+            let label = truncate_with_ellipsis(&tab.label, self.label_width(index, rect.width), self.style.font_size);
+            let text_color = if is_active {
+                self.style.text_color_pressed
+            } else if Some(index) == self.hover_index {
+                self.style.text_color_hover
+            } else {
+                self.style.text_color
+            };
+            if rect.x + rect.width <= strip.x + strip.width {
+                d.draw_text(&label, (rect.x + self.style.padding) as i32, text_y, self.style.font_size, text_color);
+                if let Some(close) = self.close_bounds(index) {
+                    let inset = close.width * 0.25;
+                    d.draw_line_ex(
+                        Vector2::new(close.x + inset, close.y + inset),
+                        Vector2::new(close.x + close.width - inset, close.y + close.height - inset),
+                        1.5,
+                        text_color,
+                    );
+                    d.draw_line_ex(
+                        Vector2::new(close.x + close.width - inset, close.y + inset),
+                        Vector2::new(close.x + inset, close.y + close.height - inset),
+                        1.5,
+                        text_color,
+                    );
+                }
+            }
+        }
+
+        if self.overflows() {
+            self.draw_arrows(d);
+        }
+
+        // Line under the bar that the active tab sits on
+        d.draw_line_ex(
+            Vector2::new(self.bounds.x, self.bounds.y + self.bounds.height),
+            Vector2::new(self.bounds.x + self.bounds.width, self.bounds.y + self.bounds.height),
+            self.style.border_thickness,
+            self.style.border_color_active,
+        );
+    }
+
+    fn draw_arrows(&self, d: &mut impl RaylibDraw) {
+        let (left, right) = self.arrow_bounds();
+        let size = self.style.font_size as f32 * 0.4;
+        for (rect, points_left, enabled) in [
+            (left, true, self.scroll_offset > 0),
+            (right, false, self.can_scroll_right()),
+        ] {
+            d.draw_rectangle_rec(rect, self.style.background_color);
+            d.draw_rectangle_lines_ex(rect, self.style.border_thickness, self.style.border_color);
+            let color = if enabled { self.style.text_color } else { self.style.text_color_disabled };
+            let cx = rect.x + rect.width / 2.0;
+            let cy = rect.y + rect.height / 2.0;
+            let direction = if points_left { ArrowDirection::Left } else { ArrowDirection::Right };
+            draw_arrow_glyph(d, Rectangle::new(cx - size / 2.0, cy - size, size, size * 2.0), direction, color);
+        }
+    }
+
+    /// Width of one tab: its label plus padding and close button, within the min/max tab width
+    pub fn tab_width(&self, index: usize) -> f32 {
+        let tab = &self.tabs[index];
+        let close_width = if tab.closable { self.close_size() + self.style.padding } else { 0.0 };
+        let natural = measure_text(&tab.label, self.style.font_size) + self.style.padding * 2.0 + close_width;
+        natural.clamp(self.min_tab_width, self.max_tab_width.max(self.min_tab_width))
+    }
+
+    fn label_width(&self, index: usize, tab_width: f32) -> f32 {
+        let close_width = if self.tabs[index].closable { self.close_size() + self.style.padding } else { 0.0 };
+        tab_width - self.style.padding * 2.0 - close_width
+    }
+
+    fn close_size(&self) -> f32 {
+        self.style.font_size as f32 * 0.8
+    }
+
+    fn total_width(&self) -> f32 {
+        (0..self.tabs.len()).map(|i| self.tab_width(i)).sum()
+    }
+
+    /// Whether the tabs are wider than the bar and need the scroll arrows
+    pub fn overflows(&self) -> bool {
+        self.total_width() > self.bounds.width
+    }
+
+    /// Area the tabs are drawn in, leaving room for the arrows when they overflow
+    fn strip_bounds(&self) -> Rectangle {
+        let arrows = if self.overflows() { ARROW_WIDTH * 2.0 } else { 0.0 };
+        Rectangle::new(self.bounds.x, self.bounds.y, self.bounds.width - arrows, self.bounds.height)
+    }
+
+    fn arrow_bounds(&self) -> (Rectangle, Rectangle) {
+        let x = self.bounds.x + self.bounds.width - ARROW_WIDTH * 2.0;
+        (
+            Rectangle::new(x, self.bounds.y, ARROW_WIDTH, self.bounds.height),
+            Rectangle::new(x + ARROW_WIDTH, self.bounds.y, ARROW_WIDTH, self.bounds.height),
+        )
+    }
+
+    /// Bounds of the tabs from `scroll_offset` onwards, left to right
+    pub fn tab_rects(&self) -> Vec<(usize, Rectangle)> {
+        let mut x = self.bounds.x;
+        (self.scroll_offset..self.tabs.len())
+            .map(|index| {
+                let width = self.tab_width(index);
+                let rect = Rectangle::new(x, self.bounds.y, width, self.bounds.height);
+                x += width;
+                (index, rect)
+            })
+            .collect()
+    }
+
+    fn tab_at(&self, point: Vector2) -> Option<usize> {
+        if !self.strip_bounds().check_collision_point_rec(point) {
+            return None;
+        }
+        self.tab_rects()
+            .into_iter()
+            .find(|(_, rect)| rect.check_collision_point_rec(point))
+            .map(|(index, _)| index)
+    }
+
+    fn close_bounds(&self, index: usize) -> Option<Rectangle> {
+        if !self.tabs.get(index)?.closable {
+            return None;
+        }
+        let (_, rect) = self.tab_rects().into_iter().find(|(i, _)| *i == index)?;
+        let size = self.close_size();
+        Some(Rectangle::new(
+            rect.x + rect.width - self.style.padding - size,
+            rect.y + (rect.height - size) / 2.0,
+            size,
+            size,
+        ))
+    }
+
+    fn can_scroll_right(&self) -> bool {
+        let strip = self.strip_bounds();
+        self.tab_rects()
+            .last()
+            .is_some_and(|(_, rect)| rect.x + rect.width > strip.x + strip.width)
+    }
+
+    fn scroll_by(&mut self, delta: i32) {
+        if delta > 0 && !self.can_scroll_right() {
+            return;
+        }
+        self.scroll_offset = (self.scroll_offset as i32 + delta).clamp(0, self.tabs.len().saturating_sub(1) as i32) as usize;
+    }
+
+    /// Scroll just enough for the tab at `index` to be fully visible
+    pub fn scroll_to_tab(&mut self, index: usize) {
+        if index < self.scroll_offset {
+            self.scroll_offset = index;
+            return;
+        }
+        let strip = self.strip_bounds();
+        while self.scroll_offset < index {
+            let fits = self
+                .tab_rects()
+                .into_iter()
+                .find(|(i, _)| *i == index)
+                .is_some_and(|(_, rect)| rect.x + rect.width <= strip.x + strip.width);
+            if fits {
+                break;
+            }
+            self.scroll_offset += 1;
+        }
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if index >= self.tabs.len() {
+            return;
+        }
+        if self.active_index != Some(index) {
+            self.active_index = Some(index);
+            self.events.push(TabEvent::Selected(index));
+        }
+        self.scroll_to_tab(index);
+    }
+
+    /// Move the active tab forwards or backwards, wrapping around
+    pub fn cycle(&mut self, direction: i32) {
+        let count = self.tabs.len() as i32;
+        if count == 0 {
+            return;
+        }
+        let current = self.active_index.unwrap_or(0) as i32;
+        self.select((current + direction).rem_euclid(count) as usize);
+    }
+
+    pub fn add_tab(&mut self, tab: Tab) {
+        self.tabs.push(tab);
+        if self.active_index.is_none() {
+            self.active_index = Some(0);
+        }
+    }
+
+    /// Remove a tab, keeping the same tab active where possible; closing the active tab selects a neighbour
+    pub fn close_tab(&mut self, index: usize) {
+        if index >= self.tabs.len() {
+            return;
+        }
+        let was_active = self.active_index == Some(index);
+        self.tabs.remove(index);
+        self.events.push(TabEvent::Closed(index));
+
+        self.active_index = match self.active_index {
+            _ if self.tabs.is_empty() => None,
+            Some(active) if active > index => Some(active - 1),
+            Some(active) if active == index => Some(active.min(self.tabs.len() - 1)),
+            other => other,
+        };
+        if let Some(active) = self.active_index.filter(|_| was_active) {
+            self.events.push(TabEvent::Selected(active));
+        }
+        self.scroll_offset = self.scroll_offset.min(self.tabs.len().saturating_sub(1));
+    }
+
+    /// Move a tab to a new position, keeping the active tab active
+    pub fn move_tab(&mut self, from: usize, to: usize) {
+        if from >= self.tabs.len() || to >= self.tabs.len() || from == to {
+            return;
+        }
+        let tab = self.tabs.remove(from);
+        self.tabs.insert(to, tab);
+        self.events.push(TabEvent::Moved { from, to });
+
+        self.active_index = self.active_index.map(|active| {
+            if active == from {
+                to
+            } else if from < active && active <= to {
+                active - 1
+            } else if to <= active && active < from {
+                active + 1
+            } else {
+                active
+            }
+        });
+    }
+
+    pub fn active_label(&self) -> Option<&String> {
+        self.active_index.and_then(|i| self.tabs.get(i)).map(|tab| &tab.label)
+    }
+}
+
+/// A tab bar above a content area, with one page per tab
+pub struct TabView<P> {
+    pub bounds: Rectangle,
+    pub bar: TabBar,
+    pub pages: Vec<P>,
+    pub bar_height: f32,
+}
+
+impl<P> TabView<P> {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, pages: Vec<(&str, P)>) -> Self {
+        let bar_height = 30.0;
+        let labels: Vec<&str> = pages.iter().map(|(label, _)| *label).collect();
+        Self {
+            bounds: Rectangle::new(x, y, width, height),
+            bar: TabBar::new(x, y, width, bar_height, &labels),
+            pages: pages.into_iter().map(|(_, page)| page).collect(),
+            bar_height,
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.bar.style = style;
+        self
+    }
+
+    pub fn with_closable_tabs(mut self) -> Self {
+        self.bar = self.bar.with_closable_tabs();
+        self
+    }
+
+    pub fn with_bar_height(mut self, height: f32) -> Self {
+        self.bar_height = height;
+        self.bar.bounds.height = height;
+        self
+    }
+
+    /// Update the tab bar, keep the pages in step with closed or moved tabs, then call
+    /// `update_page` with the active page and the mouse it should see
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle, update_page: impl FnOnce(&mut P, Vector2)) {
+        self.bar.bounds = Rectangle::new(self.bounds.x, self.bounds.y, self.bounds.width, self.bar_height);
+        self.bar.update(mouse, rl);
+
+        // Clicking the page keeps Ctrl+Tab working
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) && self.bounds.check_collision_point_rec(mouse) {
+            self.bar.is_focused = true;
+        }
+
+        self.sync_pages();
+
+        let page_mouse = self.page_mouse(mouse);
+        if let Some(page) = self.active_page_mut() {
+            update_page(page, page_mouse);
+        }
+    }
+
+    /// The mouse position the active page should see: the real one only over the content area
+    pub fn page_mouse(&self, mouse: Vector2) -> Vector2 {
+        if self.content_bounds().check_collision_point_rec(mouse) {
+            mouse
+        } else {
+            NO_MOUSE
+        }
+    }
+
+    /// Apply close and move events from the bar's last update to the pages; `update` calls this once per frame
+    fn sync_pages(&mut self) {
+        for event in &self.bar.events {
+            match *event {
+                TabEvent::Closed(index) if index < self.pages.len() => {
+                    self.pages.remove(index);
+                }
+                TabEvent::Moved { from, to } if from < self.pages.len() && to < self.pages.len() => {
+                    let page = self.pages.remove(from);
+                    self.pages.insert(to, page);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Draw the bar and page background, then call `draw_page` with the active page clipped to `content_bounds()`
+    pub fn draw<D: RaylibDraw>(&self, d: &mut D, draw_page: impl FnOnce(&P, &mut RaylibScissorMode<'_, D>)) {
+        let content = self.content_bounds();
+        d.draw_rectangle_rec(content, self.bar.style.background_color);
+        d.draw_rectangle_lines_ex(content, self.bar.style.border_thickness, self.bar.style.border_color);
+        self.bar.draw(d);
+
+        if let Some(page) = self.active_page() {
+            let mut clipped = d.begin_scissor_mode(
+                content.x as i32,
+                content.y as i32,
+                content.width as i32,
+                content.height as i32,
+            );
+            draw_page(page, &mut clipped);
+        }
+    }
+
+    pub fn content_bounds(&self) -> Rectangle {
+        Rectangle::new(
+            self.bounds.x,
+            self.bounds.y + self.bar_height,
+            self.bounds.width,
+            self.bounds.height - self.bar_height,
+        )
+    }
+
+    /// Add a tab with its page at the end
+    pub fn add_page(&mut self, tab: Tab, page: P) {
+        self.bar.add_tab(tab);
+        self.pages.push(page);
+    }
+
+    /// Close a tab and remove its page
+    pub fn close_page(&mut self, index: usize) {
+        if index >= self.bar.tabs.len() {
+            return;
+        }
+        self.bar.close_tab(index);
+        if index < self.pages.len() {
+            self.pages.remove(index);
+        }
+    }
+
+    /// Move a tab and its page to a new position
+    pub fn move_page(&mut self, from: usize, to: usize) {
+        if from >= self.bar.tabs.len() || to >= self.bar.tabs.len() || from == to {
+            return;
+        }
+        self.bar.move_tab(from, to);
+        if from < self.pages.len() && to < self.pages.len() {
+            let page = self.pages.remove(from);
+            self.pages.insert(to, page);
+        }
+    }
+
+    pub fn active_page(&self) -> Option<&P> {
+        self.bar.active_index.and_then(|i| self.pages.get(i))
+    }
+
+    pub fn active_page_mut(&mut self) -> Option<&mut P> {
+        self.bar.active_index.and_then(|i| self.pages.get_mut(i))
+    }
+}
@@ -1,5 +1,28 @@
 use raylib::prelude::*;
-use crate::{Button, TextField, Checkbox, Dropdown, Style, presets};
+use crate::{Button, IconPlacement, TextField, Checkbox, CheckState, ComboBox, Dropdown, MatchMode, RadioGroup, RadioLayout, Toggle, Style, presets};
+use crate::button::{icon_tint, layout_button};
+use crate::combobox::{fuzzy_match, prefix_match};
+use crate::cursor::{capture_cursor, request_cursor, requested_cursor, CursorResolver, CursorShape};
+use crate::context_menu::{ContextMenu, MenuEntry, MenuEvent, MenuItem};
+use crate::label::{layout_spans, parse_color, parse_markup, run_at, Label, RichText, RichTextEvent, TextAlign, TextSpan};
+use crate::layout::{Bounded, NO_MOUSE};
+use crate::link::{Link, LinkEvent};
+use crate::listbox::{ListBox, ListBoxEvent};
+use crate::menu_bar::{Menu, MenuBar};
+use crate::skin::{resolve_state, Skin, SkinState, SKIN_DISABLED_TINT};
+use crate::shortcuts::{Binding, KeyChord, ShortcutScope, Shortcuts};
+use crate::textfield::TEXT_FIELD_SCOPE;
+use crate::dropdown::{DropdownItem, SelectionSummary};
+use crate::table::{CellEdit, CellEditor, Column, EditorWidget, SortDirection, Table, TableEvent};
+use crate::tabs::{Tab, TabBar, TabEvent, TabView};
+use crate::tree::{DropPosition, TreeEvent, TreeNode, TreeView};
+use crate::text::{measure_text, parse_mnemonic, truncate_with_ellipsis, wrap_text};
+use crate::tooltip::{Tooltip, TooltipPlacement};
+use crate::modal::{ConfirmResult, DialogResult, ModalLayer};
+use crate::scroll::{ScrollArea, ScrollAxis, ScrollMode};
+use crate::window::{ResizeEdges, Window, WindowStack};
+use std::cell::Cell;
+use std::rc::Rc;
 
 pub struct TestResults {
     pub button_tests: bool,
@@ -7,6 +30,30 @@ pub struct TestResults {
     pub checkbox_tests: bool,
     pub dropdown_tests: bool,
     pub style_tests: bool,
+    pub radio_tests: bool,
+    pub toggle_tests: bool,
+    pub combobox_tests: bool,
+    pub tabs_tests: bool,
+    pub window_tests: bool,
+    pub scroll_tests: bool,
+    pub modal_tests: bool,
+    pub tooltip_tests: bool,
+    pub context_menu_tests: bool,
+    pub menu_bar_tests: bool,
+    pub shortcuts_tests: bool,
+    pub listbox_tests: bool,
+    pub table_tests: bool,
+    pub tree_tests: bool,
+    pub label_tests: bool,
+    pub link_tests: bool,
+    pub cursor_tests: bool,
+    pub button_icon_tests: bool,
+    pub skin_tests: bool,
+    pub dropdown_multi_select_tests: bool,
+    pub dropdown_typed_tests: bool,
+    pub dropdown_keyboard_tests: bool,
+    pub dropdown_groups_tests: bool,
+    pub dropdown_placement_tests: bool,
     pub all_passed: bool,
 }
 
@@ -18,6 +65,30 @@ impl TestResults {
             checkbox_tests: false,
             dropdown_tests: false,
             style_tests: false,
+            radio_tests: false,
+            toggle_tests: false,
+            combobox_tests: false,
+            tabs_tests: false,
+            window_tests: false,
+            scroll_tests: false,
+            modal_tests: false,
+            tooltip_tests: false,
+            context_menu_tests: false,
+            menu_bar_tests: false,
+            shortcuts_tests: false,
+            listbox_tests: false,
+            table_tests: false,
+            tree_tests: false,
+            label_tests: false,
+            link_tests: false,
+            cursor_tests: false,
+            button_icon_tests: false,
+            skin_tests: false,
+            dropdown_multi_select_tests: false,
+            dropdown_typed_tests: false,
+            dropdown_keyboard_tests: false,
+            dropdown_groups_tests: false,
+            dropdown_placement_tests: false,
             all_passed: false,
         }
     }
@@ -27,7 +98,31 @@ impl TestResults {
                           self.textfield_tests && 
                           self.checkbox_tests && 
                           self.dropdown_tests && 
-                          self.style_tests;
+                          self.style_tests &&
+                          self.radio_tests &&
+                          self.toggle_tests &&
+                          self.combobox_tests &&
+                          self.tabs_tests &&
+                          self.window_tests &&
+                          self.scroll_tests &&
+                          self.modal_tests &&
+                          self.tooltip_tests &&
+                          self.context_menu_tests &&
+                          self.menu_bar_tests &&
+                          self.shortcuts_tests &&
+                          self.listbox_tests &&
+                          self.table_tests &&
+                          self.tree_tests &&
+                          self.label_tests &&
+                          self.link_tests &&
+                          self.cursor_tests &&
+                          self.button_icon_tests &&
+                          self.skin_tests &&
+                          self.dropdown_multi_select_tests &&
+                          self.dropdown_typed_tests &&
+                          self.dropdown_keyboard_tests &&
+                          self.dropdown_groups_tests &&
+                          self.dropdown_placement_tests;
     }
 }
 
@@ -145,6 +240,38 @@ pub fn run_checkbox_tests() -> bool {
         return false;
     }
 
+    // Test 7: Indeterminate state becomes checked when toggled
+    checkbox.set_state(CheckState::Indeterminate);
+    if !checkbox.is_indeterminate() || checkbox.is_checked() {
+        return false;
+    }
+    checkbox.toggle();
+    if checkbox.state != CheckState::Checked {
+        return false;
+    }
+
+    // Test 8: Clicks on the label reach the box, clicks past the label don't
+    let checkbox = Checkbox::new(0.0, 0.0, 20.0, "Label");
+    let label_end = 20.0 + 10.0 + measure_text("Label", checkbox.style.font_size);
+    let hit = checkbox.hit_bounds();
+    if !hit.check_collision_point_rec(Vector2::new(label_end - 1.0, 10.0))
+        || hit.check_collision_point_rec(Vector2::new(label_end + 5.0, 10.0))
+    {
+        return false;
+    }
+
+    // Test 9: Toggling an indeterminate box checks it and writes the binding
+    let shared = Rc::new(Cell::new(false));
+    let mut checkbox = Checkbox::new(0.0, 0.0, 20.0, "All").with_binding(shared.clone());
+    checkbox.set_state(CheckState::Indeterminate);
+    if shared.get() || checkbox.is_checked {
+        return false;
+    }
+    checkbox.toggle();
+    if checkbox.state != CheckState::Checked || !checkbox.is_checked || !shared.get() {
+        return false;
+    }
+
     true
 }
 
@@ -248,6 +375,222 @@ pub fn run_dropdown_tests() -> bool {
     false
 }
 
+pub fn run_dropdown_multi_select_tests() -> bool {
+    let items = vec!["Item 1".to_string(), "Item 2".to_string(), "Item 3".to_string()];
+
+    // Test 1: Multi-select toggling keeps indices sorted
+    let mut multi = Dropdown::new(100.0, 100.0, 200.0, 30.0, items.clone())
+        .with_multi_select(SelectionSummary::Chips);
+    multi.toggle_index(2);
+    multi.toggle_index(0);
+    if multi.selected_indices() != [0, 2] || multi.selected_index != Some(0) {
+        return false;
+    }
+    multi.toggle_index(0);
+    if multi.selected_indices() != [2] {
+        return false;
+    }
+
+    // Test 2: Select all / clear all
+    multi.select_all();
+    if multi.selected_indices() != [0, 1, 2] || multi.get_selected_items().len() != 3 {
+        return false;
+    }
+    multi.clear_selection();
+    if !multi.selected_indices().is_empty() || multi.selected_index.is_some() {
+        return false;
+    }
+
+    // Test 3: The clear row is not an item and does not shift indices
+    let mut multi = Dropdown::new(100.0, 100.0, 200.0, 30.0, items.clone())
+        .with_deselect_option("Clear")
+        .with_multi_select(SelectionSummary::Count);
+    multi.select_all();
+    if multi.items.len() != 3 || multi.selected_indices().len() != 3 || multi.get_selected_items()[0] != "Item 1" {
+        return false;
+    }
+
+    // Test 4: Removing an item shifts the multi-selection
+    let mut multi = Dropdown::new(100.0, 100.0, 200.0, 30.0, items.clone())
+        .with_multi_select(SelectionSummary::Count);
+    multi.toggle_index(1);
+    multi.toggle_index(2);
+    multi.remove_item(1);
+    if multi.selected_indices() != [1] {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_dropdown_typed_tests() -> bool {
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestOption {
+        Option1,
+        Option2,
+        Option3,
+    }
+
+    impl std::fmt::Display for TestOption {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "{:?}", self)
+        }
+    }
+
+    let items = vec![TestOption::Option1, TestOption::Option2, TestOption::Option3];
+    let mut typed = Dropdown::from_enum(100.0, 100.0, 200.0, 30.0, &items);
+
+    // Test 1: Typed selection returns the value itself
+    typed.selected_index = Some(2);
+    if typed.selected_value() != Some(&TestOption::Option3) || typed.get_selected_item() != Some("Option3".to_string()) {
+        return false;
+    }
+
+    // Test 2: Custom display closure and placeholder
+    let numbers = Dropdown::new_with_display(100.0, 100.0, 200.0, 30.0, vec![1, 2, 3], |n| format!("#{}", n))
+        .with_placeholder("Pick a number");
+    if numbers.placeholder != "Pick a number" || numbers.get_selected_item().is_some() || numbers.item_text(0) != Some("#1".to_string()) {
+        return false;
+    }
+
+    // Test 3: An item literally called "None" is a normal item
+    let mut dropdown = Dropdown::new(100.0, 100.0, 200.0, 30.0, vec!["None".to_string(), "Some".to_string()])
+        .with_deselect_option("Any");
+    dropdown.selected_index = Some(0);
+    if dropdown.items.len() != 2 || dropdown.get_selected_item() != Some("None".to_string()) {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_dropdown_keyboard_tests() -> bool {
+    // Test 1: Keyboard hover moves, clamps and scrolls into view
+    let fruits: Vec<String> = ["Apple", "Banana", "Blueberry", "Cherry", "Date", "Fig", "Grape"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let mut keyboard = Dropdown::new(100.0, 100.0, 200.0, 30.0, fruits);
+    keyboard.max_visible_items = 3;
+    keyboard.open();
+    keyboard.move_hover(1);
+    keyboard.move_hover(4);
+    if keyboard.hover_index != Some(4) || keyboard.scroll_offset != 2 {
+        return false;
+    }
+    keyboard.move_hover(-100);
+    if keyboard.hover_index != Some(0) || keyboard.scroll_offset != 0 {
+        return false;
+    }
+
+    // Test 2: Type-ahead cycles single letters and refines longer prefixes
+    keyboard.type_ahead_char('b');
+    if keyboard.hover_index != Some(1) {
+        return false;
+    }
+    keyboard.type_ahead_char('l');
+    if keyboard.hover_index != Some(2) || keyboard.find_prefix("zz", 0).is_some() {
+        return false;
+    }
+
+    // Test 3: Activating the hovered row selects it and closes the list
+    keyboard.activate_hovered();
+    if keyboard.selected_index != Some(2) || keyboard.is_open || !keyboard.type_ahead.is_empty() {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_dropdown_groups_tests() -> bool {
+    // Test 1: Headers and separators are rows but not items
+    let mut assets = Dropdown::new(100.0, 100.0, 200.0, 30.0, Vec::<String>::new())
+        .with_group("Textures", vec!["grass.png".to_string(), "stone.png".to_string()])
+        .with_group("Sounds", vec!["step.wav".to_string()]);
+    assets.add_separator();
+    assets.add_entry(DropdownItem::new("Import...".to_string()).with_secondary_text("Ctrl+I"));
+    if assets.items.len() != 4 || assets.markers().len() != 3 {
+        return false;
+    }
+
+    // Test 2: Keyboard skips headers, separators and disabled items
+    assets.set_item_enabled(1, false);
+    assets.open();
+    assets.move_hover(1);
+    if assets.hover_index != Some(1) {
+        return false;
+    }
+    assets.move_hover(1);
+    assets.activate_hovered();
+    if assets.selected_value().map(|s| s.as_str()) != Some("step.wav") {
+        return false;
+    }
+    assets.open();
+    assets.move_hover(1);
+    if assets.hover_index != Some(6) || assets.find_prefix("stone", 0).is_some() {
+        return false;
+    }
+
+    // Test 3: Disabled items can't be added to a multi-selection
+    assets.multi_select = true;
+    assets.select_all();
+    if assets.selected_indices() != [0, 2, 3] {
+        return false;
+    }
+    assets.toggle_index(1);
+    if assets.selected_indices().contains(&1) {
+        return false;
+    }
+
+    // Test 4: Rows follow items removed and added again behind a header
+    let mut regrouped = Dropdown::new(100.0, 100.0, 200.0, 30.0, Vec::<String>::new())
+        .with_group("A", vec!["x".to_string(), "y".to_string()])
+        .with_group("B", vec!["z".to_string()]);
+    if regrouped.find_prefix("z", 0) != Some(4) {
+        return false;
+    }
+    regrouped.remove_item(0);
+    regrouped.add_item("w".to_string());
+    if regrouped.find_prefix("z", 0) != Some(3) || regrouped.find_prefix("w", 0) != Some(4) {
+        return false;
+    }
+
+    // Test 5: Markers cleared and added back elsewhere move the rows, even at the same count
+    regrouped.clear_markers();
+    regrouped.add_header("Recent");
+    regrouped.add_separator();
+    if regrouped.markers().len() != 2 || regrouped.find_prefix("y", 0) != Some(0) || regrouped.find_prefix("w", 0) != Some(2) {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_dropdown_placement_tests() -> bool {
+    // Test 1: List flips above the box when there is no room below
+    let options: Vec<String> = (1..=8).map(|i| format!("Option {}", i)).collect();
+    let low = Dropdown::new(100.0, 500.0, 200.0, 30.0, options.clone())
+        .with_parent_bounds(Rectangle::new(0.0, 0.0, 800.0, 600.0));
+    if !low.opens_upward() || low.list_bounds().y != 350.0 || low.visible_row_count() != 5 {
+        return false;
+    }
+
+    // Test 2: Item height is independent of the box height
+    let compact = Dropdown::new(100.0, 100.0, 200.0, 30.0, options.clone()).with_item_height(20.0);
+    if compact.opens_upward() || compact.list_bounds().y != 130.0 || compact.list_bounds().height != 100.0 {
+        return false;
+    }
+
+    // Test 3: List is clamped horizontally and shortened to fit its parent
+    let cramped = Dropdown::new(500.0, 10.0, 200.0, 30.0, options)
+        .with_parent_bounds(Rectangle::new(0.0, 0.0, 600.0, 130.0));
+    if cramped.list_bounds().x != 400.0 || cramped.visible_row_count() != 3 {
+        return false;
+    }
+
+    true
+}
+
 pub fn run_style_tests() -> bool {
     // Test 1: Default style
     let style = Style::default();
@@ -306,6 +649,1816 @@ pub fn run_style_tests() -> bool {
     true
 }
 
+pub fn run_radio_tests() -> bool {
+    // Test 1: RadioGroup creation
+    let mut group = RadioGroup::new(100.0, 100.0, 20.0, vec![("Small", 1), ("Medium", 2), ("Large", 3)]);
+    if group.buttons.len() != 3 || group.selected().is_some() {
+        return false;
+    }
+
+    // Test 2: Vertical layout stacks buttons
+    if group.buttons[1].bounds.y != 130.0 || group.buttons[1].bounds.x != 100.0 {
+        return false;
+    }
+
+    // Test 3: Selection is exclusive
+    group.select(0);
+    group.select(2);
+    if group.selected() != Some(&3) || group.buttons[0].is_selected || !group.buttons[2].is_selected {
+        return false;
+    }
+
+    // Test 4: Out of range selection is ignored
+    group.select(10);
+    if group.selected_index != Some(2) {
+        return false;
+    }
+
+    // Test 5: Clear selection
+    group.clear_selection();
+    if group.selected().is_some() || group.buttons.iter().any(|b| b.is_selected) {
+        return false;
+    }
+
+    // Test 6: Horizontal layout keeps buttons on one row
+    let group = RadioGroup::new(0.0, 50.0, 20.0, vec![("A", 'a'), ("B", 'b')])
+        .with_layout(RadioLayout::Horizontal);
+    if group.buttons[1].bounds.y != 50.0 || group.buttons[1].bounds.x <= group.buttons[0].bounds.x {
+        return false;
+    }
+
+    // Test 7: RadioGroup from enum
+    let group = RadioGroup::from_enum(0.0, 0.0, 20.0, vec![1, 2, 3]).with_selected(1);
+    if group.selected_label() != Some(&"2".to_string()) {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_toggle_tests() -> bool {
+    // Test 1: Toggle creation
+    let mut toggle = Toggle::new(100.0, 100.0, 50.0, 26.0, "Sound");
+    if toggle.is_on || !toggle.enabled || toggle.label != "Sound" {
+        return false;
+    }
+
+    // Test 2: Toggle state
+    toggle.toggle();
+    if !toggle.is_on {
+        return false;
+    }
+
+    // Test 3: State text
+    toggle = toggle.with_state_text("ON", "OFF");
+    if toggle.on_text.as_deref() != Some("ON") || toggle.off_text.as_deref() != Some("OFF") {
+        return false;
+    }
+
+    // Test 4: Shared binding between a Checkbox and a Toggle
+    let shared = Rc::new(Cell::new(true));
+    let mut checkbox = Checkbox::new(0.0, 0.0, 20.0, "Sound").with_binding(shared.clone());
+    let mut toggle = Toggle::new(0.0, 0.0, 50.0, 26.0, "Sound").with_binding(shared.clone());
+    if !checkbox.is_checked() || !toggle.is_on {
+        return false;
+    }
+
+    toggle.toggle();
+    if shared.get() {
+        return false;
+    }
+
+    checkbox.toggle();
+    if !shared.get() {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_combobox_tests() -> bool {
+    // Test 1: Matching helpers
+    if prefix_match("ap", "Apple") != Some(vec![0, 1]) || prefix_match("pp", "Apple").is_some() {
+        return false;
+    }
+    if fuzzy_match("ape", "Apple") != Some(vec![0, 1, 4]) || fuzzy_match("xa", "Apple").is_some() {
+        return false;
+    }
+
+    // Test 2: ComboBox creation shows every item
+    let items = vec!["Apple".to_string(), "Apricot".to_string(), "Banana".to_string(), "Grape".to_string()];
+    let mut combo = ComboBox::new(100.0, 100.0, 200.0, 30.0, items.clone());
+    if combo.matches.len() != 4 || combo.get_selected_item().is_some() {
+        return false;
+    }
+
+    // Test 3: Prefix filtering
+    combo.text_field.set_text("ap");
+    combo.refilter();
+    if combo.matches.len() != 2 || combo.matches[1].index != 1 {
+        return false;
+    }
+
+    // Test 4: Fuzzy filtering
+    let mut combo = ComboBox::new(100.0, 100.0, 200.0, 30.0, items).with_match_mode(MatchMode::Fuzzy);
+    combo.text_field.set_text("ae");
+    combo.refilter();
+    if combo.matches.len() != 2 || combo.matches.iter().any(|m| m.index == 2) {
+        return false;
+    }
+
+    // Test 5: Selecting an item fills the text
+    combo.select(2);
+    if combo.get_selected_item() != Some(&"Banana".to_string()) || combo.value() != "Banana" {
+        return false;
+    }
+
+    // Test 6: Clear
+    combo.clear();
+    if !combo.value().is_empty() || combo.selected_index.is_some() || combo.matches.len() != 4 {
+        return false;
+    }
+
+    // Test 7: A mouse resting over the list doesn't undo keyboard hover; moving it does
+    combo.is_open = true;
+    let over_first = Vector2::new(150.0, 145.0);
+    combo.hover_at(over_first);
+    if combo.hover_index != Some(0) {
+        return false;
+    }
+    combo.hover_index = Some(2);
+    combo.hover_at(over_first);
+    if combo.hover_index != Some(2) {
+        return false;
+    }
+    combo.hover_at(Vector2::new(150.0, 175.0));
+    combo.hover_index == Some(1)
+}
+
+pub fn run_tabs_tests() -> bool {
+    // Test 1: TabBar creation activates the first tab
+    let mut bar = TabBar::new(0.0, 0.0, 200.0, 30.0, &["Scene", "Assets", "Console"]).with_closable_tabs();
+    if bar.tabs.len() != 3 || bar.active_index != Some(0) || !bar.tabs.iter().all(|tab| tab.closable) {
+        return false;
+    }
+
+    // Test 2: Tab width stays within the min/max tab width
+    let width = bar.tab_width(0);
+    if width < bar.min_tab_width || width > bar.max_tab_width {
+        return false;
+    }
+
+    // Test 3: Ctrl+Tab cycling wraps around in both directions
+    bar.cycle(-1);
+    if bar.active_index != Some(2) {
+        return false;
+    }
+    bar.cycle(1);
+    if bar.active_index != Some(0) || bar.events.last() != Some(&TabEvent::Selected(0)) {
+        return false;
+    }
+
+    // Test 4: Moving tabs keeps the active tab active
+    bar.move_tab(0, 2);
+    if bar.tabs[2].label != "Scene" || bar.active_index != Some(2) {
+        return false;
+    }
+
+    // Test 5: Closing the active tab activates a neighbour and reports it as selected
+    bar.events.clear();
+    bar.close_tab(2);
+    if bar.tabs.len() != 2 || bar.active_index != Some(1) {
+        return false;
+    }
+    if bar.events != vec![TabEvent::Closed(2), TabEvent::Selected(1)] {
+        return false;
+    }
+    bar.events.clear();
+    bar.close_tab(0);
+    if bar.active_index != Some(0) || bar.events != vec![TabEvent::Closed(0)] {
+        return false;
+    }
+
+    // Test 6: Overflow scrolls to keep the selected tab visible
+    let labels = ["One", "Two", "Three", "Four", "Five", "Six"];
+    let mut bar = TabBar::new(0.0, 0.0, 200.0, 30.0, &labels).with_tab_width(60.0, 60.0);
+    if !bar.overflows() {
+        return false;
+    }
+    bar.select(5);
+    if bar.scroll_offset == 0 || bar.active_label() != Some(&"Six".to_string()) {
+        return false;
+    }
+
+    // Test 7: TabView keeps pages in step with the bar
+    let mut view = TabView::new(0.0, 0.0, 400.0, 300.0, vec![("A", 1), ("B", 2), ("C", 3)]);
+    view.move_page(0, 2);
+    view.close_page(0);
+    if view.pages != vec![3, 1] || view.active_page() != Some(&1) {
+        return false;
+    }
+    if view.bar.events != vec![TabEvent::Moved { from: 0, to: 2 }, TabEvent::Closed(0)] {
+        return false;
+    }
+    view.add_page(Tab::new("D"), 4);
+    view.close_page(5);
+    view.move_page(2, 0);
+    if view.pages != vec![4, 3, 1] || view.bar.tabs.len() != 3 || view.active_page() != Some(&1) {
+        return false;
+    }
+    if view.content_bounds().y != view.bar_height {
+        return false;
+    }
+    if view.page_mouse(Vector2::new(10.0, 10.0)) != NO_MOUSE || view.page_mouse(Vector2::new(10.0, 100.0)).y != 100.0 {
+        return false;
+    }
+
+    // Test 8: Ellipsis truncation shared with Button
+    if truncate_with_ellipsis("Label", 1000.0, 20) != "Label" {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_window_tests() -> bool {
+    let screen = Rectangle::new(0.0, 0.0, 800.0, 600.0);
+
+    // Test 1: Dragging the title bar moves the window and stays on screen
+    let mut window = Window::new(100.0, 100.0, 300.0, 200.0, "Inspector");
+    window.press(Vector2::new(150.0, 110.0));
+    if !window.is_dragging {
+        return false;
+    }
+    window.drag_to(Vector2::new(200.0, 160.0), screen);
+    if window.bounds.x != 150.0 || window.bounds.y != 150.0 {
+        return false;
+    }
+    window.drag_to(Vector2::new(-500.0, 2000.0), screen);
+    if window.bounds.x != 0.0 || window.bounds.y != 600.0 - window.title_bar_height {
+        return false;
+    }
+    window.release();
+
+    // Test 2: Corner resize respects the minimum size
+    let mut window = Window::new(100.0, 100.0, 300.0, 200.0, "Inspector").with_min_size(150.0, 100.0);
+    window.press(Vector2::new(400.0, 300.0));
+    if !window.resize_edges.right || !window.resize_edges.bottom {
+        return false;
+    }
+    window.drag_to(Vector2::new(450.0, 320.0), screen);
+    if window.bounds.width != 350.0 || window.bounds.height != 220.0 {
+        return false;
+    }
+    window.drag_to(Vector2::new(0.0, 0.0), screen);
+    if window.bounds.width != 150.0 || window.bounds.height != 100.0 {
+        return false;
+    }
+    window.release();
+
+    // Test 3: Left edge resize keeps the right edge in place
+    window.press(Vector2::new(100.0, 150.0));
+    window.drag_to(Vector2::new(60.0, 150.0), screen);
+    if window.bounds.x != 60.0 || window.bounds.x + window.bounds.width != 250.0 {
+        return false;
+    }
+    window.release();
+
+    // Test 4: Minimize collapses to the title bar and close hides the window
+    let mut window = Window::new(0.0, 0.0, 300.0, 200.0, "Log");
+    let minimize = window.minimize_button_bounds().unwrap();
+    window.press(Vector2::new(minimize.x + 2.0, minimize.y + 2.0));
+    if !window.is_minimized || window.frame().height != window.title_bar_height {
+        return false;
+    }
+    let close = window.close_button_bounds().unwrap();
+    window.press(Vector2::new(close.x + 2.0, close.y + 2.0));
+    if window.is_open {
+        return false;
+    }
+
+    // Test 5: Children are placed relative to the client area and follow the window
+    let mut window = Window::new(100.0, 100.0, 300.0, 200.0, "Form");
+    let mut button = Button::new(0.0, 0.0, 80.0, 30.0, "OK");
+    window.place(&mut button, 10.0, 10.0);
+    let client = window.client_bounds();
+    if button.bounds.x != client.x + 10.0 || button.bounds.y != client.y + 10.0 {
+        return false;
+    }
+    window.press(Vector2::new(150.0, 110.0));
+    window.drag_to(Vector2::new(170.0, 130.0), screen);
+    window.release();
+    window.place(&mut button, 10.0, 10.0);
+    if button.bounds.x != window.client_bounds().x + 10.0 {
+        return false;
+    }
+
+    // Test 6: Clicking a window brings it to the front
+    let mut stack = WindowStack::new();
+    let back = stack.add(Window::new(0.0, 0.0, 300.0, 200.0, "Back"));
+    let front = stack.add(Window::new(100.0, 100.0, 300.0, 200.0, "Front"));
+    if stack.topmost_at(Vector2::new(150.0, 150.0)) != Some(front) {
+        return false;
+    }
+    stack.press(Vector2::new(50.0, 50.0));
+    if stack.windows.last().map(|window| window.id) != Some(back) || !stack.get(back).unwrap().is_active {
+        return false;
+    }
+
+    // Test 7: Covered or dragged windows don't pass the mouse to their children
+    stack.press(Vector2::new(350.0, 110.0));
+    let mouse = Vector2::new(150.0, 150.0);
+    if stack.child_mouse(back, mouse) == mouse || stack.child_mouse(front, mouse) == mouse {
+        return false;
+    }
+    stack.get_mut(front).unwrap().release();
+    if stack.child_mouse(front, mouse) != mouse {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_scroll_tests() -> bool {
+    // Test 1: Scroll bars appear only for the overflowing axes
+    let mut area = ScrollArea::new(0.0, 0.0, 200.0, 100.0).with_content_size(150.0, 400.0);
+    if area.scrollbar_track(ScrollAxis::Vertical).is_none() || area.scrollbar_track(ScrollAxis::Horizontal).is_some() {
+        return false;
+    }
+    if area.viewport().width != 190.0 || area.max_scroll().y != 300.0 {
+        return false;
+    }
+
+    // Test 2: Offsets are clamped to the content
+    area.set_scroll(Vector2::new(50.0, 1000.0));
+    if area.scroll.x != 0.0 || area.scroll.y != 300.0 {
+        return false;
+    }
+    area.scroll_by(Vector2::new(0.0, -500.0));
+    if area.scroll.y != 0.0 {
+        return false;
+    }
+
+    // Test 3: Children are placed in content coordinates and hidden ones get no mouse
+    let mut button = Button::new(0.0, 0.0, 80.0, 30.0, "Row");
+    area.set_scroll(Vector2::new(0.0, 100.0));
+    area.place(&mut button, 10.0, 50.0);
+    if button.bounds.y != -50.0 {
+        return false;
+    }
+    let hidden = Vector2::new(20.0, -40.0);
+    if area.child_mouse(hidden) == hidden {
+        return false;
+    }
+    let visible = Vector2::new(20.0, 50.0);
+    if area.child_mouse(visible) != visible || area.to_content(visible).y != 150.0 {
+        return false;
+    }
+
+    // Test 4: Scrolling to a widget brings it fully into view
+    area.place(&mut button, 10.0, 350.0);
+    area.scroll_to_widget(&button);
+    if area.scroll.y != 280.0 {
+        return false;
+    }
+
+    // Test 5: Dragging the thumb maps the track onto the scroll range
+    let track = area.scrollbar_track(ScrollAxis::Vertical).unwrap();
+    if !area.press(Vector2::new(track.x + 2.0, track.y + 1.0)) {
+        return false;
+    }
+    area.drag_to(Vector2::new(track.x + 2.0, track.y - 50.0));
+    if area.scroll.y != 0.0 || area.dragging != Some(ScrollAxis::Vertical) {
+        return false;
+    }
+    area.drag_to(Vector2::new(track.x + 2.0, track.y + track.height + 50.0));
+    area.release();
+    if area.scroll.y != 300.0 || area.dragging.is_some() {
+        return false;
+    }
+
+    // Test 6: Smooth scrolling eases towards the target
+    let mut area = ScrollArea::new(0.0, 0.0, 200.0, 100.0)
+        .with_content_size(150.0, 400.0)
+        .with_scroll_mode(ScrollMode::Smooth);
+    area.scroll_by(Vector2::new(0.0, 80.0));
+    area.step(1.0 / 60.0);
+    if area.scroll.y <= 0.0 || area.scroll.y >= 80.0 {
+        return false;
+    }
+    for _ in 0..120 {
+        area.step(1.0 / 60.0);
+    }
+    if area.scroll.y != 80.0 {
+        return false;
+    }
+
+    // Test 7: Kinetic scrolling glides, slows down and stops at the edge
+    let mut area = ScrollArea::new(0.0, 0.0, 200.0, 100.0)
+        .with_content_size(150.0, 400.0)
+        .with_scroll_mode(ScrollMode::Kinetic);
+    area.scroll_by(Vector2::new(0.0, 100.0));
+    area.step(1.0 / 60.0);
+    let first = area.scroll.y;
+    area.step(1.0 / 60.0);
+    if first <= 0.0 || area.scroll.y - first >= first {
+        return false;
+    }
+    area.scroll_by(Vector2::new(0.0, 10000.0));
+    for _ in 0..10 {
+        area.step(1.0 / 60.0);
+    }
+    if area.scroll.y != 300.0 || area.velocity.y != 0.0 {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_modal_tests() -> bool {
+    // Test 1: Dialogs queue up and block the mouse underneath
+    let mut modal = ModalLayer::new();
+    let question = modal.confirm("Did all tests pass?");
+    let notice = modal.alert("Saved");
+    let mouse = Vector2::new(10.0, 10.0);
+    if !modal.is_open() || modal.current().map(|dialog| dialog.id) != Some(question) || modal.child_mouse(mouse) == mouse {
+        return false;
+    }
+
+    // Test 2: Enter picks the default action and the next dialog takes over
+    if modal.accept() != Some((question, DialogResult::Confirm(ConfirmResult::Yes))) {
+        return false;
+    }
+    if modal.current().map(|dialog| dialog.id) != Some(notice) || modal.current().unwrap().buttons.len() != 1 {
+        return false;
+    }
+
+    // Test 3: Esc dismisses an alert and unblocks input
+    if modal.cancel() != Some((notice, DialogResult::Dismissed)) || modal.is_open() || modal.child_mouse(mouse) != mouse {
+        return false;
+    }
+
+    // Test 4: Confirm buttons map to Yes/No/Cancel and Esc cancels
+    let question = modal.confirm("Overwrite?");
+    let dialog = modal.current().unwrap();
+    if dialog.button_result(1) != DialogResult::Confirm(ConfirmResult::No)
+        || dialog.cancel_result() != DialogResult::Confirm(ConfirmResult::Cancel)
+    {
+        return false;
+    }
+    if modal.cancel() != Some((question, DialogResult::Confirm(ConfirmResult::Cancel))) {
+        return false;
+    }
+
+    // Test 5: Prompt returns the typed text, or None when cancelled
+    let name = modal.prompt("Name:", "untitled");
+    let field = modal.dialogs.front_mut().unwrap().input.as_mut().unwrap();
+    if !field.is_active || field.text != "untitled" {
+        return false;
+    }
+    field.set_text("scene.rs");
+    if modal.accept() != Some((name, DialogResult::Prompt(Some("scene.rs".to_string())))) {
+        return false;
+    }
+    modal.prompt("Name:", "");
+    if modal.cancel().map(|(_, result)| result) != Some(DialogResult::Prompt(None)) {
+        return false;
+    }
+
+    // Test 6: Layout centers the dialog and keeps its contents inside
+    modal.prompt("Rename layer\nto:", "");
+    let style = modal.style.clone();
+    let dialog = modal.dialogs.front_mut().unwrap();
+    dialog.layout(Rectangle::new(0.0, 0.0, 800.0, 600.0), &style);
+    let center_x = dialog.bounds.x + dialog.bounds.width / 2.0;
+    let center_y = dialog.bounds.y + dialog.bounds.height / 2.0;
+    if center_x != 400.0 || center_y != 300.0 {
+        return false;
+    }
+    let field = dialog.input.as_ref().unwrap();
+    let last = dialog.buttons.last().unwrap();
+    if field.bounds.y + field.bounds.height > last.bounds.y
+        || last.bounds.x + last.bounds.width > dialog.bounds.x + dialog.bounds.width
+        || last.bounds.y + last.bounds.height > dialog.bounds.y + dialog.bounds.height
+    {
+        return false;
+    }
+    modal.cancel();
+
+    // Test 7: A long message wraps to the dialog's width and the dialog grows to fit
+    modal.alert("Short");
+    let dialog = modal.dialogs.front_mut().unwrap();
+    dialog.layout(Rectangle::new(0.0, 0.0, 800.0, 600.0), &style);
+    let short_height = dialog.bounds.height;
+    modal.cancel();
+    modal.alert("The scene has unsaved changes that will be lost if you close it without saving first");
+    let dialog = modal.dialogs.front_mut().unwrap();
+    dialog.layout(Rectangle::new(0.0, 0.0, 800.0, 600.0), &style);
+    let lines = dialog.message_lines(style.font_size);
+    if lines.len() < 2 || dialog.bounds.height <= short_height {
+        return false;
+    }
+    if lines.iter().any(|line| measure_text(line, style.font_size) > dialog.bounds.width - 24.0) {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_tooltip_tests() -> bool {
+    let style = Style::default();
+    let screen = Rectangle::new(0.0, 0.0, 800.0, 600.0);
+    let anchor = Rectangle::new(100.0, 100.0, 120.0, 40.0);
+    let mouse = Vector2::new(150.0, 120.0);
+
+    // Test 1: Tooltips appear only after the delay and reset when the mouse leaves
+    let mut tooltip = Tooltip::new("Save the file").with_delay(0.5);
+    tooltip.tick(true, mouse, anchor, 0.3);
+    if tooltip.is_visible {
+        return false;
+    }
+    tooltip.tick(true, mouse, anchor, 0.3);
+    if !tooltip.is_visible {
+        return false;
+    }
+    tooltip.tick(false, mouse, anchor, 0.1);
+    if tooltip.is_visible || tooltip.hover_time != 0.0 {
+        return false;
+    }
+
+    // Test 2: Following the mouse places the tooltip below and right of the cursor
+    let bounds = tooltip.tooltip_bounds(&style, screen);
+    if bounds.x <= mouse.x || bounds.y <= mouse.y {
+        return false;
+    }
+
+    // Test 3: Anchored tooltips sit below the widget, or above when asked
+    let below = tooltip.clone().with_placement(TooltipPlacement::Below).tooltip_bounds(&style, screen);
+    let above = tooltip.clone().with_placement(TooltipPlacement::Above).tooltip_bounds(&style, screen);
+    if below.x != anchor.x || below.y < anchor.y + anchor.height || above.y + above.height > anchor.y {
+        return false;
+    }
+
+    // Test 4: Tooltips flip and clamp to stay on screen
+    let corner = Vector2::new(795.0, 595.0);
+    tooltip.tick(true, corner, anchor, 0.0);
+    let bounds = tooltip.tooltip_bounds(&style, screen);
+    if bounds.x + bounds.width > 800.0 || bounds.y + bounds.height > corner.y {
+        return false;
+    }
+
+    // Test 5: Text wraps at newlines into one line each
+    let tooltip = Tooltip::new("Disabled:\nselect a file first");
+    let one_line = Tooltip::new("Disabled").tooltip_bounds(&style, screen);
+    if tooltip.tooltip_bounds(&style, screen).height <= one_line.height {
+        return false;
+    }
+    if wrap_text("a\n\nb", 100.0, 14) != vec!["a", "", "b"] {
+        return false;
+    }
+    if wrap_text("WWWWWWWW", measure_text("WWWW", 14), 14) != vec!["WWWW", "WWWW"] {
+        return false;
+    }
+
+    // Test 6: Every supported widget accepts a tooltip, including disabled buttons, sized to its
+    // wrapped lines and clamped on screen near the corner
+    let mut button = Button::new(0.0, 0.0, 100.0, 30.0, "Export").with_tooltip("Select a file to export");
+    button.enabled = false;
+    let checkbox = Checkbox::new(0.0, 0.0, 20.0, "Wrap").with_tooltip("Wrap long lines");
+    let field = TextField::new(0.0, 0.0, 100.0, 30.0, 10).with_tooltip("File name");
+    let dropdown = Dropdown::new(0.0, 0.0, 100.0, 30.0, vec!["A"]).with_tooltip("Format");
+    let long_word = Tooltip::new("Supercalifragilisticexpialidocious").with_max_width(80.0);
+    let tooltips = [button.tooltip, checkbox.tooltip, field.tooltip, dropdown.tooltip, Some(long_word)];
+    let line_height = style.tooltip_font_size as f32 + 2.0;
+    for tooltip in tooltips {
+        let Some(mut tooltip) = tooltip else {
+            return false;
+        };
+        tooltip.tick(true, corner, anchor, 0.0);
+        let bounds = tooltip.tooltip_bounds(&style, screen);
+        if bounds.x < 0.0 || bounds.y < 0.0 || bounds.x + bounds.width > 800.0 || bounds.y + bounds.height > 600.0 {
+            return false;
+        }
+        let lines = wrap_text(&tooltip.text, tooltip.max_width - style.padding * 2.0, style.tooltip_font_size);
+        if bounds.height != lines.len() as f32 * line_height + style.padding * 2.0 {
+            return false;
+        }
+        if lines.iter().any(|line| measure_text(line, style.tooltip_font_size) > tooltip.max_width - style.padding * 2.0) {
+            return false;
+        }
+    }
+    let long_word = Tooltip::new("Supercalifragilisticexpialidocious").with_max_width(80.0);
+    let lines = wrap_text(&long_word.text, long_word.max_width - style.padding * 2.0, style.tooltip_font_size);
+    if lines.len() < 2 || lines.concat() != long_word.text {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_context_menu_tests() -> bool {
+    let entries = vec![
+        MenuEntry::Item(MenuItem::new("Cut").with_shortcut("Ctrl+X")),
+        MenuEntry::Item(MenuItem::new("Copy").with_shortcut("Ctrl+C")),
+        MenuEntry::Separator,
+        MenuEntry::Item(MenuItem::new("Word wrap").with_checked(false)),
+        MenuEntry::Item(MenuItem::new("Paste").with_enabled(false)),
+        MenuEntry::Item(MenuItem::new("View").with_submenu(vec![
+            MenuEntry::Item(MenuItem::new("Zoom in").with_id("zoom_in")),
+            MenuEntry::Item(MenuItem::new("Zoom out").with_id("zoom_out")),
+        ])),
+    ];
+    let mut menu = ContextMenu::new(Rectangle::new(0.0, 0.0, 400.0, 400.0), entries);
+
+    // Test 1: Opening at a point shows the root menu there
+    menu.open_at(Vector2::new(100.0, 100.0));
+    let root = menu.level_bounds(0);
+    if !menu.is_open || menu.level_count() != 1 || root.x != 100.0 || root.y != 100.0 || root.width < menu.min_width {
+        return false;
+    }
+
+    // Test 2: Keyboard focus skips separators and disabled items
+    menu.move_focus(1);
+    menu.move_focus(1);
+    menu.move_focus(1);
+    if menu.focus_index != Some(3) {
+        return false;
+    }
+    menu.move_focus(1);
+    if menu.focus_index != Some(5) {
+        return false;
+    }
+
+    // Test 3: Right opens a submenu beside its row and Left closes it again
+    menu.open_focused_submenu();
+    let submenu = menu.level_bounds(1);
+    if menu.open_path != vec![5] || menu.focus_index != Some(0) {
+        return false;
+    }
+    if submenu.x < root.x + root.width - 5.0 || submenu.y != menu.row_bounds(0, 5).y {
+        return false;
+    }
+    menu.close_submenu();
+    if !menu.open_path.is_empty() || menu.focus_index != Some(5) {
+        return false;
+    }
+
+    // Test 4: Hovering cascades into the submenu and clicking an item activates it
+    let view_row = menu.row_bounds(0, 5);
+    menu.hover_at(Vector2::new(view_row.x + 10.0, view_row.y + 5.0));
+    if menu.level_count() != 2 {
+        return false;
+    }
+    let zoom_row = menu.row_bounds(1, 0);
+    if !menu.click_at(Vector2::new(zoom_row.x + 10.0, zoom_row.y + 5.0)) {
+        return false;
+    }
+    if menu.events != vec![MenuEvent::Activated("zoom_in".to_string())] || menu.is_open {
+        return false;
+    }
+
+    // Test 5: Checkable items toggle and report their new state
+    menu.events.clear();
+    menu.open_at(Vector2::new(100.0, 100.0));
+    let wrap_row = menu.row_bounds(0, 3);
+    menu.click_at(Vector2::new(wrap_row.x + 10.0, wrap_row.y + 5.0));
+    if menu.events != vec![MenuEvent::Toggled("Word wrap".to_string(), true)] {
+        return false;
+    }
+    if menu.item("Word wrap").and_then(|item| item.checked) != Some(true) {
+        return false;
+    }
+
+    // Test 6: Disabled items do nothing and clicks outside report a miss
+    menu.events.clear();
+    menu.open_at(Vector2::new(100.0, 100.0));
+    let paste_row = menu.row_bounds(0, 4);
+    if !menu.click_at(Vector2::new(paste_row.x + 10.0, paste_row.y + 5.0)) || !menu.events.is_empty() || !menu.is_open {
+        return false;
+    }
+    if menu.click_at(Vector2::new(10.0, 10.0)) {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_menu_bar_tests() -> bool {
+    // Test 1: Shortcut text parses into chords and prints back the same way
+    let save = KeyChord::parse("Ctrl+Shift+S");
+    if save != Some(KeyChord::new(KeyboardKey::KEY_S).with_ctrl().with_shift()) {
+        return false;
+    }
+    if save.map(|chord| chord.to_string()) != Some("Ctrl+Shift+S".to_string()) {
+        return false;
+    }
+    if KeyChord::parse("Ctrl++").map(|chord| chord.key) != Some(KeyboardKey::KEY_EQUAL) {
+        return false;
+    }
+    if KeyChord::parse("Hyper+S").is_some() || KeyChord::parse("Ctrl+Nope").is_some() {
+        return false;
+    }
+
+    // Test 2: '&' marks mnemonics and "&&" is a literal ampersand
+    if parse_mnemonic("&File") != ("File".to_string(), Some(0)) {
+        return false;
+    }
+    if parse_mnemonic("Save && E&xit") != ("Save & Exit".to_string(), Some(8)) {
+        return false;
+    }
+
+    let mut bar = MenuBar::new(0.0, 0.0, 400.0, 24.0, vec![
+        Menu::new("&File", vec![
+            MenuEntry::Item(MenuItem::new("Open").with_id("open").with_mnemonic('o').with_shortcut("Ctrl+O")),
+            MenuEntry::Item(MenuItem::new("Recent").with_submenu(vec![
+                MenuEntry::Item(MenuItem::new("Clear").with_id("clear_recent").with_shortcut("Ctrl+Shift+R")),
+            ])),
+            MenuEntry::Separator,
+            MenuEntry::Item(MenuItem::new("Exit").with_id("exit").with_mnemonic('x')),
+            MenuEntry::Item(MenuItem::new("Save & Exit")),
+        ]),
+        Menu::new("&View", vec![
+            MenuEntry::Item(MenuItem::new("Status bar").with_id("status_bar").with_checked(true).with_shortcut("F9")),
+            MenuEntry::Item(MenuItem::new("Full screen").with_id("full_screen").with_enabled(false).with_shortcut("F11")),
+        ]),
+    ]);
+
+    // Test 3: Item shortcuts are bound globally into the caller's registry, including submenu items,
+    // keeping what was bound there and not doubling up when registered again
+    let mut shortcuts = Shortcuts::defaults();
+    shortcuts.bind("Ctrl+Q", "quit");
+    bar.register_accelerators(&mut shortcuts);
+    let bound = shortcuts.bindings.len();
+    bar.register_accelerators(&mut shortcuts);
+    if shortcuts.bindings.len() != bound || shortcuts.chord_for("quit").is_none() {
+        return false;
+    }
+    if shortcuts.actions_for(KeyChord::new(KeyboardKey::KEY_O).with_ctrl()) != vec!["open"] {
+        return false;
+    }
+    if shortcuts.chord_for("clear_recent").map(|chord| chord.to_string()) != Some("Ctrl+Shift+R".to_string()) {
+        return false;
+    }
+    if bar.menus[1].mnemonic_char() != Some('V') || bar.item("exit").and_then(|item| item.mnemonic_char()) != Some('x') {
+        return false;
+    }
+    if bar.item("open").and_then(|item| item.mnemonic) != Some(0) {
+        return false;
+    }
+    // Item labels are taken as written, so an existing "Save & Exit" keeps its label and id
+    if bar.item("Save & Exit").map(|item| (item.label.as_str(), item.mnemonic)) != Some(("Save & Exit", None)) {
+        return false;
+    }
+
+    // Test 4: Clicking a title opens its menu below it and clicking again closes it
+    let file_title = bar.title_bounds(0);
+    let view_title = bar.title_bounds(1);
+    if view_title.x != file_title.x + file_title.width {
+        return false;
+    }
+    bar.click_at(Vector2::new(file_title.x + 2.0, file_title.y + 2.0));
+    let popup = bar.menus[0].popup.level_bounds(0);
+    if bar.open_index != Some(0) || popup.x != file_title.x || popup.y != bar.bounds.y + bar.bounds.height {
+        return false;
+    }
+    bar.click_at(Vector2::new(file_title.x + 2.0, file_title.y + 2.0));
+    if bar.open_index.is_some() || bar.menus[0].popup.is_open {
+        return false;
+    }
+
+    // Test 5: Switching menus closes the previous one and wraps around
+    bar.open_menu(0);
+    bar.switch_menu(1);
+    if bar.open_index != Some(1) || bar.menus[0].popup.is_open || bar.menus[1].popup.focus_index != Some(0) {
+        return false;
+    }
+    bar.switch_menu(1);
+    if bar.open_index != Some(0) {
+        return false;
+    }
+
+    // Test 6: Clicking an item reports it and closes the bar's menu
+    bar.events.clear();
+    let exit_row = bar.menus[0].popup.row_bounds(0, 3);
+    bar.click_at(Vector2::new(exit_row.x + 10.0, exit_row.y + 5.0));
+    if bar.events != vec![MenuEvent::Activated("exit".to_string())] || bar.open_index.is_some() {
+        return false;
+    }
+
+    // Test 7: Triggering an accelerator toggles checkable items and skips disabled ones
+    bar.events.clear();
+    bar.trigger("status_bar");
+    bar.trigger("full_screen");
+    bar.trigger("clear_recent");
+    let expected = vec![
+        MenuEvent::Toggled("status_bar".to_string(), false),
+        MenuEvent::Activated("clear_recent".to_string()),
+    ];
+    if bar.events != expected || bar.item("status_bar").and_then(|item| item.checked) != Some(false) {
+        return false;
+    }
+
+    // Test 8: One registry arbitrates between accelerators and a focused text field on the same keys
+    let mut edit_bar = MenuBar::new(0.0, 0.0, 400.0, 24.0, vec![Menu::new("&Edit", vec![
+        MenuEntry::Item(MenuItem::new("Copy").with_id("copy_item").with_shortcut("Ctrl+C")),
+    ])]);
+    let mut shortcuts = Shortcuts::defaults();
+    edit_bar.register_accelerators(&mut shortcuts);
+    let mut field = TextField::new(0.0, 40.0, 100.0, 30.0, 20).with_shared_shortcuts();
+    field.set_text("hello");
+    field.activate();
+    field.handle_shortcuts(&mut shortcuts);
+    if shortcuts.focused.as_deref() != Some(TEXT_FIELD_SCOPE) {
+        return false;
+    }
+    let ctrl_a = KeyChord::new(KeyboardKey::KEY_A).with_ctrl();
+    let ctrl_c = KeyChord::new(KeyboardKey::KEY_C).with_ctrl();
+    shortcuts.feed(ctrl_a);
+    field.handle_shortcuts(&mut shortcuts);
+    edit_bar.handle_shortcuts(&shortcuts);
+    if field.selection_anchor != Some(0) || !edit_bar.events.is_empty() {
+        return false;
+    }
+    shortcuts.feed(ctrl_c);
+    edit_bar.handle_shortcuts(&shortcuts);
+    if !edit_bar.events.is_empty() {
+        return false;
+    }
+    field.deactivate();
+    field.handle_shortcuts(&mut shortcuts);
+    shortcuts.feed(ctrl_c);
+    edit_bar.handle_shortcuts(&shortcuts);
+    shortcuts.focused.is_none() && edit_bar.events == vec![MenuEvent::Activated("copy_item".to_string())]
+}
+
+pub fn run_shortcuts_tests() -> bool {
+    let ctrl_k = KeyChord::new(KeyboardKey::KEY_K).with_ctrl();
+    let ctrl_s = KeyChord::new(KeyboardKey::KEY_S).with_ctrl();
+
+    // Test 1: Multi-key sequences fire only once every chord has been typed
+    let mut shortcuts = Shortcuts::new();
+    if !shortcuts.bind("Ctrl+K Ctrl+S", "save_all") || !shortcuts.bind("Ctrl+S", "save") {
+        return false;
+    }
+    if shortcuts.feed(ctrl_k).is_some() || shortcuts.pending != vec![ctrl_k] {
+        return false;
+    }
+    if shortcuts.feed(ctrl_s).as_deref() != Some("save_all") || !shortcuts.pending.is_empty() {
+        return false;
+    }
+    if shortcuts.feed(ctrl_s).as_deref() != Some("save") {
+        return false;
+    }
+
+    // Test 2: A wrong chord abandons the sequence and a pause times it out
+    shortcuts.feed(ctrl_k);
+    if shortcuts.feed(KeyChord::new(KeyboardKey::KEY_Q)).is_some() || !shortcuts.pending.is_empty() {
+        return false;
+    }
+    shortcuts.feed(ctrl_k);
+    shortcuts.tick(shortcuts.sequence_timeout + 0.1);
+    if !shortcuts.pending.is_empty() || shortcuts.feed(ctrl_s).as_deref() != Some("save") {
+        return false;
+    }
+
+    // Test 3: While a sequence is pending an unbound key is fed too, so it can't be typed between the chords
+    let x_only = |key| key == KeyboardKey::KEY_X;
+    let plain = |key| KeyChord::new(key);
+    if shortcuts.pressed_chord(x_only, plain).is_some() {
+        return false;
+    }
+    shortcuts.feed(ctrl_k);
+    let Some(x) = shortcuts.pressed_chord(x_only, plain) else {
+        return false;
+    };
+    if shortcuts.feed(x).is_some() || !shortcuts.pending.is_empty() || shortcuts.feed(ctrl_s).as_deref() != Some("save") {
+        return false;
+    }
+    let ctrl_s_down = |key| key == KeyboardKey::KEY_S;
+    if shortcuts.pressed_chord(ctrl_s_down, |key| KeyChord::new(key).with_ctrl()) != Some(ctrl_s) {
+        return false;
+    }
+
+    // Test 4: Focused and modal scopes are only active at the right times
+    shortcuts.bind_in("Ctrl+S", "save_note", ShortcutScope::Focused("notes".to_string()), 0);
+    shortcuts.bind_in("Escape", "close_dialog", ShortcutScope::Modal, 0);
+    let escape = KeyChord::new(KeyboardKey::KEY_ESCAPE);
+    if shortcuts.feed(escape).is_some() {
+        return false;
+    }
+    shortcuts.focused = Some("notes".to_string());
+    if shortcuts.feed(ctrl_s).as_deref() != Some("save_note") {
+        return false;
+    }
+    shortcuts.modal_open = true;
+    if shortcuts.feed(ctrl_s).is_some() || shortcuts.feed(escape).as_deref() != Some("close_dialog") {
+        return false;
+    }
+    shortcuts.modal_open = false;
+
+    // Test 5: Priority beats scope when bindings conflict
+    shortcuts.bind_in("Ctrl+S", "force_save", ShortcutScope::Global, 10);
+    let resolved: Vec<&str> = shortcuts.resolve(&[ctrl_s]).iter().map(|binding| binding.action.as_str()).collect();
+    if resolved != vec!["force_save", "save_note", "save"] {
+        return false;
+    }
+
+    // Test 6: Keymaps parse scopes, sequences, priorities and comments, and report bad lines
+    let keymap = "# editor keys\nCtrl+K Ctrl+S = save_all\n\n[focused:editor]\nCtrl+D = duplicate 5  # wins\n[modal]\nEnter = accept\n";
+    let Ok(loaded) = Shortcuts::from_keymap(keymap) else {
+        return false;
+    };
+    let expected = vec![
+        Binding { sequence: vec![ctrl_k, ctrl_s], action: "save_all".to_string(), scope: ShortcutScope::Global, priority: 0 },
+        Binding {
+            sequence: vec![KeyChord::new(KeyboardKey::KEY_D).with_ctrl()],
+            action: "duplicate".to_string(),
+            scope: ShortcutScope::Focused("editor".to_string()),
+            priority: 5,
+        },
+        Binding { sequence: vec![KeyChord::new(KeyboardKey::KEY_ENTER)], action: "accept".to_string(), scope: ShortcutScope::Modal, priority: 0 },
+    ];
+    if loaded.bindings != expected {
+        return false;
+    }
+    match Shortcuts::from_keymap("Ctrl+S = save\nCtrl+Nope = oops") {
+        Err(error) if error.line == 2 => {}
+        _ => return false,
+    }
+    if Shortcuts::from_keymap("[sideways]").is_ok() || Shortcuts::from_keymap("Ctrl+S = save soon").is_ok() {
+        return false;
+    }
+
+    // Test 7: Text fields use the default keymap and can be remapped
+    let mut field = TextField::new(0.0, 0.0, 100.0, 30.0, 20);
+    let Some(keymap) = &mut field.keymap else {
+        return false;
+    };
+    if keymap.chord_for("copy") != Some(KeyChord::new(KeyboardKey::KEY_C).with_ctrl()) {
+        return false;
+    }
+    // Holding Shift as well still pastes, as it did before the keymap
+    if keymap.clone().feed(KeyChord::new(KeyboardKey::KEY_V).with_ctrl().with_shift()).as_deref() != Some("paste") {
+        return false;
+    }
+    if !keymap.rebind("select_all", "Ctrl+Shift+A") || keymap.rebind("select_all", "Ctrl+Nope") {
+        return false;
+    }
+    if keymap.feed(KeyChord::new(KeyboardKey::KEY_A).with_ctrl()).is_some() {
+        return false;
+    }
+    if keymap.feed(KeyChord::new(KeyboardKey::KEY_A).with_ctrl().with_shift()).as_deref() != Some("select_all") {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_listbox_tests() -> bool {
+    let files: Vec<String> = (0..1000).map(|i| format!("file_{:04}.txt", i)).collect();
+    let mut list = ListBox::new(10.0, 10.0, 200.0, 240.0, files).with_multi_select(true);
+
+    // Test 1: Only the rows that fit are visible, and they follow the scroll offset
+    if list.visible_row_count() != 10 || list.visible_range() != (0..10) || list.max_scroll() != 990 {
+        return false;
+    }
+    list.scroll_offset = 500;
+    if list.visible_range() != (500..510) || list.row_bounds(499).is_some() {
+        return false;
+    }
+    let row = list.row_bounds(502).unwrap_or(Rectangle::new(0.0, 0.0, 0.0, 0.0));
+    if row.y != 10.0 + 2.0 * list.item_height || list.item_at(Vector2::new(20.0, row.y + 1.0)) != Some(502) {
+        return false;
+    }
+    if list.scrollbar_track().is_none() || row.width >= list.bounds.width {
+        return false;
+    }
+
+    // Test 2: Plain clicks select one item, Ctrl+click toggles, Shift+click selects a range
+    list.scroll_offset = 0;
+    list.click_item(2, false, false, 0.0);
+    list.click_item(5, true, false, 1.0);
+    if list.selected != vec![2, 5] || list.events != vec![ListBoxEvent::SelectionChanged, ListBoxEvent::SelectionChanged] {
+        return false;
+    }
+    list.click_item(8, false, true, 2.0);
+    if list.selected != vec![5, 6, 7, 8] {
+        return false;
+    }
+    list.click_item(2, true, true, 3.0);
+    if list.selected != vec![2, 3, 4, 5, 6, 7, 8] {
+        return false;
+    }
+    list.click_item(5, true, false, 4.0);
+    if list.is_selected(5) || list.selected.len() != 6 {
+        return false;
+    }
+
+    // Test 3: A quick second click on the same item activates it
+    list.events.clear();
+    list.click_item(3, false, false, 10.0);
+    list.click_item(3, false, false, 10.2);
+    if list.events != vec![ListBoxEvent::SelectionChanged, ListBoxEvent::Activated(3)] {
+        return false;
+    }
+    list.events.clear();
+    list.click_item(4, false, false, 20.0);
+    list.click_item(4, false, false, 21.0);
+    if list.events.contains(&ListBoxEvent::Activated(4)) {
+        return false;
+    }
+
+    // Test 4: Keyboard focus moves, extends with Shift and scrolls into view
+    list.move_focus(1, false);
+    list.move_focus(2, true);
+    if list.focus_index != Some(7) || list.selected != vec![5, 6, 7] {
+        return false;
+    }
+    list.move_focus(20, false);
+    if list.focus_index != Some(27) || list.selected != vec![27] || !list.visible_range().contains(&27) {
+        return false;
+    }
+    list.move_focus(5000, false);
+    if list.focus_index != Some(999) || list.scroll_offset != list.max_scroll() {
+        return false;
+    }
+
+    // Test 5: Single-select lists ignore Ctrl and Shift
+    let mut single = ListBox::new(0.0, 0.0, 100.0, 100.0, vec!["a", "b", "c"]);
+    single.click_item(0, false, false, 0.0);
+    single.click_item(2, true, true, 1.0);
+    single.select_all();
+    if single.selected != vec![2] || single.selected_value() != Some(&"c") {
+        return false;
+    }
+
+    // Test 6: Replacing the items clears the selection and focus
+    single.set_items(vec!["x"]);
+    if !single.selected.is_empty() || single.focus_index.is_some() || single.scrollbar_track().is_some() {
+        return false;
+    }
+
+    // Test 7: Home and End with nothing focused go to the first and last item; Up and Down start at the ends
+    let mut fresh = ListBox::new(0.0, 0.0, 100.0, 100.0, vec!["a", "b", "c", "d"]);
+    fresh.move_focus(-(fresh.items.len() as i32), false);
+    if fresh.focus_index != Some(0) {
+        return false;
+    }
+    fresh.focus_index = None;
+    fresh.move_focus(fresh.items.len() as i32, false);
+    if fresh.focus_index != Some(3) || fresh.selected != vec![3] {
+        return false;
+    }
+    fresh.focus_index = None;
+    fresh.move_focus(1, false);
+    if fresh.focus_index != Some(0) {
+        return false;
+    }
+    fresh.focus_index = None;
+    fresh.move_focus(-1, false);
+    if fresh.focus_index != Some(3) {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_table_tests() -> bool {
+    let columns = vec![
+        Column::new("Name", 100.0).with_editor(CellEditor::Text),
+        Column::new("Size", 60.0),
+        Column::new("Kind", 80.0).with_editor(CellEditor::Choice(vec!["Image".to_string(), "Sound".to_string()])),
+        Column::new("Done", 50.0).with_editor(CellEditor::Check).with_sortable(false),
+    ];
+    let rows: Vec<Vec<String>> = (0..5000)
+        .map(|i| vec![format!("asset_{}", i), (i % 7 * 100).to_string(), "Image".to_string(), "false".to_string()])
+        .collect();
+    let mut table = Table::new(0.0, 0.0, 400.0, 268.0, columns).with_rows(rows);
+
+    // Test 1: Only the rows that fit under the frozen header are visible
+    if table.visible_row_count() != 10 || table.visible_range() != (0..10) || table.row_bounds(10).is_some() {
+        return false;
+    }
+    table.scroll_offset = 100;
+    let first = table.row_bounds(100).unwrap_or(Rectangle::new(0.0, 0.0, 0.0, 0.0));
+    if first.y != table.header_height || table.row_at(Vector2::new(5.0, first.y + 1.0)) != Some(100) {
+        return false;
+    }
+    if table.row_at(Vector2::new(5.0, 5.0)).is_some() || table.header_at(Vector2::new(5.0, 5.0)) != Some(0) {
+        return false;
+    }
+
+    // Test 2: Clicking a header sorts by it, numbers by value, and a second click reverses
+    table.press_header(Vector2::new(110.0, 10.0));
+    table.release_header(Vector2::new(110.0, 10.0));
+    if table.sort != Some((1, SortDirection::Ascending)) || table.events != vec![TableEvent::Sorted(1, SortDirection::Ascending)] {
+        return false;
+    }
+    if table.cell(table.display_order()[0], 1) != Some("0") || table.cell(table.display_order()[4999], 1) != Some("600") {
+        return false;
+    }
+    table.toggle_sort(1);
+    if table.sort != Some((1, SortDirection::Descending)) || table.cell(table.display_order()[0], 1) != Some("600") {
+        return false;
+    }
+    if (0..5000).any(|row| table.display_index(row).map(|position| table.display_order()[position]) != Some(row)) {
+        return false;
+    }
+    table.events.clear();
+    table.press_header(Vector2::new(370.0, 10.0));
+    table.release_header(Vector2::new(370.0, 10.0));
+    if !table.events.is_empty() {
+        return false;
+    }
+    table.clear_sort();
+
+    // Test 3: Dragging a header's right edge resizes the column, down to its minimum
+    if table.resize_handle_at(Vector2::new(100.0, 10.0)) != Some(0) {
+        return false;
+    }
+    table.press_header(Vector2::new(100.0, 10.0));
+    table.drag_header(Vector2::new(150.0, 10.0));
+    table.release_header(Vector2::new(150.0, 10.0));
+    if table.columns[0].width != 150.0 || !table.events.contains(&TableEvent::ColumnResized(0)) {
+        return false;
+    }
+    table.press_header(Vector2::new(150.0, 10.0));
+    table.drag_header(Vector2::new(0.0, 10.0));
+    table.release_header(Vector2::new(0.0, 10.0));
+    if table.columns[0].width != table.columns[0].min_width {
+        return false;
+    }
+    table.columns[0].width = 100.0;
+
+    // Test 4: Dragging a header elsewhere reorders the columns without sorting
+    table.events.clear();
+    table.press_header(Vector2::new(50.0, 10.0));
+    table.drag_header(Vector2::new(200.0, 10.0));
+    table.release_header(Vector2::new(200.0, 10.0));
+    if table.column_order != vec![1, 2, 0, 3] || table.events != vec![TableEvent::ColumnMoved { from: 0, to: 2 }] {
+        return false;
+    }
+    if table.header_bounds(0).map(|header| header.x) != Some(140.0) || table.column_at(150.0) != Some(0) {
+        return false;
+    }
+
+    // Test 5: Selection follows the display order and double-clicking a row edits the cell
+    table.sort_by(0, SortDirection::Ascending);
+    table.events.clear();
+    let top = table.display_order()[0];
+    table.click_cell(top, Some(1), 0.0);
+    table.move_selection(1);
+    if table.selected_row != Some(table.display_order()[1]) || table.scroll_offset != 0 {
+        return false;
+    }
+    table.click_cell(top, Some(0), 5.0);
+    table.click_cell(top, Some(0), 5.1);
+    let Some(CellEdit { widget: EditorWidget::Text(field), .. }) = &mut table.editing else {
+        return false;
+    };
+    if field.text != "asset_0" {
+        return false;
+    }
+    field.set_text("hero.png");
+    table.commit_edit();
+    if table.cell(top, 0) != Some("hero.png") || !table.events.contains(&TableEvent::CellEdited { row: top, column: 0, value: "hero.png".to_string() }) {
+        return false;
+    }
+
+    // Test 6: Check cells toggle on click, choices come from a dropdown, and read-only cells can't be edited
+    table.events.clear();
+    table.click_cell(3, Some(3), 10.0);
+    if table.cell(3, 3) != Some("true") || table.editing.is_some() {
+        return false;
+    }
+    if !table.begin_edit(3, 2) {
+        return false;
+    }
+    if let Some(CellEdit { widget: EditorWidget::Choice(dropdown), .. }) = &mut table.editing {
+        dropdown.selected_index = Some(1);
+    }
+    table.commit_edit();
+    if table.cell(3, 2) != Some("Sound") || table.events.len() != 3 {
+        return false;
+    }
+    table.begin_edit(3, 0);
+    table.cancel_edit();
+    if table.begin_edit(3, 1) || table.editing.is_some() || table.cell(3, 0) != Some("asset_3") {
+        return false;
+    }
+
+    // Test 7: Enter commits a text edit, Escape discards one, and other keys leave the editor open
+    table.events.clear();
+    table.begin_edit(3, 0);
+    if let Some(CellEdit { widget: EditorWidget::Text(field), .. }) = &mut table.editing {
+        field.set_text("typed.png");
+    }
+    table.finish_edit(false, false);
+    if table.editing.is_none() {
+        return false;
+    }
+    table.finish_edit(false, true);
+    if table.editing.is_some() || table.cell(3, 0) != Some("typed.png") || table.events.len() != 1 {
+        return false;
+    }
+    table.begin_edit(3, 0);
+    if let Some(CellEdit { widget: EditorWidget::Text(field), .. }) = &mut table.editing {
+        field.set_text("discarded.png");
+    }
+    table.finish_edit(true, false);
+    table.editing.is_none() && table.cell(3, 0) == Some("typed.png")
+}
+
+pub fn run_tree_tests() -> bool {
+    let loads = Rc::new(Cell::new(0));
+    let counter = loads.clone();
+    let roots = vec![
+        TreeNode::new("Documents")
+            .with_checkbox(false)
+            .with_children(vec![TreeNode::new("a.txt").with_checkbox(false), TreeNode::new("b.txt").with_checkbox(false)]),
+        TreeNode::new("Music").with_checkbox(true).with_lazy_children(),
+        TreeNode::new("Notes"),
+    ];
+    let mut tree = TreeView::new(0.0, 0.0, 200.0, 120.0, roots)
+        .with_drag_and_drop(true)
+        .with_loader(move |node| {
+            counter.set(counter.get() + 1);
+            vec![TreeNode::new(&format!("{}/song", node.id)).with_checkbox(false)]
+        });
+
+    // Test 1: Collapsed nodes hide their children; lazy nodes still get an arrow
+    if tree.visible_rows().len() != 3 || !tree.roots[1].has_children() || tree.roots[2].has_children() {
+        return false;
+    }
+    tree.expand(&[0]);
+    let rows = tree.visible_rows();
+    if rows.len() != 5 || rows[1].path != vec![0, 0] || rows[1].depth != 1 || tree.events != vec![TreeEvent::Expanded("Documents".to_string())] {
+        return false;
+    }
+
+    // Test 2: Down moves through visible rows; Left goes to the parent, then collapses it
+    tree.events.clear();
+    tree.move_selection(1);
+    tree.move_selection(1);
+    if tree.selected != Some(vec![0, 0]) || tree.events != vec![TreeEvent::Selected("Documents".to_string()), TreeEvent::Selected("a.txt".to_string())] {
+        return false;
+    }
+    tree.collapse_or_leave();
+    tree.collapse_or_leave();
+    if tree.selected != Some(vec![0]) || tree.roots[0].expanded {
+        return false;
+    }
+    tree.expand_or_enter();
+    tree.expand_or_enter();
+    if !tree.roots[0].expanded || tree.selected != Some(vec![0, 0]) {
+        return false;
+    }
+
+    // Test 3: Collapsing an ancestor pulls the selection up to it
+    tree.collapse(&[0]);
+    if tree.selected != Some(vec![0]) {
+        return false;
+    }
+    tree.expand(&[0]);
+
+    // Test 4: Checking children rolls up to a tri-state parent, and checking the parent sets them all
+    tree.toggle_check(&[0, 0]);
+    if tree.roots[0].checked != Some(CheckState::Indeterminate) {
+        return false;
+    }
+    tree.toggle_check(&[0, 1]);
+    if tree.roots[0].checked != Some(CheckState::Checked) {
+        return false;
+    }
+    tree.events.clear();
+    tree.toggle_check(&[0]);
+    if tree.roots[0].children.iter().any(|child| child.checked != Some(CheckState::Unchecked))
+        || tree.events != vec![TreeEvent::Toggled("Documents".to_string(), CheckState::Unchecked)]
+    {
+        return false;
+    }
+
+    // Test 5: Lazy children load once, on first expand, and follow the parent's check box
+    tree.expand(&[1]);
+    let song = tree.node(&[1, 0]).map(|node| (node.id.clone(), node.checked));
+    if loads.get() != 1 || song != Some(("Music/song".to_string(), Some(CheckState::Checked))) {
+        return false;
+    }
+    tree.collapse(&[1]);
+    tree.expand(&[1]);
+    if loads.get() != 1 || tree.find("Music/song") != Some(vec![1, 0]) {
+        return false;
+    }
+
+    // Test 6: Nodes move before, after or inside others, but never into themselves
+    tree.events.clear();
+    if !tree.move_node(&[2], &[0], DropPosition::Inside) || tree.find("Notes") != Some(vec![0, 2]) {
+        return false;
+    }
+    let moved = TreeEvent::Moved { id: "Notes".to_string(), parent: Some("Documents".to_string()), index: 2 };
+    if tree.events != vec![moved] || tree.selected != Some(vec![0, 2]) {
+        return false;
+    }
+    if tree.move_node(&[0], &[0, 1], DropPosition::After) || tree.move_node(&[0], &[0], DropPosition::Before) {
+        return false;
+    }
+    if !tree.move_node(&[0, 0], &[1], DropPosition::After) || tree.find("a.txt") != Some(vec![2]) || tree.find("Music") != Some(vec![1]) {
+        return false;
+    }
+
+    // Test 7: Moving a checked child out updates the old parent's check box
+    tree.toggle_check(&[0, 0]);
+    if tree.roots[0].checked != Some(CheckState::Checked) {
+        return false;
+    }
+    tree.move_node(&[2], &[0, 0], DropPosition::Before);
+    if tree.roots[0].checked != Some(CheckState::Indeterminate) || tree.find("a.txt") != Some(vec![0, 0]) {
+        return false;
+    }
+    // Dropping a child above its parent shifts the parent, which must still be rolled up
+    let mut shifted = TreeView::new(0.0, 0.0, 200.0, 200.0, vec![
+        TreeNode::new("Other").with_checkbox(false),
+        TreeNode::new("Docs").with_checkbox(false).with_expanded(true).with_children(vec![
+            TreeNode::new("done.txt").with_checkbox(true),
+            TreeNode::new("todo.txt").with_checkbox(false),
+        ]),
+    ]);
+    if !shifted.move_node(&[1, 1], &[0], DropPosition::Before) || shifted.find("Docs") != Some(vec![2]) {
+        return false;
+    }
+    if shifted.roots[2].checked != Some(CheckState::Checked) || shifted.roots[1].checked != Some(CheckState::Unchecked) {
+        return false;
+    }
+
+    // Test 8: Clicking the arrow toggles expansion and clicking the check box toggles it
+    let mut tree = TreeView::new(0.0, 0.0, 200.0, 120.0, vec![
+        TreeNode::new("Root").with_checkbox(false).with_children(vec![TreeNode::new("Leaf").with_checkbox(false)]),
+        TreeNode::new("Other"),
+    ])
+    .with_drag_and_drop(true);
+    let row = tree.row_bounds(0).unwrap_or(Rectangle::new(0.0, 0.0, 0.0, 0.0));
+    let arrow = tree.arrow_bounds(row, 0);
+    tree.press_row(0, Vector2::new(arrow.x + 2.0, arrow.y + 2.0), 0.0);
+    tree.release();
+    let check = tree.check_bounds(row, 0);
+    tree.press_row(0, Vector2::new(check.x + 2.0, check.y + 2.0), 1.0);
+    tree.release();
+    if !tree.roots[0].expanded || tree.roots[0].children[0].checked != Some(CheckState::Checked) || tree.selected.is_some() {
+        return false;
+    }
+
+    // Test 9: A double click on a label activates the node
+    tree.events.clear();
+    tree.press_row(2, Vector2::new(150.0, 50.0), 2.0);
+    tree.release();
+    tree.press_row(2, Vector2::new(150.0, 50.0), 2.2);
+    tree.release();
+    if tree.events != vec![TreeEvent::Selected("Other".to_string()), TreeEvent::Activated("Other".to_string())] {
+        return false;
+    }
+
+    // Test 10: Dragging a row past the threshold shows a drop target and dropping moves the node
+    tree.press_row(2, Vector2::new(150.0, 50.0), 5.0);
+    tree.drag_to(Vector2::new(150.0, 52.0));
+    if tree.is_dragging() {
+        return false;
+    }
+    tree.drag_to(Vector2::new(150.0, 36.0));
+    if tree.drop_target() != Some(&(vec![0, 0], DropPosition::Inside)) {
+        return false;
+    }
+    tree.drag_to(Vector2::new(150.0, 46.0));
+    if tree.drop_target() != Some(&(vec![0, 0], DropPosition::After)) {
+        return false;
+    }
+    tree.release();
+    if tree.find("Other") != Some(vec![0, 1]) || tree.drop_target().is_some() || tree.is_dragging() {
+        return false;
+    }
+
+    // Test 11: A node can't be dropped onto its own subtree
+    tree.press_row(0, Vector2::new(150.0, 10.0), 8.0);
+    tree.drag_to(Vector2::new(150.0, 36.0));
+    tree.release();
+    tree.roots.len() == 1 && tree.find("Root") == Some(vec![0]) && tree.drop_target().is_none()
+}
+
+pub fn run_label_tests() -> bool {
+    // Every character is half the font size wide, so layouts don't depend on a loaded font
+    let measure = |text: &str, span: &TextSpan| text.chars().count() as f32 * span.size.unwrap_or(10) as f32 / 2.0;
+    let area = Rectangle::new(0.0, 0.0, 60.0, 100.0);
+    let layout = |spans: &[TextSpan], align| layout_spans(spans, area, align, true, 4.0, 10, measure);
+
+    // Test 1: Markup splits into formatted spans; unknown tags and "[[" stay as text
+    let spans = parse_markup("plain [b]bold [color=#ff0000]red[/color][/b] [link=https://example.com]go[/link] [[x] [bogus]");
+    let texts: Vec<&str> = spans.iter().map(|span| span.text.as_str()).collect();
+    if texts != vec!["plain ", "bold ", "red", " ", "go", " [x] [bogus]"] {
+        return false;
+    }
+    if spans[0].bold || !spans[1].bold || spans[1].color.is_some() || !spans[2].bold || spans[2].color.is_none() || spans[3].bold {
+        return false;
+    }
+    if spans[4].link.as_deref() != Some("https://example.com") || spans[5].link.is_some() {
+        return false;
+    }
+
+    // Test 2: Closing an outer tag also closes the ones inside it; stray closers and bad values are text
+    let spans = parse_markup("[u][size=20]big[/u]after[/size] [size=x]");
+    if spans.len() != 2 || spans[0].size != Some(20) || !spans[0].underline || spans[1].underline || spans[1].size.is_some() {
+        return false;
+    }
+    if spans[1].text != "after[/size] [size=x]" || parse_color("#12345").is_some() || parse_color("skyblue").is_none() {
+        return false;
+    }
+
+    // Test 3: Words wrap to the width and words of one span share a run
+    let spans = vec![TextSpan::new("hello world foo")];
+    let runs = layout(&spans, TextAlign::Left);
+    let texts: Vec<&str> = runs.iter().map(|run| run.text.as_str()).collect();
+    if texts != vec!["hello world", "foo"] || runs[0].bounds.width != 55.0 || runs[1].bounds.y != 14.0 {
+        return false;
+    }
+
+    // Test 4: Center, right and justified alignment; a paragraph's last line isn't stretched
+    if layout(&spans, TextAlign::Center)[0].bounds.x != 2.5 || layout(&spans, TextAlign::Right)[1].bounds.x != 45.0 {
+        return false;
+    }
+    let runs = layout(&spans, TextAlign::Justify);
+    let placed: Vec<(&str, f32)> = runs.iter().map(|run| (run.text.as_str(), run.bounds.x)).collect();
+    if placed != vec![("hello", 0.0), ("world", 35.0), ("foo", 0.0)] {
+        return false;
+    }
+
+    // Test 5: Mixed sizes share a bottom edge; '\n' always breaks and blank lines keep their height
+    let spans = parse_markup("[size=20]Big[/size] small\n\nend");
+    let runs = layout(&spans, TextAlign::Left);
+    let placed: Vec<(&str, f32, f32)> = runs.iter().map(|run| (run.text.as_str(), run.bounds.x, run.bounds.y)).collect();
+    if placed != vec![("Big", 0.0, 0.0), ("small", 35.0, 10.0), ("end", 0.0, 38.0)] {
+        return false;
+    }
+
+    // Test 6: Without wrapping, lines only break at '\n' and may run past the width
+    let spans = vec![TextSpan::new("a very long line\nnext")];
+    let runs = layout_spans(&spans, area, TextAlign::Left, false, 0.0, 10, measure);
+    if runs.len() != 2 || runs[0].bounds.width != 80.0 || runs[1].bounds.y != 10.0 {
+        return false;
+    }
+
+    // Test 7: Links are found under the mouse, but only within their run
+    let spans = parse_markup("see [link=docs]the docs[/link] here");
+    let runs = layout(&spans, TextAlign::Left);
+    let link = run_at(&runs, Vector2::new(30.0, 5.0)).map(|run| run.span);
+    if link != Some(1) || spans[1].link.as_deref() != Some("docs") || run_at(&runs, Vector2::new(17.0, 5.0)).is_some() {
+        return false;
+    }
+    if run_at(&runs, Vector2::new(5.0, 17.0)).map(|run| run.span) != Some(2) {
+        return false;
+    }
+
+    // Test 8: Words wider than the width break between characters onto lines of their own
+    let spans = vec![TextSpan::new("hi abcdefghijklmnop")];
+    let runs = layout(&spans, TextAlign::Left);
+    let placed: Vec<(&str, f32)> = runs.iter().map(|run| (run.text.as_str(), run.bounds.y)).collect();
+    if placed != vec![("hi", 0.0), ("abcdefghijkl", 14.0), ("mnop", 28.0)] {
+        return false;
+    }
+
+    // Test 9: A centred label wraps into lines, each centred, and the block is centred vertically
+    let size = Style::default().font_size;
+    let width = measure_text("one two", size);
+    let label = Label::new(0.0, 0.0, width, 100.0, "one two three").with_align(TextAlign::Center);
+    let runs = label.layout();
+    let texts: Vec<&str> = runs.iter().map(|run| run.text.as_str()).collect();
+    if texts != vec!["one two", "three"] || label.content_height() != size as f32 * 2.0 + label.line_spacing {
+        return false;
+    }
+    if runs[1].bounds.x != (width - measure_text("three", size)) / 2.0 || runs[0].bounds.y != (100.0 - label.content_height()) / 2.0 {
+        return false;
+    }
+
+    true
+}
+
+pub fn run_link_tests() -> bool {
+    let mut link = Link::new(10.0, 20.0, "Docs", "https://example.com/docs");
+
+    // Test 1: A new link is sized to one line of text and not yet visited
+    if link.label.bounds.height != link.label.style.font_size as f32 || link.label.wrap || link.visited {
+        return false;
+    }
+    if link.color() != link.link_color {
+        return false;
+    }
+
+    // Test 2: Activating reports the target and marks the link visited
+    link.activate();
+    if link.events != vec![LinkEvent::Clicked("https://example.com/docs".to_string())] || !link.visited || !link.is_clicked() {
+        return false;
+    }
+
+    // Test 3: Disabled links ignore activation
+    let mut disabled = Link::new(0.0, 0.0, "Off", "off");
+    disabled.enabled = false;
+    disabled.activate();
+    if !disabled.events.is_empty() || disabled.visited {
+        return false;
+    }
+
+    // Test 4: Bounded moves the label
+    link.set_position(50.0, 60.0);
+    if link.bounds().x != 50.0 || link.label.bounds.y != 60.0 {
+        return false;
+    }
+
+    // Test 5: Rich text links report their target and are remembered as visited
+    let mut text = RichText::new(0.0, 0.0, 200.0, 50.0, "Read [link=guide]the guide[/link] and [link=guide]again[/link]");
+    text.click_link(1);
+    text.click_link(3);
+    text.click_link(0);
+    if text.visited != vec!["guide".to_string()] || text.events != vec![RichTextEvent::LinkClicked("guide".to_string()); 2] {
+        return false;
+    }
+
+    // Test 6: A click only counts when the press and the release are both on the link
+    let mut link = Link::new(0.0, 0.0, "Docs", "docs");
+    let (inside, outside) = (Vector2::new(2.0, 2.0), Vector2::new(-10.0, -10.0));
+    link.handle_mouse(outside, true, true, false);
+    link.handle_mouse(inside, false, true, false);
+    link.handle_mouse(inside, false, false, true);
+    if link.is_clicked() || link.is_pressed {
+        return false;
+    }
+    link.handle_mouse(inside, true, true, false);
+    link.handle_mouse(outside, false, false, true);
+    if link.is_clicked() || link.is_pressed || !link.is_focused {
+        return false;
+    }
+    link.handle_mouse(inside, true, true, false);
+    if !link.is_pressed {
+        return false;
+    }
+    link.handle_mouse(inside, false, false, true);
+    if link.events != vec![LinkEvent::Clicked("docs".to_string())] {
+        return false;
+    }
+
+    // Test 7: Rich text links need the press and the release on the same link
+    let mut text = RichText::new(0.0, 0.0, 400.0, 50.0, "[link=a]first[/link] and [link=b]second[/link]");
+    let runs = text.layout();
+    let centre = |span: usize| {
+        let bounds = runs.iter().find(|run| run.span == span).unwrap().bounds;
+        Vector2::new(bounds.x + bounds.width / 2.0, bounds.y + bounds.height / 2.0)
+    };
+    text.handle_mouse(centre(0), true, false);
+    text.handle_mouse(centre(2), false, true);
+    if !text.events.is_empty() || text.pressed_link.is_some() {
+        return false;
+    }
+    text.handle_mouse(outside, true, false);
+    text.handle_mouse(centre(2), false, true);
+    if !text.events.is_empty() {
+        return false;
+    }
+    text.handle_mouse(centre(2), true, false);
+    text.handle_mouse(centre(2), false, true);
+    text.events == vec![RichTextEvent::LinkClicked("b".to_string())]
+}
+
+pub fn run_cursor_tests() -> bool {
+    let mut resolver = CursorResolver::new();
+    // Clear anything left over from widgets updated earlier
+    resolver.resolve();
+
+    // Test 1: Nothing requested keeps the default cursor without touching raylib
+    if resolver.resolve().is_some() || requested_cursor().is_some() {
+        return false;
+    }
+
+    // Test 2: The last hover request of the frame wins, and is applied once
+    request_cursor(CursorShape::PointingHand);
+    request_cursor(CursorShape::IBeam);
+    if requested_cursor() != Some(CursorShape::IBeam) || resolver.resolve() != Some(CursorShape::IBeam) {
+        return false;
+    }
+    request_cursor(CursorShape::IBeam);
+    if resolver.resolve().is_some() || resolver.current != CursorShape::IBeam {
+        return false;
+    }
+
+    // Test 3: A frame without requests goes back to the default arrow
+    if resolver.resolve() != Some(CursorShape::Default) {
+        return false;
+    }
+
+    // Test 4: A drag's cursor beats hover requests made after it
+    capture_cursor(CursorShape::ResizeEW);
+    request_cursor(CursorShape::PointingHand);
+    if resolver.resolve() != Some(CursorShape::ResizeEW) {
+        return false;
+    }
+    if resolver.resolve() != Some(CursorShape::Default) {
+        return false;
+    }
+
+    // Test 5: Window edges map to resize arrows, diagonal at corners
+    let edges = |left, right, top, bottom| ResizeEdges { left, right, top, bottom }.cursor();
+    if edges(true, false, false, false) != Some(CursorShape::ResizeEW) || edges(false, false, false, true) != Some(CursorShape::ResizeNS) {
+        return false;
+    }
+    if edges(true, false, true, false) != Some(CursorShape::ResizeNWSE) || edges(false, true, false, true) != Some(CursorShape::ResizeNWSE) {
+        return false;
+    }
+    if edges(false, true, true, false) != Some(CursorShape::ResizeNESW) || edges(false, false, false, false).is_some() {
+        return false;
+    }
+
+    // Test 6: Menu items that can be chosen ask for the hand; separators and disabled items don't
+    let centre = |rect: Rectangle| Vector2::new(rect.x + rect.width / 2.0, rect.y + rect.height / 2.0);
+    let mut menu = ContextMenu::new(
+        Rectangle::new(0.0, 0.0, 400.0, 400.0),
+        vec![
+            MenuEntry::Item(MenuItem::new("Cut")),
+            MenuEntry::Separator,
+            MenuEntry::Item(MenuItem::new("Paste").with_enabled(false)),
+        ],
+    );
+    menu.open_at(Vector2::new(10.0, 10.0));
+    menu.request_hover_cursor(centre(menu.row_bounds(0, 0)));
+    if resolver.resolve() != Some(CursorShape::PointingHand) {
+        return false;
+    }
+    menu.request_hover_cursor(centre(menu.row_bounds(0, 1)));
+    menu.request_hover_cursor(centre(menu.row_bounds(0, 2)));
+    if resolver.resolve() != Some(CursorShape::Default) {
+        return false;
+    }
+
+    // Test 7: Scroll thumbs ask for an arrow along their axis, held while dragged over other widgets
+    let mut area = ScrollArea::new(0.0, 0.0, 100.0, 100.0).with_content_size(300.0, 300.0);
+    let vertical = centre(area.scrollbar_thumb(ScrollAxis::Vertical).unwrap());
+    area.request_scrollbar_cursor(vertical);
+    if resolver.resolve() != Some(CursorShape::ResizeNS) {
+        return false;
+    }
+    area.press(vertical);
+    let over_content = Vector2::new(50.0, 50.0);
+    area.drag_to(over_content);
+    area.request_scrollbar_cursor(over_content);
+    request_cursor(CursorShape::PointingHand);
+    if resolver.resolve().is_some() || resolver.current != CursorShape::ResizeNS {
+        return false;
+    }
+    area.release();
+    area.request_scrollbar_cursor(centre(area.scrollbar_thumb(ScrollAxis::Horizontal).unwrap()));
+    if resolver.resolve() != Some(CursorShape::ResizeEW) {
+        return false;
+    }
+
+    // Test 8: ComboBox suggestions ask for the hand, even while the mouse rests on them
+    let mut combo = ComboBox::new(100.0, 100.0, 200.0, 30.0, vec!["Apple".to_string(), "Banana".to_string()]);
+    combo.is_open = true;
+    let over_first = Vector2::new(150.0, 145.0);
+    combo.hover_at(over_first);
+    if resolver.resolve() != Some(CursorShape::PointingHand) {
+        return false;
+    }
+    combo.hover_at(over_first);
+    if requested_cursor() != Some(CursorShape::PointingHand) || resolver.resolve().is_some() {
+        return false;
+    }
+    combo.hover_at(Vector2::new(150.0, 400.0));
+    resolver.resolve() == Some(CursorShape::Default)
+}
+
+pub fn run_button_icon_tests() -> bool {
+    let style = Style::default();
+    let padding = style.padding;
+    let icon = Some(Vector2::new(16.0, 16.0));
+    let bounds = Rectangle::new(0.0, 0.0, 160.0, 40.0);
+
+    // Test 1: Without an icon the label is centered as before
+    let layout = layout_button(bounds, &style, "Save", None, IconPlacement::Left);
+    let width = measure_text("Save", style.font_size);
+    if layout.icon.is_some() || layout.text != "Save" || layout.text_position != Vector2::new((160.0 - width) / 2.0, 10.0) {
+        return false;
+    }
+
+    // Test 2: A left icon comes before the label, the pair centered together
+    let layout = layout_button(bounds, &style, "Save", icon, IconPlacement::Left);
+    let start = (160.0 - (16.0 + padding + width)) / 2.0;
+    if layout.icon != Some(Rectangle::new(start, 12.0, 16.0, 16.0)) || layout.text_position.x != start + 16.0 + padding {
+        return false;
+    }
+
+    // Test 3: A right icon follows the label
+    let layout = layout_button(bounds, &style, "Save", icon, IconPlacement::Right);
+    if layout.text_position.x != start || layout.icon.map(|r| r.x) != Some(start + width + padding) {
+        return false;
+    }
+
+    // Test 4: A top icon sits above the label, shrunk to leave room for it
+    let layout = layout_button(Rectangle::new(0.0, 0.0, 60.0, 60.0), &style, "Go", Some(Vector2::new(32.0, 32.0)), IconPlacement::Top);
+    let room = 60.0 - padding * 3.0 - style.font_size as f32;
+    let Some(icon_rect) = layout.icon else {
+        return false;
+    };
+    if icon_rect.width != room || icon_rect.height != room || layout.text_position.y != icon_rect.y + room + padding {
+        return false;
+    }
+
+    // Test 5: Icon-only buttons center the icon without scaling it up
+    let layout = layout_button(Rectangle::new(0.0, 0.0, 40.0, 40.0), &style, "", Some(Vector2::new(24.0, 24.0)), IconPlacement::Left);
+    if layout.icon != Some(Rectangle::new(8.0, 8.0, 24.0, 24.0)) || !layout.text.is_empty() {
+        return false;
+    }
+
+    // Test 6: Oversized icons shrink to fit, keeping their aspect ratio
+    let layout = layout_button(Rectangle::new(0.0, 0.0, 40.0, 40.0), &style, "", Some(Vector2::new(60.0, 30.0)), IconPlacement::Left);
+    if layout.icon.map(|r| (r.width, r.height)) != Some((30.0, 15.0)) {
+        return false;
+    }
+
+    // Test 7: The icon's width comes out of the room the label is truncated to
+    let label = "A rather long toolbar label";
+    let layout = layout_button(Rectangle::new(0.0, 0.0, 120.0, 40.0), &style, label, icon, IconPlacement::Left);
+    if layout.text != truncate_with_ellipsis(label, 120.0 - padding * 3.0 - 16.0, style.font_size) {
+        return false;
+    }
+
+    // Test 8: Every placement keeps the icon and the label inside the button without overlapping
+    let inside = |rect: Rectangle| {
+        rect.x >= bounds.x && rect.y >= bounds.y && rect.x + rect.width <= bounds.x + bounds.width && rect.y + rect.height <= bounds.y + bounds.height
+    };
+    for placement in [IconPlacement::Left, IconPlacement::Right, IconPlacement::Top] {
+        let layout = layout_button(bounds, &style, "Save", icon, placement);
+        let Some(icon_rect) = layout.icon else {
+            return false;
+        };
+        let text_rect = Rectangle::new(layout.text_position.x, layout.text_position.y, width, style.font_size as f32);
+        if !inside(icon_rect) || !inside(text_rect) || icon_rect.check_collision_recs(&text_rect) {
+            return false;
+        }
+    }
+
+    // Test 9: Each state picks its own icon tint, and disabled beats pressed and hovered
+    let style = Style {
+        icon_tint: Color::WHITE,
+        icon_tint_hover: Color::YELLOW,
+        icon_tint_pressed: Color::ORANGE,
+        icon_tint_disabled: Color::GRAY,
+        ..Style::default()
+    };
+    icon_tint(&style, SkinState::from_flags(false, true, false, true)) == Color::GRAY
+        && icon_tint(&style, SkinState::from_flags(true, true, false, true)) == Color::ORANGE
+        && icon_tint(&style, SkinState::from_flags(true, false, false, true)) == Color::YELLOW
+        && icon_tint(&style, SkinState::from_flags(true, false, false, false)) == Color::WHITE
+}
+
+pub fn run_skin_tests() -> bool {
+    // Test 1: Styles draw procedurally until given a skin
+    if Style::default().skin.is_some() || Style::dark_theme().skin.is_some() {
+        return false;
+    }
+
+    // Test 2: Disabled beats pressed, which beats focused, which beats hovered
+    if SkinState::from_flags(false, true, true, true) != SkinState::Disabled
+        || SkinState::from_flags(true, true, true, true) != SkinState::Pressed
+        || SkinState::from_flags(true, false, true, true) != SkinState::Focused
+        || SkinState::from_flags(true, false, false, true) != SkinState::Hover
+        || SkinState::from_flags(true, false, false, false) != SkinState::Normal
+    {
+        return false;
+    }
+
+    // Test 3: A skin without textures falls back to procedural drawing in every state
+    let skin = Skin::default();
+    let states = [SkinState::Normal, SkinState::Hover, SkinState::Pressed, SkinState::Disabled, SkinState::Focused];
+    if states.iter().any(|&state| skin.texture_for(state).is_some()) {
+        return false;
+    }
+
+    // Test 4: States with their own texture use it untinted
+    let has_texture = |state| matches!(state, SkinState::Normal | SkinState::Pressed);
+    if resolve_state(SkinState::Pressed, has_texture) != Some((SkinState::Pressed, Color::WHITE))
+        || resolve_state(SkinState::Normal, has_texture) != Some((SkinState::Normal, Color::WHITE))
+    {
+        return false;
+    }
+
+    // Test 5: Missing states fall back to the normal texture, faded only when disabled
+    if resolve_state(SkinState::Hover, has_texture) != Some((SkinState::Normal, Color::WHITE))
+        || resolve_state(SkinState::Focused, has_texture) != Some((SkinState::Normal, Color::WHITE))
+        || resolve_state(SkinState::Disabled, has_texture) != Some((SkinState::Normal, SKIN_DISABLED_TINT))
+    {
+        return false;
+    }
+
+    // Test 6: A disabled texture of its own isn't faded, and a skin without a normal texture only has its own states
+    let disabled_only = |state| state == SkinState::Disabled;
+    resolve_state(SkinState::Disabled, disabled_only) == Some((SkinState::Disabled, Color::WHITE))
+        && resolve_state(SkinState::Hover, disabled_only).is_none()
+}
+
 pub fn run_all_tests() -> TestResults {
     let mut results = TestResults::new();
     
@@ -314,6 +2467,30 @@ pub fn run_all_tests() -> TestResults {
     results.checkbox_tests = run_checkbox_tests();
     results.dropdown_tests = run_dropdown_tests();
     results.style_tests = run_style_tests();
+    results.radio_tests = run_radio_tests();
+    results.toggle_tests = run_toggle_tests();
+    results.combobox_tests = run_combobox_tests();
+    results.tabs_tests = run_tabs_tests();
+    results.window_tests = run_window_tests();
+    results.scroll_tests = run_scroll_tests();
+    results.modal_tests = run_modal_tests();
+    results.tooltip_tests = run_tooltip_tests();
+    results.context_menu_tests = run_context_menu_tests();
+    results.menu_bar_tests = run_menu_bar_tests();
+    results.shortcuts_tests = run_shortcuts_tests();
+    results.listbox_tests = run_listbox_tests();
+    results.table_tests = run_table_tests();
+    results.tree_tests = run_tree_tests();
+    results.label_tests = run_label_tests();
+    results.link_tests = run_link_tests();
+    results.cursor_tests = run_cursor_tests();
+    results.button_icon_tests = run_button_icon_tests();
+    results.skin_tests = run_skin_tests();
+    results.dropdown_multi_select_tests = run_dropdown_multi_select_tests();
+    results.dropdown_typed_tests = run_dropdown_typed_tests();
+    results.dropdown_keyboard_tests = run_dropdown_keyboard_tests();
+    results.dropdown_groups_tests = run_dropdown_groups_tests();
+    results.dropdown_placement_tests = run_dropdown_placement_tests();
     
     results.update_all_passed();
     results
@@ -0,0 +1,127 @@
+use raylib::prelude::*;
+use std::ffi::CString;
+
+/// Width of `text` in pixels with the default font
+pub fn measure_text(text: &str, font_size: i32) -> f32 {
+    let cstr = CString::new(text).unwrap_or_default();
+    unsafe { raylib::ffi::MeasureText(cstr.as_ptr(), font_size) as f32 }
+}
+
+/// Letter spacing for `font`, scaled the way raylib's draw_text scales its 10px default font
+fn font_spacing(font: &Font, font_size: i32) -> f32 {
+    font_size as f32 / font.base_size().max(1) as f32
+}
+
+/// Width of `text` in pixels with `font`, or with the default font when None
+pub fn measure_text_in(font: Option<&Font>, text: &str, font_size: i32) -> f32 {
+    match font {
+        Some(font) => font.measure_text(text, font_size as f32, font_spacing(font, font_size)).x,
+        None => measure_text(text, font_size),
+    }
+}
+
+/// Draw `text` with `font`, or with the default font when None
+pub fn draw_text_in(d: &mut impl RaylibDraw, font: Option<&Font>, text: &str, x: f32, y: f32, font_size: i32, color: Color) {
+    match font {
+        Some(font) => d.draw_text_ex(font, text, Vector2::new(x, y), font_size as f32, font_spacing(font, font_size), color),
+        None => d.draw_text(text, x as i32, y as i32, font_size, color),
+    }
+}
+
+/// Shorten `text` with a trailing "..." until it fits in `max_width`
+pub fn truncate_with_ellipsis(text: &str, max_width: f32, font_size: i32) -> String {
+    if measure_text(text, font_size) <= max_width {
+        return text.to_string();
+    }
+
+    let mut truncated = text.to_string();
+    while measure_text(&(truncated.clone() + "..."), font_size) > max_width {
+        truncated.pop();
+        if truncated.is_empty() {
+            break;
+        }
+    }
+    truncated + "..."
+}
+
+/// Split `text` into lines no wider than `max_width`, breaking at spaces and at '\n'.
+/// A single word wider than `max_width` is broken between characters.
+pub fn wrap_text(text: &str, max_width: f32, font_size: i32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split(' ').filter(|word| !word.is_empty()) {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", line, word)
+            };
+            if measure_text(&candidate, font_size) <= max_width {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            // The word doesn't fit on a line of its own either, so break it wherever it overflows
+            for c in word.chars() {
+                line.push(c);
+                if line.chars().count() > 1 && measure_text(&line, font_size) > max_width {
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, c.to_string()));
+                }
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Strip the '&' mnemonic marker from a label like "&File", returning the
+/// label and the char index of the marked letter. "&&" is a literal '&'.
+pub fn parse_mnemonic(text: &str) -> (String, Option<usize>) {
+    let mut label = String::new();
+    let mut mnemonic = None;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.next() {
+                Some('&') => label.push('&'),
+                Some(next) => {
+                    if mnemonic.is_none() {
+                        mnemonic = Some(label.chars().count());
+                    }
+                    label.push(next);
+                }
+                None => label.push('&'),
+            }
+        } else {
+            label.push(c);
+        }
+    }
+    (label, mnemonic)
+}
+
+/// Underline the character at char index `index` of `text` drawn at (`x`, `y`)
+pub fn draw_mnemonic_underline(
+    d: &mut impl RaylibDraw,
+    text: &str,
+    index: usize,
+    x: f32,
+    y: f32,
+    font_size: i32,
+    color: Color,
+) {
+    let Some((start, letter)) = text.char_indices().nth(index) else {
+        return;
+    };
+    let offset = measure_text(&text[..start], font_size);
+    let width = measure_text(&letter.to_string(), font_size);
+    let underline_y = y + font_size as f32 + 1.0;
+    d.draw_line_ex(
+        Vector2::new(x + offset, underline_y),
+        Vector2::new(x + offset + width, underline_y),
+        1.0,
+        color,
+    );
+}
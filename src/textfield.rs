@@ -1,9 +1,16 @@
 use raylib::prelude::*;
+use crate::cursor::{request_cursor, CursorShape};
+use crate::shortcuts::{ShortcutScope, Shortcuts};
+use crate::skin::SkinState;
 use crate::style::Style;
+use crate::tooltip::Tooltip;
 use std::ffi::CString;
 use regex::Regex;
 use arboard::Clipboard;
 
+/// Name text fields use for `ShortcutScope::Focused` bindings, as in the default keymap's `[focused:text_field]`
+pub const TEXT_FIELD_SCOPE: &str = "text_field";
+
 pub struct TextField {
     pub bounds: Rectangle,
     pub text: String,
@@ -11,6 +18,7 @@ pub struct TextField {
     pub max_length: usize,
     pub style: Style,
     pub is_active: bool,
+    pub is_hovered: bool,
     pub cursor_position: usize,
     pub cursor_blink_timer: f32,
     pub backspace_hold_timer: f32,
@@ -27,6 +35,12 @@ pub struct TextField {
     pub arrow_repeat_timer: f32,
     pub arrow_repeat_active: bool,
     pub clipboard: Option<Clipboard>,
+    pub tooltip: Option<Tooltip>,
+    /// Editing bindings the field polls itself: select_all, copy, cut and paste, in the `focused:text_field`
+    /// scope. `None` once the field takes its actions from a shared registry through `handle_shortcuts`
+    pub keymap: Option<Shortcuts>,
+    /// Whether this field set the shared registry's focus, so only it clears it again
+    holds_focus: bool,
 }
 
 impl TextField {
@@ -38,6 +52,7 @@ impl TextField {
             max_length,
             style: Style::default(),
             is_active: false,
+            is_hovered: false,
             cursor_position: 0,
             cursor_blink_timer: 0.0,
             backspace_hold_timer: 0.0,
@@ -54,14 +69,43 @@ impl TextField {
             arrow_repeat_timer: 0.0,
             arrow_repeat_active: false,
             clipboard: Clipboard::new().ok(),
+            tooltip: None,
+            keymap: Some(Self::default_keymap()),
+            holds_focus: false,
         }
     }
 
+    /// The editing bindings from `Shortcuts::defaults()`
+    pub fn default_keymap() -> Shortcuts {
+        let mut keymap = Shortcuts::defaults();
+        keymap.focused = Some(TEXT_FIELD_SCOPE.to_string());
+        keymap
+    }
+
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = style;
         self
     }
 
+    /// Show `text` in a tooltip after hovering
+    pub fn with_tooltip(mut self, text: &str) -> Self {
+        self.tooltip = Some(Tooltip::new(text));
+        self
+    }
+
+    /// Replace the editing bindings, e.g. with `rebind("select_all", "Ctrl+Shift+A")` applied
+    pub fn with_keymap(mut self, mut keymap: Shortcuts) -> Self {
+        keymap.focused = Some(TEXT_FIELD_SCOPE.to_string());
+        self.keymap = Some(keymap);
+        self
+    }
+
+    /// Take editing actions from a shared registry passed to `handle_shortcuts` instead of polling a keymap
+    pub fn with_shared_shortcuts(mut self) -> Self {
+        self.keymap = None;
+        self
+    }
+
     pub fn with_placeholder(mut self, placeholder: &str) -> Self {
         self.placeholder = placeholder.to_string();
         self
@@ -84,6 +128,14 @@ impl TextField {
     }
 
     pub fn update(&mut self, mouse: Vector2, rl: &mut RaylibHandle) {
+        if let Some(tooltip) = &mut self.tooltip {
+            tooltip.update(self.bounds.check_collision_point_rec(mouse), mouse, self.bounds, rl);
+        }
+        self.is_hovered = self.bounds.check_collision_point_rec(mouse);
+        if self.is_hovered {
+            request_cursor(CursorShape::IBeam);
+        }
+
         // Update cursor blink timer
         self.cursor_blink_timer += rl.get_frame_time();
         if self.cursor_blink_timer >= 1.0 {
@@ -197,73 +249,8 @@ impl TextField {
             let ctrl = rl.is_key_down(KeyboardKey::KEY_LEFT_CONTROL) || rl.is_key_down(KeyboardKey::KEY_RIGHT_CONTROL);
             let shift = rl.is_key_down(KeyboardKey::KEY_LEFT_SHIFT) || rl.is_key_down(KeyboardKey::KEY_RIGHT_SHIFT);
 
-            // Ctrl+A: select all
-            if ctrl && rl.is_key_pressed(KeyboardKey::KEY_A) {
-                self.selection_anchor = Some(0);
-                self.cursor_position = self.text.len();
-                self.update_scroll_offset();
-            }
-            // Ctrl+C: copy
-            if ctrl && rl.is_key_pressed(KeyboardKey::KEY_C) {
-                if let Some(anchor) = self.selection_anchor {
-                    if anchor != self.cursor_position {
-                        let (start, end) = if anchor < self.cursor_position {
-                            (anchor, self.cursor_position)
-                        } else {
-                            (self.cursor_position, anchor)
-                        };
-                        if let Some(cb) = self.clipboard.as_mut() {
-                            let _ = cb.set_text(self.text[start..end].to_string());
-                        }
-                    }
-                }
-            }
-            // Ctrl+X: cut
-            if ctrl && rl.is_key_pressed(KeyboardKey::KEY_X) {
-                if let Some(anchor) = self.selection_anchor {
-                    if anchor != self.cursor_position {
-                        let (start, end) = if anchor < self.cursor_position {
-                            (anchor, self.cursor_position)
-                        } else {
-                            (self.cursor_position, anchor)
-                        };
-                        if let Some(cb) = self.clipboard.as_mut() {
-                            let _ = cb.set_text(self.text[start..end].to_string());
-                        }
-                        self.text.replace_range(start..end, "");
-                        self.cursor_position = start;
-                        self.selection_anchor = None;
-                        self.update_scroll_offset();
-                    }
-                }
-            }
-            // Ctrl+V: paste
-            if ctrl && rl.is_key_pressed(KeyboardKey::KEY_V) {
-                let mut paste_text = None;
-                if let Some(cb) = self.clipboard.as_mut() {
-                    if let Ok(paste) = cb.get_text() {
-                        paste_text = Some(paste);
-                    }
-                }
-                if let Some(paste) = paste_text {
-                    // Remove selection if any
-                    if let Some(anchor) = self.selection_anchor {
-                        if anchor != self.cursor_position {
-                            let (start, end) = if anchor < self.cursor_position {
-                                (anchor, self.cursor_position)
-                            } else {
-                                (self.cursor_position, anchor)
-                            };
-                            self.text.replace_range(start..end, "");
-                            self.cursor_position = start;
-                        }
-                    }
-                    let insert = paste.chars().take(self.max_length - self.text.len()).collect::<String>();
-                    self.text.insert_str(self.cursor_position, &insert);
-                    self.cursor_position += insert.len();
-                    self.selection_anchor = None;
-                    self.update_scroll_offset();
-                }
+            if let Some(action) = self.keymap.as_mut().and_then(|keymap| keymap.poll(rl)) {
+                self.apply_action(&action);
             }
 
             // Helper: clear selection if not holding shift
@@ -534,6 +521,97 @@ impl TextField {
         count
     }
 
+    /// Resolve editing keys through a shared registry; call after `shortcuts.poll(rl)` each frame.
+    /// While active the field holds the registry's focus, so its bindings beat global ones on the same keys.
+    pub fn handle_shortcuts(&mut self, shortcuts: &mut Shortcuts) {
+        if !self.is_active {
+            if self.holds_focus && shortcuts.focused.as_deref() == Some(TEXT_FIELD_SCOPE) {
+                shortcuts.focused = None;
+            }
+            self.holds_focus = false;
+            return;
+        }
+        let scope = ShortcutScope::Focused(TEXT_FIELD_SCOPE.to_string());
+        if let Some(action) = shortcuts.fired_in(&scope).map(str::to_string) {
+            self.apply_action(&action);
+        }
+        shortcuts.focused = Some(TEXT_FIELD_SCOPE.to_string());
+        self.holds_focus = true;
+    }
+
+    /// Run an editing action: select_all, copy, cut or paste
+    pub fn apply_action(&mut self, action: &str) {
+
+        // Select all
+        if action == "select_all" {
+            self.selection_anchor = Some(0);
+            self.cursor_position = self.text.len();
+            self.update_scroll_offset();
+        }
+        // Copy
+        if action == "copy" {
+            if let Some(anchor) = self.selection_anchor {
+                if anchor != self.cursor_position {
+                    let (start, end) = if anchor < self.cursor_position {
+                        (anchor, self.cursor_position)
+                    } else {
+                        (self.cursor_position, anchor)
+                    };
+                    if let Some(cb) = self.clipboard.as_mut() {
+                        let _ = cb.set_text(self.text[start..end].to_string());
+                    }
+                }
+            }
+        }
+        // Cut
+        if action == "cut" {
+            if let Some(anchor) = self.selection_anchor {
+                if anchor != self.cursor_position {
+                    let (start, end) = if anchor < self.cursor_position {
+                        (anchor, self.cursor_position)
+                    } else {
+                        (self.cursor_position, anchor)
+                    };
+                    if let Some(cb) = self.clipboard.as_mut() {
+                        let _ = cb.set_text(self.text[start..end].to_string());
+                    }
+                    self.text.replace_range(start..end, "");
+                    self.cursor_position = start;
+                    self.selection_anchor = None;
+                    self.update_scroll_offset();
+                }
+            }
+        }
+        // Paste
+        if action == "paste" {
+            let mut paste_text = None;
+            if let Some(cb) = self.clipboard.as_mut() {
+                if let Ok(paste) = cb.get_text() {
+                    paste_text = Some(paste);
+                }
+            }
+            if let Some(paste) = paste_text {
+                // Remove selection if any
+                if let Some(anchor) = self.selection_anchor {
+                    if anchor != self.cursor_position {
+                        let (start, end) = if anchor < self.cursor_position {
+                            (anchor, self.cursor_position)
+                        } else {
+                            (self.cursor_position, anchor)
+                        };
+                        self.text.replace_range(start..end, "");
+                        self.cursor_position = start;
+                    }
+                }
+                let insert = paste.chars().take(self.max_length - self.text.len()).collect::<String>();
+                self.text.insert_str(self.cursor_position, &insert);
+                self.cursor_position += insert.len();
+                self.selection_anchor = None;
+                self.update_scroll_offset();
+            }
+        }
+    }
+
     fn update_scroll_offset(&mut self) {
         // Use pixel-accurate measurement to ensure the cursor is always visible
         let visible_width = self.bounds.width - self.style.padding * 2.0;
@@ -585,16 +663,19 @@ impl TextField {
     }
 
     pub fn draw(&self, d: &mut impl RaylibDraw) {
-        // Draw background
-        d.draw_rectangle_rec(self.bounds, self.style.background_color);
-        
-        // Draw border (active color if active, normal color if not)
-        let border_color = if self.is_active { 
-            self.style.border_color_active 
-        } else { 
-            self.style.border_color 
-        };
-        d.draw_rectangle_lines_ex(self.bounds, self.style.border_thickness, border_color);
+        let state = SkinState::from_flags(true, false, self.is_active, self.is_hovered);
+        if !self.style.draw_skin(d, self.bounds, state) {
+            // Draw background
+            d.draw_rectangle_rec(self.bounds, self.style.background_color);
+
+            // Draw border (active color if active, normal color if not)
+            let border_color = if self.is_active {
+                self.style.border_color_active
+            } else {
+                self.style.border_color
+            };
+            d.draw_rectangle_lines_ex(self.bounds, self.style.border_thickness, border_color);
+        }
 
         // Draw selection highlight if any
         if let Some(anchor) = self.selection_anchor {
@@ -758,12 +839,28 @@ impl TextField {
         }
     }
     
+    /// Draw the tooltip if it is showing; call after drawing everything else so it stays on top
+    pub fn draw_tooltip(&self, d: &mut impl RaylibDraw) {
+        if let Some(tooltip) = &self.tooltip {
+            tooltip.draw(d, &self.style);
+        }
+    }
+
     pub fn clear(&mut self) {
         self.text.clear();
         self.cursor_position = 0;
         self.scroll_offset = 0;
     }
 
+    /// Replace the text, moving the cursor to the end and dropping any selection
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.chars().take(self.max_length).collect();
+        self.cursor_position = self.text.len();
+        self.selection_anchor = None;
+        self.scroll_offset = 0;
+        self.update_scroll_offset();
+    }
+
     pub fn activate(&mut self) {
         self.is_active = true;
         self.cursor_position = self.text.len();
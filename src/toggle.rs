@@ -0,0 +1,210 @@
+use raylib::prelude::*;
+use crate::cursor::{request_cursor, CursorShape};
+use crate::style::Style;
+use std::cell::Cell;
+use std::rc::Rc;
+
+pub struct Toggle {
+    pub bounds: Rectangle,
+    pub is_on: bool,
+    pub style: Style,
+    pub label: String,
+    pub on_text: Option<String>,
+    pub off_text: Option<String>,
+    pub is_hovered: bool,
+    pub is_focused: bool,
+    pub animation_progress: f32,
+    pub enabled: bool,
+    pub binding: Option<Rc<Cell<bool>>>,
+}
+
+impl Toggle {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, label: &str) -> Self {
+        Self {
+            bounds: Rectangle::new(x, y, width, height),
+            is_on: false,
+            style: Style::default(),
+            label: label.to_string(),
+            on_text: None,
+            off_text: None,
+            is_hovered: false,
+            is_focused: false,
+            animation_progress: 0.0,
+            enabled: true,
+            binding: None,
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Text drawn inside the track, e.g. "ON" / "OFF"
+    pub fn with_state_text(mut self, on_text: &str, off_text: &str) -> Self {
+        self.on_text = Some(on_text.to_string());
+        self.off_text = Some(off_text.to_string());
+        self
+    }
+
+    /// Share the on/off state with other widgets through a common cell
+    pub fn with_binding(mut self, binding: Rc<Cell<bool>>) -> Self {
+        self.is_on = binding.get();
+        self.animation_progress = if self.is_on { 1.0 } else { 0.0 };
+        self.binding = Some(binding);
+        self
+    }
+
+    pub fn set_colors(&mut self, on: Color, off: Color, border: Color, label: Color) {
+        self.style.active_color = on;
+        self.style.background_color = off;
+        self.style.border_color = border;
+        self.style.text_color = label;
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        // Pick up changes made through the binding by other widgets
+        if let Some(binding) = &self.binding {
+            self.is_on = binding.get();
+        }
+
+        if !self.enabled {
+            self.is_hovered = false;
+            self.is_focused = false;
+            if self.bounds.check_collision_point_rec(mouse) {
+                request_cursor(CursorShape::NotAllowed);
+            }
+        } else {
+            self.is_hovered = self.bounds.check_collision_point_rec(mouse);
+            if self.is_hovered {
+                request_cursor(CursorShape::PointingHand);
+            }
+
+            if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+                self.is_focused = self.is_hovered;
+                if self.is_hovered {
+                    self.toggle();
+                }
+            }
+
+            if self.is_focused
+                && (rl.is_key_pressed(KeyboardKey::KEY_SPACE) || rl.is_key_pressed(KeyboardKey::KEY_ENTER))
+            {
+                self.toggle();
+            }
+        }
+
+        // Slide the knob towards its target
+        let animation_speed = 6.0;
+        if self.is_on && self.animation_progress < 1.0 {
+            self.animation_progress += rl.get_frame_time() * animation_speed;
+            if self.animation_progress > 1.0 {
+                self.animation_progress = 1.0;
+            }
+        } else if !self.is_on && self.animation_progress > 0.0 {
+            self.animation_progress -= rl.get_frame_time() * animation_speed;
+            if self.animation_progress < 0.0 {
+                self.animation_progress = 0.0;
+            }
+        }
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        let t = self.animation_progress;
+        let off_color = self.style.background_color;
+        let on_color = self.style.active_color;
+
+        // Track color blends from off to on as the knob slides
+        let track_color = if !self.enabled {
+            self.style.disabled_color
+        } else {
+            Color::new(
+                ((on_color.r as f32 - off_color.r as f32) * t + off_color.r as f32) as u8,
+                ((on_color.g as f32 - off_color.g as f32) * t + off_color.g as f32) as u8,
+                ((on_color.b as f32 - off_color.b as f32) * t + off_color.b as f32) as u8,
+                ((on_color.a as f32 - off_color.a as f32) * t + off_color.a as f32) as u8,
+            )
+        };
+
+        // Draw track as a pill
+        d.draw_rectangle_rounded(self.bounds, 1.0, 16, track_color);
+        let border_color = if self.is_focused {
+            self.style.border_color_active
+        } else if self.is_hovered {
+            self.style.border_color_hover
+        } else {
+            self.style.border_color
+        };
+        d.draw_rectangle_rounded_lines(self.bounds, 1.0, 16, border_color);
+
+        // Draw on/off text on the side the knob is not covering
+        let state_text = if self.is_on { &self.on_text } else { &self.off_text };
+        if let Some(text) = state_text {
+            let font_size = (self.bounds.height * 0.5) as i32;
+            let text_width = unsafe {
+                let cstr = std::ffi::CString::new(text.as_str()).unwrap_or_default();
+                raylib::ffi::MeasureText(cstr.as_ptr(), font_size)
+            } as f32;
+            let text_x = if self.is_on {
+                self.bounds.x + self.bounds.height / 2.0
+            } else {
+                self.bounds.x + self.bounds.width - self.bounds.height / 2.0 - text_width
+            };
+            let text_color = if self.enabled {
+                self.style.text_color_pressed
+            } else {
+                self.style.text_color_disabled
+            };
+            d.draw_text(
+                text,
+                text_x as i32,
+                (self.bounds.y + (self.bounds.height - font_size as f32) / 2.0) as i32,
+                font_size,
+                text_color,
+            );
+        }
+
+        // Draw knob
+        let knob_radius = self.bounds.height / 2.0 - 3.0;
+        let travel = self.bounds.width - self.bounds.height;
+        let knob_center = Vector2::new(
+            self.bounds.x + self.bounds.height / 2.0 + travel * t,
+            self.bounds.y + self.bounds.height / 2.0,
+        );
+        let knob_color = if self.enabled { Color::WHITE } else { self.style.text_color_disabled };
+        d.draw_circle_v(knob_center, knob_radius, knob_color);
+        d.draw_circle_lines_v(knob_center, knob_radius, self.style.border_color);
+
+        // Draw label with proper alignment
+        let label_x = self.bounds.x + self.bounds.width + 10.0;
+        let label_y = self.bounds.y + (self.bounds.height - self.style.font_size as f32) / 2.0;
+        let label_color = if self.enabled {
+            self.style.text_color
+        } else {
+            self.style.text_color_disabled
+        };
+
+        d.draw_text(
+            &self.label,
+            label_x as i32,
+            label_y as i32,
+            self.style.font_size,
+            label_color,
+        );
+    }
+
+    pub fn toggle(&mut self) {
+        // Toggle from the shared value in case another widget changed it this frame
+        if let Some(binding) = &self.binding {
+            self.is_on = binding.get();
+        }
+        self.set_on(!self.is_on);
+    }
+
+    pub fn set_on(&mut self, on: bool) {
+        self.is_on = on;
+        if let Some(binding) = &self.binding {
+            binding.set(on);
+        }
+    }
+}
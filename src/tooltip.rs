@@ -0,0 +1,151 @@
+use crate::style::Style;
+use crate::text::{measure_text, wrap_text};
+use raylib::prelude::*;
+
+/// Gap between the tooltip and the mouse or the widget it belongs to
+const OFFSET: f32 = 6.0;
+/// Extra room below the mouse so the tooltip clears the cursor
+const CURSOR_HEIGHT: f32 = 16.0;
+
+/// Where a tooltip appears
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TooltipPlacement {
+    /// Below and to the right of the mouse, moving with it
+    FollowMouse,
+    /// Under the widget, left-aligned with it
+    Below,
+    /// Over the widget, left-aligned with it
+    Above,
+}
+
+/// Hover text shown after a delay, attached to a widget with `with_tooltip`
+#[derive(Clone, Debug)]
+pub struct Tooltip {
+    pub text: String,
+    pub delay: f32,
+    pub placement: TooltipPlacement,
+    pub max_width: f32,
+    pub hover_time: f32,
+    pub is_visible: bool,
+    mouse: Vector2,
+    anchor: Rectangle,
+    screen_bounds: Rectangle,
+    dismissed: bool,
+}
+
+impl Tooltip {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            delay: 0.5,
+            placement: TooltipPlacement::FollowMouse,
+            max_width: 250.0,
+            hover_time: 0.0,
+            is_visible: false,
+            mouse: Vector2::zero(),
+            anchor: Rectangle::new(0.0, 0.0, 0.0, 0.0),
+            screen_bounds: Rectangle::new(0.0, 0.0, 0.0, 0.0),
+            dismissed: false,
+        }
+    }
+
+    pub fn with_delay(mut self, seconds: f32) -> Self {
+        self.delay = seconds;
+        self
+    }
+
+    pub fn with_placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    pub fn with_max_width(mut self, width: f32) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Called from the owning widget's update with whether the mouse is over it,
+    /// regardless of whether the widget is enabled
+    pub fn update(&mut self, hovered: bool, mouse: Vector2, anchor: Rectangle, rl: &RaylibHandle) {
+        self.screen_bounds = Rectangle::new(0.0, 0.0, rl.get_screen_width() as f32, rl.get_screen_height() as f32);
+        // Clicking hides the tooltip until the mouse leaves and comes back
+        if hovered && rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.dismissed = true;
+        }
+        self.tick(hovered, mouse, anchor, rl.get_frame_time());
+    }
+
+    /// Advance the hover timer by `dt` seconds
+    pub fn tick(&mut self, hovered: bool, mouse: Vector2, anchor: Rectangle, dt: f32) {
+        self.mouse = mouse;
+        self.anchor = anchor;
+        if hovered {
+            self.hover_time += dt;
+            self.is_visible = !self.dismissed && self.hover_time >= self.delay;
+        } else {
+            self.hover_time = 0.0;
+            self.is_visible = false;
+            self.dismissed = false;
+        }
+    }
+
+    fn lines(&self, style: &Style) -> Vec<String> {
+        wrap_text(&self.text, self.max_width - style.padding * 2.0, style.tooltip_font_size)
+    }
+
+    /// Where the tooltip is drawn, flipped and clamped to stay inside `area`
+    pub fn tooltip_bounds(&self, style: &Style, area: Rectangle) -> Rectangle {
+        let lines = self.lines(style);
+        let line_height = style.tooltip_font_size as f32 + 2.0;
+        let text_width = lines
+            .iter()
+            .map(|line| measure_text(line, style.tooltip_font_size))
+            .fold(0.0, f32::max);
+        let width = text_width.min(self.max_width - style.padding * 2.0) + style.padding * 2.0;
+        let height = lines.len() as f32 * line_height + style.padding * 2.0;
+
+        let (x, below, above) = match self.placement {
+            TooltipPlacement::FollowMouse => (
+                self.mouse.x + OFFSET,
+                self.mouse.y + CURSOR_HEIGHT,
+                self.mouse.y - height - OFFSET,
+            ),
+            TooltipPlacement::Below | TooltipPlacement::Above => (
+                self.anchor.x,
+                self.anchor.y + self.anchor.height + OFFSET,
+                self.anchor.y - height - OFFSET,
+            ),
+        };
+        // Prefer the requested side, but flip if it would leave the area
+        let mut y = if self.placement == TooltipPlacement::Above { above } else { below };
+        if self.placement == TooltipPlacement::Above && y < area.y {
+            y = below;
+        } else if self.placement != TooltipPlacement::Above && y + height > area.y + area.height {
+            y = above;
+        }
+
+        let x = x.min(area.x + area.width - width).max(area.x);
+        let y = y.min(area.y + area.height - height).max(area.y);
+        Rectangle::new(x, y, width, height)
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw, style: &Style) {
+        if !self.is_visible || self.text.is_empty() {
+            return;
+        }
+        let bounds = self.tooltip_bounds(style, self.screen_bounds);
+        d.draw_rectangle_rec(bounds, style.tooltip_background_color);
+        d.draw_rectangle_lines_ex(bounds, 1.0, style.tooltip_border_color);
+
+        let line_height = style.tooltip_font_size as f32 + 2.0;
+        for (i, line) in self.lines(style).iter().enumerate() {
+            d.draw_text(
+                line,
+                (bounds.x + style.padding) as i32,
+                (bounds.y + style.padding + i as f32 * line_height) as i32,
+                style.tooltip_font_size,
+                style.tooltip_text_color,
+            );
+        }
+    }
+}
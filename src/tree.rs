@@ -0,0 +1,851 @@
+use crate::checkbox::CheckState;
+use crate::cursor::{request_cursor, CursorShape};
+use crate::dropdown::{
+    drag_scroll, draw_arrow_glyph, draw_check_state_glyph, draw_scrollbar, scrollbar_cursor, scrollbar_thumb,
+    scrollbar_track, start_scroll_drag, ArrowDirection, SCROLLBAR_WIDTH,
+};
+use crate::scroll::ScrollAxis;
+use crate::style::Style;
+use crate::text::truncate_with_ellipsis;
+use raylib::prelude::*;
+use std::ops::Range;
+
+/// How far the mouse has to move with a row held before it is dragged
+const DRAG_THRESHOLD: f32 = 5.0;
+
+/// A node and its children
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeNode {
+    pub id: String,
+    pub label: String,
+    pub children: Vec<TreeNode>,
+    pub expanded: bool,
+    /// Children haven't been fetched yet; the tree's loader fills them in on first expand
+    pub lazy: bool,
+    /// Check box state, or None for nodes without one
+    pub checked: Option<CheckState>,
+}
+
+impl TreeNode {
+    /// A node whose id is its label
+    pub fn new(label: &str) -> Self {
+        Self {
+            id: label.to_string(),
+            label: label.to_string(),
+            children: Vec::new(),
+            expanded: false,
+            lazy: false,
+            checked: None,
+        }
+    }
+
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<TreeNode>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Load this node's children through `TreeView::with_loader` when it is first expanded
+    pub fn with_lazy_children(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    /// Give the node a check box
+    pub fn with_checkbox(mut self, checked: bool) -> Self {
+        self.checked = Some(if checked { CheckState::Checked } else { CheckState::Unchecked });
+        self
+    }
+
+    /// Whether the node shows an expand arrow
+    pub fn has_children(&self) -> bool {
+        !self.children.is_empty() || self.lazy
+    }
+
+    fn set_checked_recursive(&mut self, state: CheckState) {
+        if self.checked.is_some() {
+            self.checked = Some(state);
+        }
+        for child in &mut self.children {
+            child.set_checked_recursive(state);
+        }
+    }
+
+    /// Checked or unchecked when every child with a check box agrees, mixed otherwise
+    fn rolled_up_state(&self) -> Option<CheckState> {
+        let mut states = self.children.iter().filter_map(|child| child.checked);
+        let first = states.next()?;
+        Some(if states.all(|state| state == first) { first } else { CheckState::Indeterminate })
+    }
+}
+
+/// A row on screen: the node's path from the roots and its depth
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeRow {
+    pub path: Vec<usize>,
+    pub depth: usize,
+}
+
+/// Where a dragged node lands relative to the row it is dropped on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DropPosition {
+    Before,
+    Inside,
+    After,
+}
+
+/// What happened in a tree during the last update; nodes are named by id
+#[derive(Clone, Debug, PartialEq)]
+pub enum TreeEvent {
+    Selected(String),
+    /// A node was double-clicked or Enter was pressed on it
+    Activated(String),
+    Expanded(String),
+    Collapsed(String),
+    Toggled(String, CheckState),
+    /// A node was dropped under a new parent (None for the top level) at `index`
+    Moved { id: String, parent: Option<String>, index: usize },
+}
+
+/// Fetches the children of a lazy node
+pub type NodeLoader = Box<dyn FnMut(&TreeNode) -> Vec<TreeNode>>;
+
+struct TreeDrag {
+    path: Vec<usize>,
+    press: Vector2,
+    active: bool,
+}
+
+/// A hierarchy of expandable nodes with selection, check boxes and drag-and-drop
+pub struct TreeView {
+    pub bounds: Rectangle,
+    pub roots: Vec<TreeNode>,
+    pub style: Style,
+    pub row_height: f32,
+    /// Horizontal offset per level
+    pub indent: f32,
+    pub selected: Option<Vec<usize>>,
+    /// Visible row under the mouse
+    pub hover_row: Option<usize>,
+    /// First visible row
+    pub scroll_offset: usize,
+    pub is_focused: bool,
+    pub is_scrolling: bool,
+    pub drag_and_drop: bool,
+    pub double_click_time: f64,
+    pub events: Vec<TreeEvent>,
+    pub loader: Option<NodeLoader>,
+    drag: Option<TreeDrag>,
+    drop_target: Option<(Vec<usize>, DropPosition)>,
+    last_click: Option<(Vec<usize>, f64)>,
+    scroll_grab: f32,
+}
+
+impl TreeView {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, roots: Vec<TreeNode>) -> Self {
+        Self {
+            bounds: Rectangle::new(x, y, width, height),
+            roots,
+            style: Style::default(),
+            row_height: 24.0,
+            indent: 18.0,
+            selected: None,
+            hover_row: None,
+            scroll_offset: 0,
+            is_focused: false,
+            is_scrolling: false,
+            drag_and_drop: false,
+            double_click_time: 0.4,
+            events: Vec::new(),
+            loader: None,
+            drag: None,
+            drop_target: None,
+            last_click: None,
+            scroll_grab: 0.0,
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_row_height(mut self, height: f32) -> Self {
+        self.row_height = height;
+        self
+    }
+
+    pub fn with_indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Let nodes be dragged onto, before or after other nodes
+    pub fn with_drag_and_drop(mut self, enabled: bool) -> Self {
+        self.drag_and_drop = enabled;
+        self
+    }
+
+    /// Called with a lazy node the first time it is expanded; returns its children
+    pub fn with_loader(mut self, loader: impl FnMut(&TreeNode) -> Vec<TreeNode> + 'static) -> Self {
+        self.loader = Some(Box::new(loader));
+        self
+    }
+
+    pub fn node(&self, path: &[usize]) -> Option<&TreeNode> {
+        let (first, rest) = path.split_first()?;
+        rest.iter().try_fold(self.roots.get(*first)?, |node, &index| node.children.get(index))
+    }
+
+    pub fn node_mut(&mut self, path: &[usize]) -> Option<&mut TreeNode> {
+        let (first, rest) = path.split_first()?;
+        rest.iter().try_fold(self.roots.get_mut(*first)?, |node, &index| node.children.get_mut(index))
+    }
+
+    /// The children of the node at `parent`, or the roots for an empty path
+    fn children_mut(&mut self, parent: &[usize]) -> Option<&mut Vec<TreeNode>> {
+        if parent.is_empty() {
+            Some(&mut self.roots)
+        } else {
+            self.node_mut(parent).map(|node| &mut node.children)
+        }
+    }
+
+    /// Path of the node with `id`, searching collapsed nodes too
+    pub fn find(&self, id: &str) -> Option<Vec<usize>> {
+        fn search(nodes: &[TreeNode], id: &str, path: &mut Vec<usize>) -> bool {
+            for (index, node) in nodes.iter().enumerate() {
+                path.push(index);
+                if node.id == id || search(&node.children, id, path) {
+                    return true;
+                }
+                path.pop();
+            }
+            false
+        }
+
+        let mut path = Vec::new();
+        search(&self.roots, id, &mut path).then_some(path)
+    }
+
+    pub fn selected_node(&self) -> Option<&TreeNode> {
+        self.selected.as_deref().and_then(|path| self.node(path))
+    }
+
+    /// Rows for every node whose ancestors are all expanded, top to bottom
+    pub fn visible_rows(&self) -> Vec<TreeRow> {
+        fn collect(nodes: &[TreeNode], path: &mut Vec<usize>, rows: &mut Vec<TreeRow>) {
+            for (index, node) in nodes.iter().enumerate() {
+                path.push(index);
+                rows.push(TreeRow {
+                    path: path.clone(),
+                    depth: path.len() - 1,
+                });
+                if node.expanded {
+                    collect(&node.children, path, rows);
+                }
+                path.pop();
+            }
+        }
+
+        let mut rows = Vec::new();
+        collect(&self.roots, &mut Vec::new(), &mut rows);
+        rows
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        self.events.clear();
+        let hovered = self.bounds.check_collision_point_rec(mouse);
+
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.is_focused = hovered;
+            if self.scrollbar_track().is_some_and(|track| track.check_collision_point_rec(mouse)) {
+                self.start_scroll_drag(mouse);
+            } else if let Some(row) = self.row_at(mouse) {
+                self.press_row(row, mouse, rl.get_time());
+            }
+        }
+
+        if rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT) {
+            if self.is_scrolling {
+                self.drag_scroll(mouse);
+            }
+            self.drag_to(mouse);
+        }
+        if rl.is_mouse_button_released(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.is_scrolling = false;
+            self.release();
+        }
+
+        if hovered {
+            let wheel_move = rl.get_mouse_wheel_move() as i32;
+            if wheel_move != 0 {
+                self.scroll_offset = (self.scroll_offset as i32 - wheel_move).clamp(0, self.max_scroll() as i32) as usize;
+            }
+        }
+        let row_count = self.visible_rows().len();
+        self.hover_row = if self.is_scrolling { None } else { self.row_at_with(mouse, row_count) };
+        if self.hover_row.is_some() && !self.is_dragging() {
+            request_cursor(CursorShape::PointingHand);
+        }
+        if let Some(track) = self.scrollbar_track_with(row_count) {
+            scrollbar_cursor(ScrollAxis::Vertical, self.scrollbar_thumb(track, row_count), mouse, self.is_scrolling);
+        }
+
+        if self.is_focused {
+            self.handle_keyboard(rl);
+        }
+    }
+
+    /// Up/Down, Page Up/Down and Home/End move the selection; Right expands or enters a node,
+    /// Left collapses it or goes to its parent; Space toggles the check box and Enter activates
+    pub fn handle_keyboard(&mut self, rl: &RaylibHandle) {
+        let key_down = |key| rl.is_key_pressed(key) || rl.is_key_pressed_repeat(key);
+        let page = self.visible_row_count().max(1) as i32;
+
+        if key_down(KeyboardKey::KEY_DOWN) {
+            self.move_selection(1);
+        } else if key_down(KeyboardKey::KEY_UP) {
+            self.move_selection(-1);
+        } else if key_down(KeyboardKey::KEY_PAGE_DOWN) {
+            self.move_selection(page);
+        } else if key_down(KeyboardKey::KEY_PAGE_UP) {
+            self.move_selection(-page);
+        } else if rl.is_key_pressed(KeyboardKey::KEY_HOME) {
+            self.move_selection(i32::MIN / 2);
+        } else if rl.is_key_pressed(KeyboardKey::KEY_END) {
+            self.move_selection(i32::MAX / 2);
+        } else if key_down(KeyboardKey::KEY_RIGHT) {
+            self.expand_or_enter();
+        } else if key_down(KeyboardKey::KEY_LEFT) {
+            self.collapse_or_leave();
+        }
+
+        if let Some(path) = self.selected.clone() {
+            if rl.is_key_pressed(KeyboardKey::KEY_SPACE) {
+                self.toggle_check(&path);
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_ENTER) {
+                self.activate(&path);
+            }
+        }
+    }
+
+    /// React to a press on the visible row `row`: the arrow expands or collapses, the check box
+    /// toggles, anything else selects; a quick second click activates
+    pub fn press_row(&mut self, row: usize, mouse: Vector2, time: f64) {
+        let rows = self.visible_rows();
+        let Some(TreeRow { path, depth }) = rows.get(row).cloned() else {
+            return;
+        };
+        let Some(bounds) = self.row_bounds_with(row, rows.len()) else {
+            return;
+        };
+        let Some(node) = self.node(&path) else {
+            return;
+        };
+
+        if node.has_children() && self.arrow_bounds(bounds, depth).check_collision_point_rec(mouse) {
+            self.toggle_expanded(&path);
+            return;
+        }
+        if node.checked.is_some() && self.check_bounds(bounds, depth).check_collision_point_rec(mouse) {
+            self.toggle_check(&path);
+            return;
+        }
+
+        self.select(&path);
+        let double_click = self
+            .last_click
+            .as_ref()
+            .is_some_and(|(last, at)| *last == path && time - at <= self.double_click_time);
+        if double_click {
+            self.activate(&path);
+            self.last_click = None;
+        } else {
+            self.last_click = Some((path.clone(), time));
+        }
+
+        if self.drag_and_drop {
+            self.drag = Some(TreeDrag {
+                path,
+                press: mouse,
+                active: false,
+            });
+        }
+    }
+
+    /// Follow the mouse while a node is held, updating the drop target
+    pub fn drag_to(&mut self, mouse: Vector2) {
+        let Some(drag) = &mut self.drag else {
+            return;
+        };
+        if !drag.active {
+            drag.active = (mouse.x - drag.press.x).abs() >= DRAG_THRESHOLD || (mouse.y - drag.press.y).abs() >= DRAG_THRESHOLD;
+        }
+        if drag.active {
+            self.drop_target = self.drop_target_at(mouse);
+        }
+    }
+
+    /// Drop a dragged node on the current drop target
+    pub fn release(&mut self) {
+        let drag = self.drag.take();
+        let target = self.drop_target.take();
+        if let (Some(drag), Some((target, position))) = (drag, target) {
+            if drag.active {
+                self.move_node(&drag.path, &target, position);
+            }
+        }
+    }
+
+    /// Where a node dragged to `point` would land; None over its own subtree
+    pub fn drop_target_at(&self, point: Vector2) -> Option<(Vec<usize>, DropPosition)> {
+        let dragged = &self.drag.as_ref()?.path;
+        let rows = self.visible_rows();
+        let row = self.row_at_with(point, rows.len())?;
+        let bounds = self.row_bounds_with(row, rows.len())?;
+        let target = rows.get(row)?.path.clone();
+        if target.starts_with(dragged) {
+            return None;
+        }
+        let offset = (point.y - bounds.y) / bounds.height;
+        let position = if offset < 0.25 {
+            DropPosition::Before
+        } else if offset > 0.75 {
+            DropPosition::After
+        } else {
+            DropPosition::Inside
+        };
+        Some((target, position))
+    }
+
+    pub fn drop_target(&self) -> Option<&(Vec<usize>, DropPosition)> {
+        self.drop_target.as_ref()
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag.as_ref().is_some_and(|drag| drag.active)
+    }
+
+    /// Move the node at `from` relative to `target`; returns false if either path is invalid
+    /// or `target` is inside the moved node
+    pub fn move_node(&mut self, from: &[usize], target: &[usize], position: DropPosition) -> bool {
+        if from.is_empty() || target.starts_with(from) || self.node(from).is_none() || self.node(target).is_none() {
+            return false;
+        }
+        if position == DropPosition::Inside {
+            self.expand(target);
+        }
+
+        let (from_parent, from_index) = (&from[..from.len() - 1], from[from.len() - 1]);
+        let Some(node) = self.children_mut(from_parent).map(|siblings| siblings.remove(from_index)) else {
+            return false;
+        };
+        // Roll up the old parent while `from_parent` still names it; inserting can shift it
+        self.roll_up(from_parent);
+
+        // Removing the node shifts its later siblings, and their descendants, up by one
+        let mut target = target.to_vec();
+        if target.len() > from_parent.len() && target.starts_with(from_parent) && target[from_parent.len()] > from_index {
+            target[from_parent.len()] -= 1;
+        }
+
+        let (parent, index) = match position {
+            DropPosition::Inside => {
+                let count = self.node(&target).map_or(0, |node| node.children.len());
+                (target, count)
+            }
+            DropPosition::Before | DropPosition::After => {
+                let index = target[target.len() - 1] + usize::from(position == DropPosition::After);
+                target.pop();
+                (target, index)
+            }
+        };
+
+        let id = node.id.clone();
+        let Some(siblings) = self.children_mut(&parent) else {
+            return false;
+        };
+        siblings.insert(index, node);
+
+        let mut new_path = parent.clone();
+        new_path.push(index);
+        self.selected = Some(new_path);
+        self.roll_up(&parent);
+        self.events.push(TreeEvent::Moved {
+            id,
+            parent: self.node(&parent).map(|node| node.id.clone()),
+            index,
+        });
+        true
+    }
+
+    pub fn select(&mut self, path: &[usize]) {
+        let Some(id) = self.node(path).map(|node| node.id.clone()) else {
+            return;
+        };
+        if self.selected.as_deref() != Some(path) {
+            self.selected = Some(path.to_vec());
+            self.events.push(TreeEvent::Selected(id));
+        }
+        if let Some(row) = self.visible_rows().iter().position(|row| row.path == path) {
+            self.scroll_to(row);
+        }
+    }
+
+    pub fn activate(&mut self, path: &[usize]) {
+        if let Some(node) = self.node(path) {
+            self.events.push(TreeEvent::Activated(node.id.clone()));
+        }
+    }
+
+    /// Move the selection `delta` visible rows
+    pub fn move_selection(&mut self, delta: i32) {
+        let rows = self.visible_rows();
+        if rows.is_empty() {
+            return;
+        }
+        let current = self
+            .selected
+            .as_ref()
+            .and_then(|selected| rows.iter().position(|row| row.path == *selected));
+        let row = match current {
+            Some(current) => (current as i64 + delta as i64).clamp(0, rows.len() as i64 - 1) as usize,
+            None if delta < 0 => rows.len() - 1,
+            None => 0,
+        };
+        self.select(&rows[row].path);
+    }
+
+    /// Right arrow: expand the selected node, or step into its first child if already expanded
+    pub fn expand_or_enter(&mut self) {
+        let Some(path) = self.selected.clone() else {
+            return self.move_selection(1);
+        };
+        let Some(node) = self.node(&path) else {
+            return;
+        };
+        if !node.has_children() {
+            return;
+        }
+        if node.expanded {
+            if !node.children.is_empty() {
+                let mut child = path;
+                child.push(0);
+                self.select(&child);
+            }
+        } else {
+            self.expand(&path);
+        }
+    }
+
+    /// Left arrow: collapse the selected node, or step out to its parent if already collapsed
+    pub fn collapse_or_leave(&mut self) {
+        let Some(path) = self.selected.clone() else {
+            return self.move_selection(1);
+        };
+        if self.node(&path).is_some_and(|node| node.expanded && node.has_children()) {
+            self.collapse(&path);
+        } else if path.len() > 1 {
+            self.select(&path[..path.len() - 1]);
+        }
+    }
+
+    /// Show a node's children, loading them first if the node is lazy
+    pub fn expand(&mut self, path: &[usize]) {
+        if self.node(path).is_some_and(|node| node.lazy) {
+            // Take the loader out so it can borrow the node
+            let mut loader = self.loader.take();
+            let children = match (&mut loader, self.node(path)) {
+                (Some(loader), Some(node)) => loader(node),
+                _ => Vec::new(),
+            };
+            self.loader = loader;
+            let Some(node) = self.node_mut(path) else {
+                return;
+            };
+            node.children = children;
+            node.lazy = false;
+            // Loaded check boxes start out matching a fully checked or unchecked parent
+            if let Some(state @ (CheckState::Checked | CheckState::Unchecked)) = node.checked {
+                node.set_checked_recursive(state);
+            }
+        }
+
+        let Some(node) = self.node_mut(path) else {
+            return;
+        };
+        if !node.expanded && !node.children.is_empty() {
+            node.expanded = true;
+            let id = node.id.clone();
+            self.events.push(TreeEvent::Expanded(id));
+        }
+    }
+
+    /// Hide a node's children, moving the selection up to it if it was inside
+    pub fn collapse(&mut self, path: &[usize]) {
+        let Some(node) = self.node_mut(path) else {
+            return;
+        };
+        if !node.expanded {
+            return;
+        }
+        node.expanded = false;
+        let id = node.id.clone();
+        self.events.push(TreeEvent::Collapsed(id));
+        if self
+            .selected
+            .as_ref()
+            .is_some_and(|selected| selected.len() > path.len() && selected.starts_with(path))
+        {
+            self.select(path);
+        }
+    }
+
+    pub fn toggle_expanded(&mut self, path: &[usize]) {
+        if self.node(path).is_some_and(|node| node.expanded) {
+            self.collapse(path);
+        } else {
+            self.expand(path);
+        }
+    }
+
+    /// Check or uncheck a node and everything under it, then update its ancestors
+    pub fn toggle_check(&mut self, path: &[usize]) {
+        let Some(node) = self.node_mut(path) else {
+            return;
+        };
+        let Some(current) = node.checked else {
+            return;
+        };
+        let state = if current == CheckState::Checked {
+            CheckState::Unchecked
+        } else {
+            CheckState::Checked
+        };
+        node.set_checked_recursive(state);
+        let id = node.id.clone();
+        self.roll_up(&path[..path.len() - 1]);
+        self.events.push(TreeEvent::Toggled(id, state));
+    }
+
+    /// Recompute the check state of the node at `path` and each of its ancestors from their children
+    fn roll_up(&mut self, path: &[usize]) {
+        for depth in (1..=path.len()).rev() {
+            let Some(node) = self.node_mut(&path[..depth]) else {
+                continue;
+            };
+            if node.checked.is_some() {
+                if let Some(state) = node.rolled_up_state() {
+                    node.checked = Some(state);
+                }
+            }
+        }
+    }
+
+    pub fn visible_row_count(&self) -> usize {
+        ((self.bounds.height / self.row_height).floor() as usize).max(1)
+    }
+
+    /// Rows on screen, as indices into `visible_rows()`
+    pub fn visible_range(&self) -> Range<usize> {
+        self.visible_range_with(self.visible_rows().len())
+    }
+
+    /// `visible_range` for `row_count` visible rows, so callers can flatten the tree once
+    fn visible_range_with(&self, row_count: usize) -> Range<usize> {
+        let end = (self.scroll_offset + self.visible_row_count()).min(row_count);
+        self.scroll_offset.min(end)..end
+    }
+
+    pub fn max_scroll(&self) -> usize {
+        self.max_scroll_with(self.visible_rows().len())
+    }
+
+    fn max_scroll_with(&self, row_count: usize) -> usize {
+        row_count.saturating_sub(self.visible_row_count())
+    }
+
+    /// Scroll just enough for visible row `row` to be on screen
+    pub fn scroll_to(&mut self, row: usize) {
+        if row < self.scroll_offset {
+            self.scroll_offset = row;
+        } else if row >= self.scroll_offset + self.visible_row_count() {
+            self.scroll_offset = row + 1 - self.visible_row_count();
+        }
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll());
+    }
+
+    /// Bounds of visible row `row`, if it is on screen
+    pub fn row_bounds(&self, row: usize) -> Option<Rectangle> {
+        self.row_bounds_with(row, self.visible_rows().len())
+    }
+
+    fn row_bounds_with(&self, row: usize, row_count: usize) -> Option<Rectangle> {
+        if !self.visible_range_with(row_count).contains(&row) {
+            return None;
+        }
+        let width = self.bounds.width - if self.max_scroll_with(row_count) > 0 { SCROLLBAR_WIDTH } else { 0.0 };
+        Some(Rectangle::new(
+            self.bounds.x,
+            self.bounds.y + (row - self.scroll_offset) as f32 * self.row_height,
+            width,
+            self.row_height,
+        ))
+    }
+
+    pub fn row_at(&self, point: Vector2) -> Option<usize> {
+        self.row_at_with(point, self.visible_rows().len())
+    }
+
+    fn row_at_with(&self, point: Vector2, row_count: usize) -> Option<usize> {
+        self.visible_range_with(row_count).find(|&row| {
+            self.row_bounds_with(row, row_count)
+                .is_some_and(|bounds| bounds.check_collision_point_rec(point))
+        })
+    }
+
+    /// The expand arrow's hit area in a row at `depth`
+    pub fn arrow_bounds(&self, row: Rectangle, depth: usize) -> Rectangle {
+        let x = row.x + self.style.padding + depth as f32 * self.indent;
+        Rectangle::new(x, row.y, self.row_height * 0.75, row.height)
+    }
+
+    /// The check box's hit area in a row at `depth`, right of the arrow
+    pub fn check_bounds(&self, row: Rectangle, depth: usize) -> Rectangle {
+        let arrow = self.arrow_bounds(row, depth);
+        Rectangle::new(arrow.x + arrow.width, row.y, self.row_height * 0.75, row.height)
+    }
+
+    /// Track along the right edge, present only when the rows scroll
+    pub fn scrollbar_track(&self) -> Option<Rectangle> {
+        self.scrollbar_track_with(self.visible_rows().len())
+    }
+
+    fn scrollbar_track_with(&self, row_count: usize) -> Option<Rectangle> {
+        if self.max_scroll_with(row_count) == 0 {
+            return None;
+        }
+        Some(scrollbar_track(self.bounds))
+    }
+
+    fn scrollbar_thumb(&self, track: Rectangle, row_count: usize) -> Rectangle {
+        let visible = self.visible_row_count() as f32;
+        scrollbar_thumb(ScrollAxis::Vertical, track, visible, row_count as f32, self.scroll_offset as f32)
+    }
+
+    /// Grab the thumb where it was clicked, or centre it on the click when the track was hit
+    fn start_scroll_drag(&mut self, mouse: Vector2) {
+        let row_count = self.visible_rows().len();
+        let Some(track) = self.scrollbar_track_with(row_count) else {
+            return;
+        };
+        self.scroll_grab = start_scroll_drag(ScrollAxis::Vertical, self.scrollbar_thumb(track, row_count), mouse);
+        self.is_scrolling = true;
+        self.drag_scroll(mouse);
+    }
+
+    fn drag_scroll(&mut self, mouse: Vector2) {
+        let row_count = self.visible_rows().len();
+        let Some(track) = self.scrollbar_track_with(row_count) else {
+            return;
+        };
+        let thumb = self.scrollbar_thumb(track, row_count);
+        let max_scroll = self.max_scroll_with(row_count) as f32;
+        self.scroll_offset = drag_scroll(ScrollAxis::Vertical, track, thumb, self.scroll_grab, mouse, max_scroll).round() as usize;
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        d.draw_rectangle_rec(self.bounds, self.style.background_color);
+
+        // Flatten the tree once for the whole frame
+        let rows = self.visible_rows();
+        for row in self.visible_range_with(rows.len()) {
+            let (Some(bounds), Some(node)) = (self.row_bounds_with(row, rows.len()), self.node(&rows[row].path)) else {
+                continue;
+            };
+            let depth = rows[row].depth;
+            let selected = self.selected.as_ref() == Some(&rows[row].path);
+            if selected {
+                d.draw_rectangle_rec(bounds, self.style.pressed_color);
+            } else if self.hover_row == Some(row) && !self.is_dragging() {
+                d.draw_rectangle_rec(bounds, self.style.hover_color);
+            }
+            let text_color = if selected {
+                self.style.text_color_pressed
+            } else {
+                self.style.text_color
+            };
+
+            let arrow = self.arrow_bounds(bounds, depth);
+            if node.has_children() {
+                let size = self.row_height * 0.3;
+                let direction = if node.expanded { ArrowDirection::Down } else { ArrowDirection::Right };
+                draw_arrow_glyph(
+                    d,
+                    Rectangle::new(arrow.x + (arrow.width - size) / 2.0, arrow.y + (arrow.height - size) / 2.0, size, size),
+                    direction,
+                    text_color,
+                );
+            }
+
+            let mut label_x = arrow.x + arrow.width;
+            if let Some(state) = node.checked {
+                let check = self.check_bounds(bounds, depth);
+                // The glyph helper indents by the padding, so start it that much to the left
+                draw_check_state_glyph(d, &self.style, Rectangle::new(check.x - self.style.padding, check.y, check.width, check.height), state);
+                label_x = check.x + check.width;
+            }
+
+            let label = truncate_with_ellipsis(&node.label, bounds.x + bounds.width - label_x - self.style.padding, self.style.font_size);
+            d.draw_text(
+                &label,
+                label_x as i32,
+                (bounds.y + (bounds.height - self.style.font_size as f32) / 2.0) as i32,
+                self.style.font_size,
+                text_color,
+            );
+        }
+
+        // Show where a dragged node would land
+        if let Some((target, position)) = &self.drop_target {
+            let row = rows.iter().position(|row| row.path == *target);
+            if let Some(bounds) = row.and_then(|row| self.row_bounds_with(row, rows.len())) {
+                let x = self.arrow_bounds(bounds, target.len() - 1).x;
+                match position {
+                    DropPosition::Inside => d.draw_rectangle_lines_ex(bounds, 2.0, self.style.border_color_active),
+                    DropPosition::Before | DropPosition::After => {
+                        let y = if *position == DropPosition::Before { bounds.y } else { bounds.y + bounds.height };
+                        d.draw_line_ex(
+                            Vector2::new(x, y),
+                            Vector2::new(bounds.x + bounds.width, y),
+                            2.0,
+                            self.style.border_color_active,
+                        );
+                    }
+                }
+            }
+        }
+
+        if let Some(track) = self.scrollbar_track_with(rows.len()) {
+            draw_scrollbar(d, &self.style, track, self.scrollbar_thumb(track, rows.len()), self.is_scrolling);
+        }
+
+        let border_color = if self.is_focused {
+            self.style.border_color_active
+        } else {
+            self.style.border_color
+        };
+        d.draw_rectangle_lines_ex(self.bounds, self.style.border_thickness, border_color);
+    }
+}
@@ -0,0 +1,518 @@
+use crate::cursor::{capture_cursor, request_cursor, CursorShape};
+use crate::layout::{Bounded, NO_MOUSE};
+use crate::style::Style;
+use crate::text::truncate_with_ellipsis;
+use raylib::prelude::*;
+
+const RESIZE_HANDLE: f32 = 6.0;
+
+/// Which edges of the window a resize drag moves
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ResizeEdges {
+    pub left: bool,
+    pub right: bool,
+    pub top: bool,
+    pub bottom: bool,
+}
+
+impl ResizeEdges {
+    pub fn any(&self) -> bool {
+        self.left || self.right || self.top || self.bottom
+    }
+
+    /// The resize arrow for these edges, diagonal at corners
+    pub fn cursor(&self) -> Option<CursorShape> {
+        match (self.left || self.right, self.top || self.bottom) {
+            (true, true) if self.left == self.top => Some(CursorShape::ResizeNWSE),
+            (true, true) => Some(CursorShape::ResizeNESW),
+            (true, false) => Some(CursorShape::ResizeEW),
+            (false, true) => Some(CursorShape::ResizeNS),
+            (false, false) => None,
+        }
+    }
+}
+
+/// A floating panel with a title bar, resize handles and minimize/close buttons
+pub struct Window {
+    pub id: usize,
+    pub bounds: Rectangle,
+    pub title: String,
+    pub style: Style,
+    pub title_bar_height: f32,
+    pub min_size: Vector2,
+    pub is_open: bool,
+    pub is_minimized: bool,
+    pub closable: bool,
+    pub minimizable: bool,
+    pub resizable: bool,
+    pub is_active: bool,
+    pub is_covered: bool,
+    pub is_dragging: bool,
+    pub resize_edges: ResizeEdges,
+    pub hover_close: bool,
+    pub hover_minimize: bool,
+    press_position: Vector2,
+    press_bounds: Rectangle,
+}
+
+impl Window {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, title: &str) -> Self {
+        Self {
+            id: 0,
+            bounds: Rectangle::new(x, y, width, height),
+            title: title.to_string(),
+            style: Style::default(),
+            title_bar_height: 28.0,
+            min_size: Vector2::new(120.0, 80.0),
+            is_open: true,
+            is_minimized: false,
+            closable: true,
+            minimizable: true,
+            resizable: true,
+            is_active: false,
+            is_covered: false,
+            is_dragging: false,
+            resize_edges: ResizeEdges::default(),
+            hover_close: false,
+            hover_minimize: false,
+            press_position: Vector2::zero(),
+            press_bounds: Rectangle::new(x, y, width, height),
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_min_size(mut self, width: f32, height: f32) -> Self {
+        self.min_size = Vector2::new(width, height);
+        self
+    }
+
+    pub fn with_title_bar_height(mut self, height: f32) -> Self {
+        self.title_bar_height = height;
+        self
+    }
+
+    pub fn with_closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    pub fn with_minimizable(mut self, minimizable: bool) -> Self {
+        self.minimizable = minimizable;
+        self
+    }
+
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        let screen = Rectangle::new(0.0, 0.0, rl.get_screen_width() as f32, rl.get_screen_height() as f32);
+
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.press(mouse);
+        }
+        if rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT) {
+            self.drag_to(mouse, screen);
+        } else {
+            self.release();
+        }
+
+        if let Some(shape) = self.resize_edges.cursor() {
+            capture_cursor(shape);
+        } else if let Some(shape) = self.edges_at(mouse).cursor() {
+            request_cursor(shape);
+        }
+
+        self.hover_close = self.close_button_bounds().is_some_and(|rect| rect.check_collision_point_rec(mouse));
+        self.hover_minimize = self.minimize_button_bounds().is_some_and(|rect| rect.check_collision_point_rec(mouse));
+    }
+
+    /// Handle a left-button press; returns true if it landed on the window
+    pub fn press(&mut self, mouse: Vector2) -> bool {
+        if !self.is_open || !self.frame().check_collision_point_rec(mouse) && !self.edges_at(mouse).any() {
+            return false;
+        }
+
+        if self.close_button_bounds().is_some_and(|rect| rect.check_collision_point_rec(mouse)) {
+            self.is_open = false;
+            return true;
+        }
+        if self.minimize_button_bounds().is_some_and(|rect| rect.check_collision_point_rec(mouse)) {
+            self.is_minimized = !self.is_minimized;
+            return true;
+        }
+
+        self.press_position = mouse;
+        self.press_bounds = self.bounds;
+        let edges = self.edges_at(mouse);
+        if edges.any() {
+            self.resize_edges = edges;
+        } else if self.title_bar_bounds().check_collision_point_rec(mouse) {
+            self.is_dragging = true;
+        }
+        true
+    }
+
+    /// Continue a move or resize started by `press`, keeping the title bar inside `area`
+    pub fn drag_to(&mut self, mouse: Vector2, area: Rectangle) {
+        let dx = mouse.x - self.press_position.x;
+        let dy = mouse.y - self.press_position.y;
+        let start = self.press_bounds;
+
+        if self.is_dragging {
+            let max_x = (area.x + area.width - start.width).max(area.x);
+            let max_y = (area.y + area.height - self.title_bar_height).max(area.y);
+            self.bounds.x = (start.x + dx).clamp(area.x, max_x);
+            self.bounds.y = (start.y + dy).clamp(area.y, max_y);
+            return;
+        }
+
+        let edges = self.resize_edges;
+        if edges.left {
+            let dx = dx.min(start.width - self.min_size.x);
+            self.bounds.x = start.x + dx;
+            self.bounds.width = start.width - dx;
+        } else if edges.right {
+            self.bounds.width = (start.width + dx).max(self.min_size.x);
+        }
+        if edges.top {
+            let dy = dy.min(start.height - self.min_size.y);
+            self.bounds.y = start.y + dy;
+            self.bounds.height = start.height - dy;
+        } else if edges.bottom {
+            self.bounds.height = (start.height + dy).max(self.min_size.y);
+        }
+    }
+
+    pub fn release(&mut self) {
+        self.is_dragging = false;
+        self.resize_edges = ResizeEdges::default();
+    }
+
+    /// True while the window is being moved or resized
+    pub fn is_interacting(&self) -> bool {
+        self.is_dragging || self.resize_edges.any()
+    }
+
+    /// The resize edges under `mouse`; corners report two edges
+    pub fn edges_at(&self, mouse: Vector2) -> ResizeEdges {
+        if !self.resizable || self.is_minimized || !self.is_open {
+            return ResizeEdges::default();
+        }
+
+        let outer = Rectangle::new(
+            self.bounds.x - RESIZE_HANDLE / 2.0,
+            self.bounds.y - RESIZE_HANDLE / 2.0,
+            self.bounds.width + RESIZE_HANDLE,
+            self.bounds.height + RESIZE_HANDLE,
+        );
+        if !outer.check_collision_point_rec(mouse) {
+            return ResizeEdges::default();
+        }
+
+        ResizeEdges {
+            left: mouse.x < self.bounds.x + RESIZE_HANDLE / 2.0,
+            right: mouse.x > self.bounds.x + self.bounds.width - RESIZE_HANDLE / 2.0,
+            top: mouse.y < self.bounds.y + RESIZE_HANDLE / 2.0,
+            bottom: mouse.y > self.bounds.y + self.bounds.height - RESIZE_HANDLE / 2.0,
+        }
+    }
+
+    /// The area the window covers on screen; just the title bar while minimized
+    pub fn frame(&self) -> Rectangle {
+        if self.is_minimized {
+            self.title_bar_bounds()
+        } else {
+            self.bounds
+        }
+    }
+
+    pub fn title_bar_bounds(&self) -> Rectangle {
+        Rectangle::new(self.bounds.x, self.bounds.y, self.bounds.width, self.title_bar_height)
+    }
+
+    /// The area below the title bar that children are placed in and clipped to
+    pub fn client_bounds(&self) -> Rectangle {
+        let border = self.style.border_thickness;
+        Rectangle::new(
+            self.bounds.x + border,
+            self.bounds.y + self.title_bar_height,
+            (self.bounds.width - border * 2.0).max(0.0),
+            (self.bounds.height - self.title_bar_height - border).max(0.0),
+        )
+    }
+
+    fn title_button_size(&self) -> f32 {
+        self.title_bar_height - 8.0
+    }
+
+    pub fn close_button_bounds(&self) -> Option<Rectangle> {
+        if !self.closable {
+            return None;
+        }
+        let size = self.title_button_size();
+        Some(Rectangle::new(
+            self.bounds.x + self.bounds.width - size - 4.0,
+            self.bounds.y + 4.0,
+            size,
+            size,
+        ))
+    }
+
+    pub fn minimize_button_bounds(&self) -> Option<Rectangle> {
+        if !self.minimizable {
+            return None;
+        }
+        let size = self.title_button_size();
+        let right = match self.close_button_bounds() {
+            Some(close) => close.x - 4.0,
+            None => self.bounds.x + self.bounds.width - 4.0,
+        };
+        Some(Rectangle::new(right - size, self.bounds.y + 4.0, size, size))
+    }
+
+    /// Move `widget` to (`x`, `y`) relative to the client area.
+    /// Call it every frame before updating the widget so it follows the window.
+    pub fn place(&self, widget: &mut impl Bounded, x: f32, y: f32) {
+        let client = self.client_bounds();
+        widget.set_position(client.x + x, client.y + y);
+    }
+
+    /// The mouse position children should see: the real one only when it is
+    /// over the visible client area and the window isn't covered or being dragged
+    pub fn child_mouse(&self, mouse: Vector2) -> Vector2 {
+        let reachable = self.is_open
+            && !self.is_minimized
+            && !self.is_covered
+            && !self.is_interacting()
+            && self.client_bounds().check_collision_point_rec(mouse);
+        if reachable {
+            mouse
+        } else {
+            NO_MOUSE
+        }
+    }
+
+    pub fn draw(&self, d: &mut impl RaylibDraw) {
+        if !self.is_open {
+            return;
+        }
+
+        let frame = self.frame();
+        let border_color = if self.is_active {
+            self.style.border_color_active
+        } else {
+            self.style.border_color
+        };
+        d.draw_rectangle_rec(frame, self.style.background_color);
+
+        let title_bar = self.title_bar_bounds();
+        let title_color = if self.is_active {
+            self.style.active_color
+        } else {
+            self.style.pressed_color
+        };
+        d.draw_rectangle_rec(title_bar, title_color);
+
+        let buttons_width = match (self.minimize_button_bounds(), self.close_button_bounds()) {
+            (Some(minimize), _) => title_bar.x + title_bar.width - minimize.x,
+            (None, Some(close)) => title_bar.x + title_bar.width - close.x,
+            (None, None) => 0.0,
+        };
+        let title = truncate_with_ellipsis(
+            &self.title,
+            title_bar.width - self.style.padding - buttons_width - 4.0,
+            self.style.font_size,
+        );
+        let text_color = if self.is_active {
+            self.style.text_color_pressed
+        } else {
+            self.style.text_color
+        };
+        d.draw_text(
+            &title,
+            (title_bar.x + self.style.padding / 2.0) as i32,
+            (title_bar.y + (title_bar.height - self.style.font_size as f32) / 2.0) as i32,
+            self.style.font_size,
+            text_color,
+        );
+
+        if let Some(close) = self.close_button_bounds() {
+            if self.hover_close {
+                d.draw_rectangle_rec(close, self.style.hover_color);
+            }
+            let inset = close.width * 0.25;
+            d.draw_line_ex(
+                Vector2::new(close.x + inset, close.y + inset),
+                Vector2::new(close.x + close.width - inset, close.y + close.height - inset),
+                1.5,
+                text_color,
+            );
+            d.draw_line_ex(
+                Vector2::new(close.x + close.width - inset, close.y + inset),
+                Vector2::new(close.x + inset, close.y + close.height - inset),
+                1.5,
+                text_color,
+            );
+        }
+
+        if let Some(minimize) = self.minimize_button_bounds() {
+            if self.hover_minimize {
+                d.draw_rectangle_rec(minimize, self.style.hover_color);
+            }
+            let inset = minimize.width * 0.25;
+            d.draw_line_ex(
+                Vector2::new(minimize.x + inset, minimize.y + minimize.height - inset),
+                Vector2::new(minimize.x + minimize.width - inset, minimize.y + minimize.height - inset),
+                1.5,
+                text_color,
+            );
+        }
+
+        // Grip in the bottom-right corner
+        if self.resizable && !self.is_minimized {
+            let corner = Vector2::new(frame.x + frame.width - 3.0, frame.y + frame.height - 3.0);
+            for offset in [4.0, 8.0, 12.0] {
+                d.draw_line_ex(
+                    Vector2::new(corner.x - offset, corner.y),
+                    Vector2::new(corner.x, corner.y - offset),
+                    1.0,
+                    self.style.border_color,
+                );
+            }
+        }
+
+        d.draw_rectangle_lines_ex(frame, self.style.border_thickness, border_color);
+    }
+
+    /// Draw children clipped to the client area; nothing is drawn while minimized or closed
+    pub fn draw_client<D: RaylibDraw>(&self, d: &mut D, contents: impl FnOnce(&mut RaylibScissorMode<'_, D>)) {
+        if !self.is_open || self.is_minimized {
+            return;
+        }
+        let client = self.client_bounds();
+        let mut clipped = d.begin_scissor_mode(
+            client.x as i32,
+            client.y as i32,
+            client.width as i32,
+            client.height as i32,
+        );
+        contents(&mut clipped);
+    }
+}
+
+/// Windows kept in z-order, back to front
+pub struct WindowStack {
+    pub windows: Vec<Window>,
+    next_id: usize,
+}
+
+impl Default for WindowStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindowStack {
+    pub fn new() -> Self {
+        Self {
+            windows: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Add a window on top of the others and return its id
+    pub fn add(&mut self, mut window: Window) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        window.id = id;
+        self.windows.push(window);
+        self.refresh_states(None);
+        id
+    }
+
+    pub fn get(&self, id: usize) -> Option<&Window> {
+        self.windows.iter().find(|window| window.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut Window> {
+        self.windows.iter_mut().find(|window| window.id == id)
+    }
+
+    pub fn bring_to_front(&mut self, id: usize) {
+        if let Some(index) = self.windows.iter().position(|window| window.id == id) {
+            let window = self.windows.remove(index);
+            self.windows.push(window);
+        }
+    }
+
+    /// Id of the frontmost open window under `point`
+    pub fn topmost_at(&self, point: Vector2) -> Option<usize> {
+        self.windows
+            .iter()
+            .rev()
+            .find(|window| window.is_open && (window.frame().check_collision_point_rec(point) || window.edges_at(point).any()))
+            .map(|window| window.id)
+    }
+
+    pub fn update(&mut self, mouse: Vector2, rl: &RaylibHandle) {
+        // A window being moved or resized keeps the mouse even when it leaves it
+        let target = self
+            .windows
+            .iter()
+            .find(|window| window.is_interacting())
+            .map(|window| window.id)
+            .or_else(|| self.topmost_at(mouse));
+
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+            if let Some(id) = target {
+                self.bring_to_front(id);
+            }
+        }
+        self.refresh_states(target);
+
+        for window in &mut self.windows {
+            let window_mouse = if Some(window.id) == target { mouse } else { NO_MOUSE };
+            window.update(window_mouse, rl);
+        }
+    }
+
+    /// Bring the window under `mouse` to the front and start moving or resizing it
+    pub fn press(&mut self, mouse: Vector2) -> Option<usize> {
+        let id = self.topmost_at(mouse)?;
+        self.bring_to_front(id);
+        self.refresh_states(Some(id));
+        if let Some(window) = self.get_mut(id) {
+            window.press(mouse);
+        }
+        Some(id)
+    }
+
+    /// The mouse position children of window `id` should see
+    pub fn child_mouse(&self, id: usize, mouse: Vector2) -> Vector2 {
+        self.get(id).map_or(NO_MOUSE, |window| window.child_mouse(mouse))
+    }
+
+    /// Draw every window back to front, calling `contents` with each window's id
+    /// to draw its children clipped to the client area
+    pub fn draw<D: RaylibDraw>(&self, d: &mut D, mut contents: impl FnMut(usize, &mut RaylibScissorMode<'_, D>)) {
+        for window in &self.windows {
+            window.draw(d);
+            window.draw_client(d, |clipped| contents(window.id, clipped));
+        }
+    }
+
+    /// The frontmost open window is active; windows other than `target` are covered
+    fn refresh_states(&mut self, target: Option<usize>) {
+        let front = self.windows.iter().rev().find(|window| window.is_open).map(|window| window.id);
+        for window in &mut self.windows {
+            window.is_active = Some(window.id) == front;
+            window.is_covered = target.is_some_and(|id| id != window.id);
+        }
+    }
+}